@@ -0,0 +1,28 @@
+// Feeds arbitrary bytes through the whole parser. Any panic is a bug: the
+// parser must reject malformed input with an `ElfError`.
+//
+//     cargo +nightly fuzz run parse
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = elfp::validate(data);
+    let _ = elfp::parse_archive(data);
+    if let Ok(elf) = elfp::ElfFile::parse(data) {
+        let _ = elf.sections_data();
+        let _ = elf.section_dump("1");
+        let _ = elf.strings(4, &elfp::ElfStringEncoding::DEFAULT);
+        let _ = elf.symbol_tables();
+        let _ = elf.relocation_tables();
+        let _ = elf.dynamic_table();
+        let _ = elf.note_tables();
+        let _ = elf.summary();
+        let _ = elf.checksec();
+        let _ = elf.segment_mapping();
+        let _ = elf.disassemble(".text");
+        let _ = elf.dwarf();
+        let _ = elf.addr2line(elf.header.entry_point.0 as u64);
+        let _ = elf.core();
+    }
+});
@@ -0,0 +1,292 @@
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{ElfEndianness, ElfError, ElfPlatformType, ElfReader, parse_string_at, slice_at};
+
+// Global header of ar archives, and of GNU thin archives whose members are
+// stored next to the archive instead of inside it
+const ARCHIVE_MAGIC: &[u8; 8] = b"!<arch>\n";
+const THIN_ARCHIVE_MAGIC: &[u8; 8] = b"!<thin>\n";
+// struct ar_hdr: name[16] date[12] uid[6] gid[6] mode[8] size[10] fmag[2]
+const MEMBER_HEADER_SIZE: usize = 60;
+
+// A static library, the members in archive order
+#[derive(Debug, Default)]
+pub struct ElfArchive<'data> {
+    // Members are files named relative to the archive, see `ElfArchiveMember`
+    pub thin: bool,
+    pub members: Vec<ElfArchiveMember<'data>>,
+    pub symbols: ElfArchiveSymbols,
+}
+
+#[derive(Debug, Default)]
+pub struct ElfArchiveMember<'data> {
+    // Long names resolved, the path of the file for thin archives
+    pub name: String,
+    // Offset of the member header, which the symbol index refers to
+    pub offset: usize,
+    // ar_size, the size of the file for thin archives
+    pub size: usize,
+    // Empty for thin archives, whose members are not stored in the archive
+    pub data: &'data [u8],
+}
+
+// The symbol index of an archive, GNU `/` or `/SYM64/`, or BSD `__.SYMDEF`
+#[derive(Debug, Default, Serialize)]
+pub struct ElfArchiveSymbols {
+    pub inner: Vec<ElfArchiveSymbol>,
+}
+
+impl ElfArchiveSymbols {
+    pub fn inner(self) -> Vec<ElfArchiveSymbol> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfArchiveSymbols {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfArchiveSymbol {
+    pub symbol_name: String,
+    // Member defining the symbol, empty when no member header is at the offset
+    pub member_name: String,
+    pub member_offset: ElfArchiveOffset,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfArchiveOffset(pub usize);
+
+impl std::fmt::Display for ElfArchiveOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+// Whether the content is an ar archive rather than an ELF file
+pub fn is_archive(content: &[u8]) -> bool {
+    content.starts_with(ARCHIVE_MAGIC) || content.starts_with(THIN_ARCHIVE_MAGIC)
+}
+
+// The flavours of archive symbol index
+enum SymbolIndexKind {
+    Gnu,   // "/", 32-bit big endian
+    Gnu64, // "/SYM64/", 64-bit big endian
+    Bsd,   // "__.SYMDEF", 32-bit ranlib entries
+    Bsd64, // "__.SYMDEF_64", 64-bit ranlib entries
+}
+
+// Reads a decimal field of a member header, ASCII digits padded with spaces
+fn decimal_field(field: &'static str, offset: usize, bytes: &[u8]) -> Result<usize, ElfError> {
+    let text = String::from_utf8_lossy(bytes);
+    let text = text.trim_end_matches(' ');
+    text.parse::<usize>().map_err(|_| ElfError::InvalidValue {
+        field,
+        offset,
+        expected: "a decimal number",
+        actual: bytes
+            .iter()
+            .fold(0, |value, byte| value << 8 | u64::from(*byte)),
+    })
+}
+
+// Looks a GNU long name up in the `//` member. Entries end with "/\n".
+fn long_name(names: &[u8], index: usize, offset: usize) -> Result<String, ElfError> {
+    let name = names.get(index..).ok_or(ElfError::InvalidValue {
+        field: "ar_name",
+        offset,
+        expected: "an offset into the long name table",
+        actual: index as u64,
+    })?;
+    let end = name
+        .iter()
+        .position(|byte| *byte == b'\n')
+        .unwrap_or(name.len());
+    let name = String::from_utf8_lossy(&name[..end]);
+
+    Ok(name.trim_end_matches('/').to_string())
+}
+
+pub fn parse_archive(content: &[u8]) -> Result<ElfArchive<'_>, ElfError> {
+    let thin = content.starts_with(THIN_ARCHIVE_MAGIC);
+    if !is_archive(content) {
+        let magic = slice_at(content, "archive magic", 0, ARCHIVE_MAGIC.len())?;
+        return Err(ElfError::InvalidValue {
+            field: "archive magic",
+            offset: 0,
+            expected: "!<arch> or !<thin>",
+            actual: u64::from_be_bytes(magic.try_into().unwrap_or_default()),
+        });
+    }
+
+    let mut members = Vec::new();
+    let mut names: &[u8] = &[];
+    let mut index = None;
+    let mut offset = ARCHIVE_MAGIC.len();
+    while offset < content.len() {
+        let header = slice_at(content, "archive member header", offset, MEMBER_HEADER_SIZE)?;
+        if &header[58..60] != b"`\n" {
+            return Err(ElfError::InvalidValue {
+                field: "ar_fmag",
+                offset: offset + 58,
+                expected: "0x600a",
+                actual: u64::from(u16::from_be_bytes([header[58], header[59]])),
+            });
+        }
+        let size = decimal_field("ar_size", offset + 48, &header[48..58])?;
+        let raw_name = String::from_utf8_lossy(&header[..16]);
+        let raw_name = raw_name.trim_end_matches(' ');
+        let mut data_offset = offset + MEMBER_HEADER_SIZE;
+        let mut data_size = size;
+
+        // BSD keeps long names in front of the data: "#1/<length>"
+        let name = if let Some(length) = raw_name.strip_prefix("#1/") {
+            let length = decimal_field("ar_name", offset, length.as_bytes())?;
+            let name = slice_at(content, "archive member name", data_offset, length)?;
+            data_offset += length;
+            data_size = size.checked_sub(length).ok_or(ElfError::InvalidValue {
+                field: "ar_size",
+                offset: offset + 48,
+                expected: "a size covering the BSD long name",
+                actual: size as u64,
+            })?;
+            let end = name.iter().position(|b| *b == 0).unwrap_or(name.len());
+            String::from_utf8_lossy(&name[..end]).to_string()
+        } else {
+            raw_name.to_string()
+        };
+
+        // The symbol index and name table are stored even in thin archives
+        let special = matches!(
+            name.as_str(),
+            "/" | "//" | "/SYM64/" | "__.SYMDEF" | "__.SYMDEF SORTED" | "__.SYMDEF_64"
+        );
+        let stored = match thin && !special {
+            true => 0,
+            false => data_size,
+        };
+        let data = slice_at(content, "archive member", data_offset, stored)?;
+        let end = data_offset + stored;
+        match name.as_str() {
+            "/" => index = Some((SymbolIndexKind::Gnu, data_offset, end)),
+            "/SYM64/" => index = Some((SymbolIndexKind::Gnu64, data_offset, end)),
+            "__.SYMDEF" | "__.SYMDEF SORTED" => {
+                index = Some((SymbolIndexKind::Bsd, data_offset, end))
+            }
+            "__.SYMDEF_64" => index = Some((SymbolIndexKind::Bsd64, data_offset, end)),
+            "//" => names = data,
+            _ => {
+                let name = match name.strip_prefix('/') {
+                    Some(digits) => {
+                        let index = decimal_field("ar_name", offset, digits.as_bytes())?;
+                        long_name(names, index, offset)?
+                    }
+                    // GNU ends short names with a slash so they can hold spaces
+                    None => name.strip_suffix('/').unwrap_or(&name).to_string(),
+                };
+                members.push(ElfArchiveMember {
+                    name,
+                    offset,
+                    size: data_size,
+                    data,
+                });
+            }
+        }
+
+        // Members start on even offsets
+        offset = end + end % 2;
+    }
+
+    let symbols = match index {
+        Some((kind, start, end)) => parse_archive_symbols(&content[..end], start, kind, &members)?,
+        None => ElfArchiveSymbols::default(),
+    };
+
+    Ok(ElfArchive {
+        thin,
+        members,
+        symbols,
+    })
+}
+
+// GNU writes the index big endian: a count, the member offsets and the names
+// one after the other. BSD writes an array of (name, member) offset pairs
+// followed by a string table, in the byte order of the host, little endian
+// in practice. `content` ends with the index member.
+fn parse_archive_symbols(
+    content: &[u8],
+    start: usize,
+    kind: SymbolIndexKind,
+    members: &[ElfArchiveMember<'_>],
+) -> Result<ElfArchiveSymbols, ElfError> {
+    let (endian, platform) = match kind {
+        SymbolIndexKind::Gnu => (ElfEndianness::Big, ElfPlatformType::Bit32),
+        SymbolIndexKind::Gnu64 => (ElfEndianness::Big, ElfPlatformType::Bit64),
+        SymbolIndexKind::Bsd => (ElfEndianness::Little, ElfPlatformType::Bit32),
+        SymbolIndexKind::Bsd64 => (ElfEndianness::Little, ElfPlatformType::Bit64),
+    };
+    let mut reader = ElfReader::at(content, start);
+    let mut symbols = Vec::new();
+    match kind {
+        SymbolIndexKind::Gnu | SymbolIndexKind::Gnu64 => {
+            let count = reader.read_word("archive symbol count", &endian, &platform)?;
+            let word = match platform {
+                ElfPlatformType::Bit32 => 4,
+                ElfPlatformType::Bit64 => 8,
+            };
+            let mut offsets = Vec::with_capacity(reader.capacity_for(count, word));
+            for _ in 0..count {
+                offsets.push(reader.read_word("archive symbol offset", &endian, &platform)?);
+            }
+            let mut names = content[reader.pointer()..].split(|byte| *byte == 0);
+            for member_offset in offsets {
+                let name = String::from_utf8_lossy(names.next().unwrap_or_default());
+                symbols.push(archive_symbol(name.to_string(), member_offset, members));
+            }
+        }
+        SymbolIndexKind::Bsd | SymbolIndexKind::Bsd64 => {
+            let ranlib_size = reader.read_word("ranlib size", &endian, &platform)?;
+            let ranlib_start = reader.pointer();
+            reader.skip("ranlib", ranlib_size)?;
+            let strings_size = reader.read_word("ranlib strings size", &endian, &platform)?;
+            let strings_start = reader.pointer();
+            let entry_size = match platform {
+                ElfPlatformType::Bit32 => 8,
+                ElfPlatformType::Bit64 => 16,
+            };
+            for index in 0..ranlib_size / entry_size {
+                reader.seek(ranlib_start + index * entry_size);
+                let name_offset = reader.read_word("ran_strx", &endian, &platform)?;
+                let member_offset = reader.read_word("ran_off", &endian, &platform)?;
+                let name = match name_offset < strings_size {
+                    true => parse_string_at(content, strings_start, strings_size, name_offset)?,
+                    false => format!("<corrupt: {:#x}>", name_offset),
+                };
+                symbols.push(archive_symbol(name, member_offset, members));
+            }
+        }
+    }
+
+    Ok(ElfArchiveSymbols { inner: symbols })
+}
+
+fn archive_symbol(
+    symbol_name: String,
+    member_offset: usize,
+    members: &[ElfArchiveMember<'_>],
+) -> ElfArchiveSymbol {
+    let member_name = members
+        .iter()
+        .find(|member| member.offset == member_offset)
+        .map(|member| member.name.clone())
+        .unwrap_or_default();
+
+    ElfArchiveSymbol {
+        symbol_name,
+        member_name,
+        member_offset: ElfArchiveOffset(member_offset),
+    }
+}
@@ -0,0 +1,498 @@
+use serde::{Serialize, Serializer};
+
+use crate::{
+    ElfDynamicTable, ElfDynamicTag, ElfError, ElfHeader, ElfInstructionSet, ElfObjectFileType,
+    ElfProgramHeader, ElfSectionFlags, ElfSectionHeader, ElfSectionHeaderType, ElfSegmentFlags,
+    ElfSegmentType, ElfSymbol, GNU_PROPERTY_AARCH64_FEATURE_1_AND, GNU_PROPERTY_X86_FEATURE_1_AND,
+    parse_dynamic_table, parse_note_tables, parse_symbol_tables, serialize_named,
+};
+
+const DF_BIND_NOW: usize = 0x8;
+const DF_1_NOW: usize = 0x1;
+const DF_1_PIE: usize = 0x8000000;
+
+const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 0x1;
+const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 0x2;
+const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 0x1;
+const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 0x2;
+
+// Symbols referenced by code built with -fstack-protector
+const CANARY_SYMBOLS: [&str; 3] = [
+    "__stack_chk_fail",
+    "__stack_chk_guard",
+    "__intel_security_cookie",
+];
+
+// Hardening features of a binary, in the spirit of checksec.sh
+#[derive(Debug, Default, Serialize)]
+pub struct ElfChecksec {
+    pub pie: ElfPie,
+    // The stack is not executable (PT_GNU_STACK without PF_X, or a
+    // .note.GNU-stack section without SHF_EXECINSTR in relocatable files)
+    pub nx: bool,
+    pub relro: ElfRelro,
+    pub canary: bool,
+    // The fortified `__*_chk` functions the binary calls
+    pub fortified: Vec<String>,
+    pub rpath: Option<String>,
+    pub runpath: Option<String>,
+    // x86 control-flow enforcement from GNU_PROPERTY_X86_FEATURE_1_AND
+    pub ibt: bool,
+    pub shstk: bool,
+    // AArch64 branch protection from GNU_PROPERTY_AARCH64_FEATURE_1_AND
+    pub bti: bool,
+    pub pac: bool,
+    // No .symtab left in the file
+    pub stripped: bool,
+    // Rules of the policy this report failed, see `ElfChecksecRule`
+    pub violations: Vec<String>,
+}
+
+impl ElfChecksec {
+    pub fn fortify(&self) -> bool {
+        !self.fortified.is_empty()
+    }
+
+    // Records every rule of `policy` that this binary does not satisfy.
+    pub fn check(&mut self, policy: &[ElfChecksecRule]) {
+        self.violations = policy
+            .iter()
+            .filter(|rule| !rule.holds(self))
+            .map(|rule| rule.to_string())
+            .collect();
+    }
+}
+
+impl std::fmt::Display for ElfChecksec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let yes_no = |value: bool| if value { "yes" } else { "no" };
+        writeln!(f, "PIE:       {}", self.pie)?;
+        writeln!(f, "NX:        {}", yes_no(self.nx))?;
+        writeln!(f, "RELRO:     {}", self.relro)?;
+        writeln!(f, "Canary:    {}", yes_no(self.canary))?;
+        match self.fortify() {
+            true => writeln!(f, "FORTIFY:   yes ({})", self.fortified.join(", "))?,
+            false => writeln!(f, "FORTIFY:   no")?,
+        }
+        writeln!(f, "RPATH:     {}", self.rpath.as_deref().unwrap_or("-"))?;
+        writeln!(f, "RUNPATH:   {}", self.runpath.as_deref().unwrap_or("-"))?;
+        writeln!(
+            f,
+            "CET:       IBT {}, SHSTK {}",
+            yes_no(self.ibt),
+            yes_no(self.shstk)
+        )?;
+        writeln!(
+            f,
+            "BTI/PAC:   BTI {}, PAC {}",
+            yes_no(self.bti),
+            yes_no(self.pac)
+        )?;
+        writeln!(f, "Stripped:  {}", yes_no(self.stripped))?;
+        match self.violations.is_empty() {
+            true => write!(f, "Policy:    passed"),
+            false => write!(f, "Policy:    violated ({})", self.violations.join(", ")),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub enum ElfPie {
+    #[default]
+    NoPie, // Fixed address executable
+    Pie, // Position independent executable
+    Dso, // Shared object
+    Rel, // Relocatable object, not linked yet
+}
+
+impl ElfPie {
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfPie::NoPie => 0,
+            ElfPie::Pie => 1,
+            ElfPie::Dso => 2,
+            ElfPie::Rel => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for ElfPie {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfPie::NoPie => "No PIE",
+            ElfPie::Pie => "PIE enabled",
+            ElfPie::Dso => "DSO",
+            ElfPie::Rel => "REL",
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl Serialize for ElfPie {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, self.value() as u64, self)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub enum ElfRelro {
+    #[default]
+    No, // No PT_GNU_RELRO
+    Partial, // PT_GNU_RELRO, lazy binding leaves the PLT GOT writable
+    Full,    // PT_GNU_RELRO and BIND_NOW
+    Rel,     // Relocatable object, the linker decides
+}
+
+impl ElfRelro {
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfRelro::No => 0,
+            ElfRelro::Partial => 1,
+            ElfRelro::Full => 2,
+            ElfRelro::Rel => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for ElfRelro {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfRelro::No => "No RELRO",
+            ElfRelro::Partial => "Partial RELRO",
+            ElfRelro::Full => "Full RELRO",
+            ElfRelro::Rel => "REL",
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl Serialize for ElfRelro {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, self.value() as u64, self)
+    }
+}
+
+// A single requirement of a checksec policy. Shared objects and relocatable
+// files satisfy `Pie` since they are position independent by construction,
+// relocatable files satisfy `Relro` and `FullRelro` since they are not linked yet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElfChecksecRule {
+    Pie,
+    Nx,
+    Relro,     // At least partial RELRO
+    FullRelro, // Full RELRO
+    Canary,
+    Fortify,
+    NoRpath,
+    NoRunpath,
+    Ibt,
+    Shstk,
+    Bti,
+    Pac,
+    Stripped,
+}
+
+impl ElfChecksecRule {
+    // Used when no policy is given: what a hardened release build should have
+    pub const DEFAULT_POLICY: [ElfChecksecRule; 5] = [
+        ElfChecksecRule::Pie,
+        ElfChecksecRule::Nx,
+        ElfChecksecRule::FullRelro,
+        ElfChecksecRule::Canary,
+        ElfChecksecRule::NoRpath,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let rule = match name {
+            "pie" => ElfChecksecRule::Pie,
+            "nx" => ElfChecksecRule::Nx,
+            "relro" | "relro=partial" => ElfChecksecRule::Relro,
+            "relro=full" => ElfChecksecRule::FullRelro,
+            "canary" => ElfChecksecRule::Canary,
+            "fortify" => ElfChecksecRule::Fortify,
+            "no-rpath" => ElfChecksecRule::NoRpath,
+            "no-runpath" => ElfChecksecRule::NoRunpath,
+            "ibt" => ElfChecksecRule::Ibt,
+            "shstk" => ElfChecksecRule::Shstk,
+            "bti" => ElfChecksecRule::Bti,
+            "pac" => ElfChecksecRule::Pac,
+            "stripped" => ElfChecksecRule::Stripped,
+            _ => return None,
+        };
+
+        Some(rule)
+    }
+
+    pub fn holds(&self, report: &ElfChecksec) -> bool {
+        match self {
+            ElfChecksecRule::Pie => report.pie != ElfPie::NoPie,
+            ElfChecksecRule::Nx => report.nx,
+            ElfChecksecRule::Relro => report.relro != ElfRelro::No,
+            ElfChecksecRule::FullRelro => {
+                matches!(report.relro, ElfRelro::Full | ElfRelro::Rel)
+            }
+            ElfChecksecRule::Canary => report.canary,
+            ElfChecksecRule::Fortify => report.fortify(),
+            ElfChecksecRule::NoRpath => report.rpath.is_none(),
+            ElfChecksecRule::NoRunpath => report.runpath.is_none(),
+            ElfChecksecRule::Ibt => report.ibt,
+            ElfChecksecRule::Shstk => report.shstk,
+            ElfChecksecRule::Bti => report.bti,
+            ElfChecksecRule::Pac => report.pac,
+            ElfChecksecRule::Stripped => report.stripped,
+        }
+    }
+}
+
+impl std::fmt::Display for ElfChecksecRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfChecksecRule::Pie => "pie",
+            ElfChecksecRule::Nx => "nx",
+            ElfChecksecRule::Relro => "relro",
+            ElfChecksecRule::FullRelro => "relro=full",
+            ElfChecksecRule::Canary => "canary",
+            ElfChecksecRule::Fortify => "fortify",
+            ElfChecksecRule::NoRpath => "no-rpath",
+            ElfChecksecRule::NoRunpath => "no-runpath",
+            ElfChecksecRule::Ibt => "ibt",
+            ElfChecksecRule::Shstk => "shstk",
+            ElfChecksecRule::Bti => "bti",
+            ElfChecksecRule::Pac => "pac",
+            ElfChecksecRule::Stripped => "stripped",
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+fn dynamic_value(dynamic: &ElfDynamicTable, tag: u64) -> Option<usize> {
+    dynamic.find(tag).map(|entry| entry.dynamic_value.0)
+}
+
+fn dynamic_string(dynamic: &ElfDynamicTable, tag: u64) -> Option<String> {
+    dynamic.find(tag).map(|entry| entry.dynamic_meaning.clone())
+}
+
+// ET_DYN files are PIEs when DT_FLAGS_1 has DF_1_PIE. Older linkers wrote no
+// DT_FLAGS_1 at all, their executables are told from shared libraries by a
+// program interpreter along with DT_DEBUG, which only executables get:
+// libc.so.6 has PT_INTERP too, to run on its own.
+fn check_pie(
+    header: &ElfHeader,
+    program_header: &ElfProgramHeader,
+    dynamic: &ElfDynamicTable,
+) -> ElfPie {
+    let has_interpreter = program_header
+        .inner
+        .iter()
+        .any(|entry| matches!(entry.segment_type, ElfSegmentType::PtInterp));
+    let is_pie = match dynamic_value(dynamic, ElfDynamicTag::DT_FLAGS_1) {
+        Some(flags_1) => flags_1 & DF_1_PIE != 0,
+        None => has_interpreter && dynamic.find(ElfDynamicTag::DT_DEBUG).is_some(),
+    };
+
+    match header.object_file_type {
+        ElfObjectFileType::EtDyn if is_pie => ElfPie::Pie,
+        ElfObjectFileType::EtDyn => ElfPie::Dso,
+        ElfObjectFileType::EtRel => ElfPie::Rel,
+        _ => ElfPie::NoPie,
+    }
+}
+
+fn check_relro(
+    header: &ElfHeader,
+    program_header: &ElfProgramHeader,
+    dynamic: &ElfDynamicTable,
+) -> ElfRelro {
+    if let ElfObjectFileType::EtRel = header.object_file_type {
+        return ElfRelro::Rel;
+    }
+    let has_relro = program_header
+        .inner
+        .iter()
+        .any(|entry| matches!(entry.segment_type, ElfSegmentType::PtGnuRelro));
+    let bind_now = dynamic.find(ElfDynamicTag::DT_BIND_NOW).is_some()
+        || dynamic_value(dynamic, ElfDynamicTag::DT_FLAGS).unwrap_or(0) & DF_BIND_NOW != 0
+        || dynamic_value(dynamic, ElfDynamicTag::DT_FLAGS_1).unwrap_or(0) & DF_1_NOW != 0;
+
+    match (has_relro, bind_now) {
+        (false, _) => ElfRelro::No,
+        (true, false) => ElfRelro::Partial,
+        (true, true) => ElfRelro::Full,
+    }
+}
+
+pub fn parse_checksec(
+    content: &[u8],
+    header: &ElfHeader,
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+) -> Result<ElfChecksec, ElfError> {
+    let dynamic = parse_dynamic_table(
+        content,
+        program_header,
+        section_header,
+        &header.endianness,
+        &header.platform_type,
+    )?;
+    let symbol_tables = parse_symbol_tables(
+        content,
+        section_header,
+        &header.endianness,
+        &header.platform_type,
+    )?;
+    let note_tables = parse_note_tables(
+        content,
+        program_header,
+        section_header,
+        &header.instruction_set,
+        &header.endianness,
+        &header.platform_type,
+    )?;
+
+    let symbols = symbol_tables
+        .inner
+        .iter()
+        .flat_map(|table| table.inner.iter())
+        .collect::<Vec<&ElfSymbol>>();
+    let canary = symbols
+        .iter()
+        .any(|symbol| CANARY_SYMBOLS.contains(&symbol.symbol_name.0.as_str()));
+    // Only calls count, libc itself defines every `__*_chk` function
+    let mut fortified = symbols
+        .iter()
+        .filter(|symbol| symbol.symbol_section_index.is_undefined())
+        .map(|symbol| symbol.symbol_name.0.as_str())
+        .filter(|name| name.starts_with("__") && name.ends_with("_chk"))
+        .filter(|name| !CANARY_SYMBOLS.contains(name))
+        .map(|name| name.to_string())
+        .collect::<Vec<String>>();
+    fortified.sort();
+    fortified.dedup();
+
+    // Without PT_GNU_STACK the kernel maps the stack executable. Relocatable
+    // files have no segments yet, the linker turns .note.GNU-stack into one.
+    let nx = match header.object_file_type {
+        ElfObjectFileType::EtRel => section_header
+            .inner
+            .iter()
+            .find(|entry| entry.section_name.0 == ".note.GNU-stack")
+            .is_some_and(|entry| !entry.section_flags.contains(ElfSectionFlags::SHF_EXECINSTR)),
+        _ => program_header
+            .inner
+            .iter()
+            .find(|entry| matches!(entry.segment_type, ElfSegmentType::PtGnuStack))
+            .is_some_and(|entry| !entry.segment_flags.contains(ElfSegmentFlags::PF_X)),
+    };
+
+    let property = |pr_type: u32| {
+        note_tables
+            .gnu_property(pr_type, &header.endianness, &header.platform_type)
+            .unwrap_or(0)
+    };
+    let (x86_features, aarch64_features) = match header.instruction_set {
+        ElfInstructionSet::X86 | ElfInstructionSet::AmdX86_64 => {
+            (property(GNU_PROPERTY_X86_FEATURE_1_AND), 0)
+        }
+        ElfInstructionSet::Arm64bit => (0, property(GNU_PROPERTY_AARCH64_FEATURE_1_AND)),
+        _ => (0, 0),
+    };
+
+    let stripped = !section_header
+        .inner
+        .iter()
+        .any(|entry| matches!(entry.section_header_type, ElfSectionHeaderType::ShtSymtab));
+
+    Ok(ElfChecksec {
+        pie: check_pie(header, program_header, &dynamic),
+        nx,
+        relro: check_relro(header, program_header, &dynamic),
+        canary,
+        fortified,
+        rpath: dynamic_string(&dynamic, ElfDynamicTag::DT_RPATH),
+        runpath: dynamic_string(&dynamic, ElfDynamicTag::DT_RUNPATH),
+        ibt: x86_features & GNU_PROPERTY_X86_FEATURE_1_IBT != 0,
+        shstk: x86_features & GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0,
+        bti: aarch64_features & GNU_PROPERTY_AARCH64_FEATURE_1_BTI != 0,
+        pac: aarch64_features & GNU_PROPERTY_AARCH64_FEATURE_1_PAC != 0,
+        stripped,
+        violations: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ElfDynamicEntry, ElfDynamicValue, ElfProgramHeaderEntry, ElfSegmentAllignment,
+        ElfSegmentFileSize, ElfSegmentMemorySize, ElfSegmentOffset, ElfSegmentPAddr,
+        ElfSegmentVAddr,
+    };
+
+    fn shared_object() -> ElfHeader {
+        ElfHeader {
+            object_file_type: ElfObjectFileType::EtDyn,
+            ..Default::default()
+        }
+    }
+
+    fn with_interpreter() -> ElfProgramHeader {
+        ElfProgramHeader {
+            inner: vec![ElfProgramHeaderEntry {
+                segment_type: ElfSegmentType::PtInterp,
+                segment_flags: ElfSegmentFlags(ElfSegmentFlags::PF_R),
+                segment_offset: ElfSegmentOffset(0x318),
+                segment_vaddr: ElfSegmentVAddr(0x318),
+                segment_paddr: ElfSegmentPAddr(0x318),
+                segment_file_size: ElfSegmentFileSize(0x1c),
+                segment_memory_size: ElfSegmentMemorySize(0x1c),
+                segment_allignment: ElfSegmentAllignment(1),
+            }],
+            entry_padding: 0,
+        }
+    }
+
+    fn dynamic(entries: Vec<(ElfDynamicTag, usize)>) -> ElfDynamicTable {
+        ElfDynamicTable {
+            offset: 0,
+            inner: entries
+                .into_iter()
+                .map(|(tag, value)| ElfDynamicEntry {
+                    dynamic_tag: tag,
+                    dynamic_value: ElfDynamicValue(value),
+                    dynamic_meaning: String::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn pie_flag_decides() {
+        let dynamic = dynamic(vec![(ElfDynamicTag::DtFlags1, DF_1_NOW | DF_1_PIE)]);
+        let pie = check_pie(&shared_object(), &ElfProgramHeader::default(), &dynamic);
+        assert_eq!(pie, ElfPie::Pie);
+    }
+
+    // libc.so.6 asks for an interpreter to run on its own, and so do shared
+    // libraries linked with DT_FLAGS_1 but without DF_1_PIE
+    #[test]
+    fn shared_library_with_interpreter_is_dso() {
+        let header = shared_object();
+        let program_header = with_interpreter();
+        let libc = dynamic(vec![(ElfDynamicTag::DtFlags, DF_BIND_NOW)]);
+        assert_eq!(check_pie(&header, &program_header, &libc), ElfPie::Dso);
+        let flagged = dynamic(vec![(ElfDynamicTag::DtFlags1, DF_1_NOW)]);
+        assert_eq!(check_pie(&header, &program_header, &flagged), ElfPie::Dso);
+    }
+
+    // Executables of linkers older than DF_1_PIE
+    #[test]
+    fn interpreter_and_debug_without_flags_1_is_pie() {
+        let dynamic = dynamic(vec![(ElfDynamicTag::DtDebug, 0)]);
+        let pie = check_pie(&shared_object(), &with_interpreter(), &dynamic);
+        assert_eq!(pie, ElfPie::Pie);
+    }
+}
@@ -0,0 +1,239 @@
+use std::{borrow::Cow, io::Read};
+
+use serde::{Serialize, Serializer};
+
+use crate::{
+    ElfEndianness, ElfError, ElfPlatformType, ElfReader, ElfSectionFlags, ElfSectionHeaderEntry,
+    section_data, serialize_named,
+};
+
+// Largest ch_size accepted, relative to the compressed size. Deflate cannot
+// expand past 1032:1 and real sections stay far below it, anything larger is
+// a corrupt or hostile header and is rejected before allocating.
+const MAX_COMPRESSION_RATIO: usize = 1032;
+
+// Describes the compressed content of a section, either an Elf32_Chdr /
+// Elf64_Chdr in front of an SHF_COMPRESSED section or the "ZLIB" header of a
+// legacy .zdebug_* section
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ElfCompressionHeader {
+    pub compression_type: ElfCompressionType,
+    // Size of the section once decompressed, ch_size
+    pub uncompressed_size: usize,
+    // Alignment of the decompressed section, ch_addralign
+    pub uncompressed_alignment: usize,
+    // Bytes in front of the compressed stream
+    pub header_size: usize,
+}
+
+impl std::fmt::Display for ElfCompressionHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:X} {:X}",
+            self.compression_type, self.uncompressed_size, self.uncompressed_alignment
+        )
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub enum ElfCompressionType {
+    #[default]
+    Zlib, // ELFCOMPRESS_ZLIB, a zlib stream
+    Zstd,       // ELFCOMPRESS_ZSTD, a zstd frame
+    LegacyZlib, // .zdebug_* section, "ZLIB" and a big endian size
+    Other(u32), // OS or processor specific
+}
+
+impl std::fmt::Display for ElfCompressionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfCompressionType::Zlib => "ELFCOMPRESS_ZLIB",
+            ElfCompressionType::Zstd => "ELFCOMPRESS_ZSTD",
+            ElfCompressionType::LegacyZlib => "ZLIB",
+            ElfCompressionType::Other(value) => return write!(f, "ELFCOMPRESS_<{value:#x}>"),
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfCompressionType {
+    // Raw ch_type value, 0 for legacy .zdebug_* sections which have none
+    pub fn value(&self) -> u32 {
+        match self {
+            ElfCompressionType::Zlib => 1,
+            ElfCompressionType::Zstd => 2,
+            ElfCompressionType::LegacyZlib => 0,
+            ElfCompressionType::Other(value) => *value,
+        }
+    }
+}
+
+impl Serialize for ElfCompressionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+// The compression of a section, `-` for sections stored as is
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionCompression(pub Option<ElfCompressionHeader>);
+
+impl std::fmt::Display for ElfSectionCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(header) => write!(f, "{}", header),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+// Reads the compression header of a section. Sections that are neither
+// SHF_COMPRESSED nor a .zdebug_* section starting with "ZLIB" have none.
+pub fn parse_compression_header(
+    content: &[u8],
+    entry: &ElfSectionHeaderEntry,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<Option<ElfCompressionHeader>, ElfError> {
+    let offset = entry.section_offset.0;
+    let data = section_data(content, entry)?;
+    let header = if entry
+        .section_flags
+        .contains(ElfSectionFlags::SHF_COMPRESSED)
+    {
+        let mut reader = ElfReader::at(content, offset);
+        let compression_type = match reader.read_u32("ch_type", endian)? {
+            1 => ElfCompressionType::Zlib,
+            2 => ElfCompressionType::Zstd,
+            other => ElfCompressionType::Other(other),
+        };
+        if let ElfPlatformType::Bit64 = platform {
+            reader.skip("ch_reserved", 4)?;
+        }
+        let uncompressed_size = reader.read_word("ch_size", endian, platform)?;
+        let uncompressed_alignment = reader.read_word("ch_addralign", endian, platform)?;
+
+        ElfCompressionHeader {
+            compression_type,
+            uncompressed_size,
+            uncompressed_alignment,
+            header_size: reader.pointer() - offset,
+        }
+    } else if entry.section_name.0.starts_with(".zdebug") && data.starts_with(b"ZLIB") {
+        // GNU's older scheme: the name says .zdebug instead of .debug and the data
+        // starts with "ZLIB" and the decompressed size as a big endian 64-bit word
+        let mut reader = ElfReader::at(content, offset + 4);
+        let size = reader.read_u64("zdebug size", &ElfEndianness::Big)?;
+        let uncompressed_size = usize::try_from(size).map_err(|_| ElfError::Overflow {
+            field: "zdebug size",
+            offset: offset + 4,
+        })?;
+
+        ElfCompressionHeader {
+            compression_type: ElfCompressionType::LegacyZlib,
+            uncompressed_size,
+            uncompressed_alignment: entry.section_addr_allign.0,
+            header_size: 12,
+        }
+    } else {
+        return Ok(None);
+    };
+
+    // The header is read from the file, make sure it also fits the section
+    if data.len() < header.header_size {
+        return Err(ElfError::Truncated {
+            field: "compression header",
+            offset,
+            expected: header.header_size,
+            actual: data.len(),
+        });
+    }
+
+    Ok(Some(header))
+}
+
+// The content of a section as its consumers see it: decompressed when the
+// section is compressed, borrowed from the file otherwise.
+pub fn section_contents<'data>(
+    content: &'data [u8],
+    entry: &ElfSectionHeaderEntry,
+) -> Result<Cow<'data, [u8]>, ElfError> {
+    let data = section_data(content, entry)?;
+    let Some(header) = &entry.section_compression.0 else {
+        // The header is read along with the section header table and left
+        // out when it does not parse, which must not pass as plain content
+        if entry
+            .section_flags
+            .contains(ElfSectionFlags::SHF_COMPRESSED)
+        {
+            return Err(ElfError::Truncated {
+                field: "compression header",
+                offset: entry.section_offset.0,
+                expected: 12,
+                actual: data.len(),
+            });
+        }
+        return Ok(Cow::Borrowed(data));
+    };
+
+    let offset = entry.section_offset.0;
+    let stream = data.get(header.header_size..).unwrap_or_default();
+    let corrupt = |err: std::io::Error| ElfError::Decompression {
+        offset: offset + header.header_size,
+        message: err.to_string(),
+    };
+    if header.uncompressed_size > stream.len().saturating_mul(MAX_COMPRESSION_RATIO) {
+        return Err(ElfError::Decompression {
+            offset: offset + header.header_size,
+            message: format!(
+                "ch_size of {} bytes is over {MAX_COMPRESSION_RATIO} times the {} compressed bytes",
+                header.uncompressed_size,
+                stream.len()
+            ),
+        });
+    }
+    let size = header.uncompressed_size as u64;
+    let mut decompressed = Vec::with_capacity(header.uncompressed_size);
+    match header.compression_type {
+        ElfCompressionType::Zlib | ElfCompressionType::LegacyZlib => {
+            flate2::read::ZlibDecoder::new(stream)
+                .take(size)
+                .read_to_end(&mut decompressed)
+                .map_err(corrupt)?;
+        }
+        ElfCompressionType::Zstd => {
+            let decoder = ruzstd::decoding::StreamingDecoder::new(stream).map_err(|err| {
+                ElfError::Decompression {
+                    offset: offset + header.header_size,
+                    message: err.to_string(),
+                }
+            })?;
+            decoder
+                .take(size)
+                .read_to_end(&mut decompressed)
+                .map_err(corrupt)?;
+        }
+        ElfCompressionType::Other(value) => {
+            return Err(ElfError::InvalidValue {
+                field: "ch_type",
+                offset,
+                expected: "ELFCOMPRESS_ZLIB or ELFCOMPRESS_ZSTD",
+                actual: u64::from(value),
+            });
+        }
+    }
+    if decompressed.len() != header.uncompressed_size {
+        return Err(ElfError::Decompression {
+            offset: offset + header.header_size,
+            message: format!(
+                "expected {} bytes, found {}",
+                header.uncompressed_size,
+                decompressed.len()
+            ),
+        });
+    }
+
+    Ok(Cow::Owned(decompressed))
+}
@@ -0,0 +1,768 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfEndianness, ElfError, ElfHeader, ElfInstructionSet, ElfNote, ElfObjectFileType,
+    ElfPlatformType, ElfProgramHeader, ElfReader, ElfSectionHeader, ElfSegmentFlags,
+    ElfSegmentType, parse_note_tables, serialize_named, slice_at,
+};
+
+// n_type of the notes the kernel writes into core dumps, owner "CORE"
+const NT_PRSTATUS: u32 = 1;
+const NT_PRPSINFO: u32 = 3;
+const NT_AUXV: u32 = 6;
+const NT_SIGINFO: u32 = 0x53494749;
+const NT_FILE: u32 = 0x46494C45;
+
+// elf_gregset_t of the architectures whose layout is known, in order
+const X86_64_REGISTERS: [&str; 27] = [
+    "r15", "r14", "r13", "r12", "rbp", "rbx", "r11", "r10", "r9", "r8", "rax", "rcx", "rdx", "rsi",
+    "rdi", "orig_rax", "rip", "cs", "eflags", "rsp", "ss", "fs_base", "gs_base", "ds", "es", "fs",
+    "gs",
+];
+const X86_REGISTERS: [&str; 17] = [
+    "ebx", "ecx", "edx", "esi", "edi", "ebp", "eax", "ds", "es", "fs", "gs", "orig_eax", "eip",
+    "cs", "eflags", "esp", "ss",
+];
+const AARCH64_REGISTERS: [&str; 34] = [
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14",
+    "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27",
+    "x28", "x29", "x30", "sp", "pc", "pstate",
+];
+const ARM_REGISTERS: [&str; 18] = [
+    "r0", "r1", "r2", "r3", "r4", "r5", "r6", "r7", "r8", "r9", "r10", "fp", "ip", "sp", "lr",
+    "pc", "cpsr", "orig_r0",
+];
+const RISCV_REGISTERS: [&str; 32] = [
+    "pc", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4", "a5",
+    "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4", "t5",
+    "t6",
+];
+
+// Signals of the generic Linux numbering, shared by x86, Arm and RISC-V
+const SIGNAL_NAMES: [&str; 31] = [
+    "SIGHUP",
+    "SIGINT",
+    "SIGQUIT",
+    "SIGILL",
+    "SIGTRAP",
+    "SIGABRT",
+    "SIGBUS",
+    "SIGFPE",
+    "SIGKILL",
+    "SIGUSR1",
+    "SIGSEGV",
+    "SIGUSR2",
+    "SIGPIPE",
+    "SIGALRM",
+    "SIGTERM",
+    "SIGSTKFLT",
+    "SIGCHLD",
+    "SIGCONT",
+    "SIGSTOP",
+    "SIGTSTP",
+    "SIGTTIN",
+    "SIGTTOU",
+    "SIGURG",
+    "SIGXCPU",
+    "SIGXFSZ",
+    "SIGVTALRM",
+    "SIGPROF",
+    "SIGWINCH",
+    "SIGIO",
+    "SIGPWR",
+    "SIGSYS",
+];
+const SIGILL: u32 = 4;
+const SIGTRAP: u32 = 5;
+const SIGBUS: u32 = 7;
+const SIGFPE: u32 = 8;
+const SIGSEGV: u32 = 11;
+
+// What a core dump says about the crashed process: the PT_NOTE records of
+// owner CORE and the memory layout they describe.
+#[derive(Debug, Default, Serialize)]
+pub struct ElfCore {
+    // NT_PRPSINFO
+    pub process: Option<ElfCoreProcess>,
+    // NT_SIGINFO of the thread that took the signal
+    pub signal: Option<ElfCoreSignal>,
+    // One NT_PRSTATUS per thread, the thread that took the signal first
+    pub threads: Vec<ElfCoreThread>,
+    // NT_AUXV
+    pub auxv: Vec<ElfAuxvEntry>,
+    // NT_FILE
+    pub files: Vec<ElfCoreFile>,
+    // PT_LOAD segments with the file mapped at their address
+    pub memory_map: Vec<ElfCoreMapping>,
+}
+
+impl std::fmt::Display for ElfCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.process {
+            Some(process) => {
+                writeln!(f, "Command:  {}", process.arguments)?;
+                writeln!(
+                    f,
+                    "Process:  {} (pid {}, ppid {}, uid {}, gid {}, state {})",
+                    process.name,
+                    process.pid,
+                    process.ppid,
+                    process.uid,
+                    process.gid,
+                    process.state
+                )?;
+            }
+            None => writeln!(f, "Process:  -")?,
+        }
+        match &self.signal {
+            Some(signal) => writeln!(f, "Signal:   {}", signal)?,
+            None => writeln!(f, "Signal:   -")?,
+        }
+        write!(f, "Threads:  {}", self.threads.len())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfCoreProcess {
+    // pr_fname, the executable name cut to 15 bytes
+    pub name: String,
+    // pr_psargs, the start of the command line
+    pub arguments: String,
+    pub pid: u32,
+    pub ppid: u32,
+    pub uid: u32,
+    pub gid: u32,
+    // pr_sname, e.g. R for running
+    pub state: char,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfCoreSignal {
+    pub signal: ElfCoreSignalNumber,
+    // si_code, positive when the kernel raised the signal
+    pub code: i32,
+    pub code_name: Option<&'static str>,
+    pub errno: i32,
+    // si_addr of SIGSEGV, SIGBUS, SIGILL, SIGFPE and SIGTRAP faults
+    pub address: Option<u64>,
+    // si_pid and si_uid of signals sent by a process
+    pub sender_pid: Option<u32>,
+    pub sender_uid: Option<u32>,
+}
+
+impl std::fmt::Display for ElfCoreSignal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.signal)?;
+        match self.code_name {
+            Some(name) => write!(f, " ({})", name)?,
+            None => write!(f, " (si_code {})", self.code)?,
+        }
+        if let Some(address) = self.address {
+            write!(f, " at address {:#x}", address)?;
+        }
+        if let (Some(pid), Some(uid)) = (self.sender_pid, self.sender_uid) {
+            write!(f, " sent by pid {} uid {}", pid, uid)?;
+        }
+        if self.errno != 0 {
+            write!(f, ", errno {}", self.errno)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ElfCoreSignalNumber(pub u32);
+
+impl std::fmt::Display for ElfCoreSignalNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            0 => write!(f, "-"),
+            number => match SIGNAL_NAMES.get(number as usize - 1) {
+                Some(name) => write!(f, "{}", name),
+                None => write!(f, "SIG<{}>", number),
+            },
+        }
+    }
+}
+
+impl Serialize for ElfCoreSignalNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.0), self)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfCoreThread {
+    pub thread_pid: u32,
+    // pr_cursig, the signal that stopped the thread
+    pub thread_signal: ElfCoreSignalNumber,
+    // Pending and blocked signal masks
+    pub thread_pending: ElfCoreAddress,
+    pub thread_held: ElfCoreAddress,
+    // User and system CPU time in seconds
+    pub thread_user_time: ElfCoreTime,
+    pub thread_system_time: ElfCoreTime,
+    #[tabled(skip)]
+    pub registers: ElfCoreRegisters,
+}
+
+// A struct timeval
+#[derive(Debug, Default, Serialize)]
+pub struct ElfCoreTime {
+    pub seconds: u64,
+    pub microseconds: u64,
+}
+
+impl std::fmt::Display for ElfCoreTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{:06}", self.seconds, self.microseconds)
+    }
+}
+
+// pr_reg, named for the architectures listed above and `r<n>` otherwise.
+// Serialised as the list of registers.
+#[derive(Debug, Default)]
+pub struct ElfCoreRegisters {
+    pub inner: Vec<ElfCoreRegister>,
+    // Hex digits of a register, for the columns to line up
+    pub digits: usize,
+}
+
+impl Serialize for ElfCoreRegisters {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl std::fmt::Display for ElfCoreRegisters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, register) in self.inner.iter().enumerate() {
+            match index % 4 {
+                0 if index != 0 => writeln!(f)?,
+                0 => {}
+                _ => write!(f, "  ")?,
+            }
+            write!(
+                f,
+                "{:>8} {:0width$X}",
+                register.name,
+                register.value,
+                width = self.digits
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfCoreRegister {
+    pub name: String,
+    pub value: u64,
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfAuxvEntry {
+    pub auxv_type: ElfAuxvType,
+    pub auxv_value: ElfCoreAddress,
+    // Decimal numbers and the strings read from the dumped memory
+    pub auxv_meaning: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ElfAuxvType(pub u64);
+
+impl ElfAuxvType {
+    pub const AT_NULL: u64 = 0;
+    pub const AT_PLATFORM: u64 = 15;
+    pub const AT_BASE_PLATFORM: u64 = 24;
+    pub const AT_EXECFN: u64 = 31;
+    pub const AT_SYSINFO_EHDR: u64 = 33;
+
+    fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "AT_NULL",
+            1 => "AT_IGNORE",
+            2 => "AT_EXECFD",
+            3 => "AT_PHDR",
+            4 => "AT_PHENT",
+            5 => "AT_PHNUM",
+            6 => "AT_PAGESZ",
+            7 => "AT_BASE",
+            8 => "AT_FLAGS",
+            9 => "AT_ENTRY",
+            10 => "AT_NOTELF",
+            11 => "AT_UID",
+            12 => "AT_EUID",
+            13 => "AT_GID",
+            14 => "AT_EGID",
+            15 => "AT_PLATFORM",
+            16 => "AT_HWCAP",
+            17 => "AT_CLKTCK",
+            23 => "AT_SECURE",
+            24 => "AT_BASE_PLATFORM",
+            25 => "AT_RANDOM",
+            26 => "AT_HWCAP2",
+            27 => "AT_RSEQ_FEATURE_SIZE",
+            28 => "AT_RSEQ_ALIGN",
+            29 => "AT_HWCAP3",
+            30 => "AT_HWCAP4",
+            31 => "AT_EXECFN",
+            32 => "AT_SYSINFO",
+            33 => "AT_SYSINFO_EHDR",
+            51 => "AT_MINSIGSTKSZ",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+}
+
+impl std::fmt::Display for ElfAuxvType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "AT_<{:#x}>", self.0),
+        }
+    }
+}
+
+impl Serialize for ElfAuxvType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, self.0, self)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfCoreFile {
+    pub file_start: ElfCoreAddress,
+    pub file_end: ElfCoreAddress,
+    // Offset in the file of the first mapped byte
+    pub file_offset: ElfCoreAddress,
+    pub file_path: String,
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfCoreMapping {
+    pub segment_index: usize,
+    pub segment_start: ElfCoreAddress,
+    pub segment_end: ElfCoreAddress,
+    pub segment_flags: ElfSegmentFlags,
+    // Bytes of the segment present in the core, 0 when the kernel left the
+    // memory out, e.g. for read-only file mappings
+    pub segment_file_size: ElfCoreAddress,
+    // File mapped at the start of the segment per NT_FILE, [vdso] for the
+    // AT_SYSINFO_EHDR image, empty for anonymous memory
+    pub mapping_path: String,
+    pub mapping_offset: ElfCoreOffset,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfCoreAddress(pub u64);
+
+impl std::fmt::Display for ElfCoreAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfCoreOffset(pub Option<u64>);
+
+impl std::fmt::Display for ElfCoreOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(offset) => write!(f, "{:X}", offset),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+fn signal_code_name(signal: u32, code: i32) -> Option<&'static str> {
+    let name = match (signal, code) {
+        (SIGSEGV, 1) => "SEGV_MAPERR",
+        (SIGSEGV, 2) => "SEGV_ACCERR",
+        (SIGSEGV, 3) => "SEGV_BNDERR",
+        (SIGSEGV, 4) => "SEGV_PKUERR",
+        (SIGBUS, 1) => "BUS_ADRALN",
+        (SIGBUS, 2) => "BUS_ADRERR",
+        (SIGBUS, 3) => "BUS_OBJERR",
+        (SIGILL, 1) => "ILL_ILLOPC",
+        (SIGILL, 2) => "ILL_ILLOPN",
+        (SIGILL, 3) => "ILL_ILLADR",
+        (SIGILL, 4) => "ILL_ILLTRP",
+        (SIGILL, 5) => "ILL_PRVOPC",
+        (SIGILL, 6) => "ILL_PRVREG",
+        (SIGILL, 7) => "ILL_COPROC",
+        (SIGILL, 8) => "ILL_BADSTK",
+        (SIGFPE, 1) => "FPE_INTDIV",
+        (SIGFPE, 2) => "FPE_INTOVF",
+        (SIGFPE, 3) => "FPE_FLTDIV",
+        (SIGFPE, 4) => "FPE_FLTOVF",
+        (SIGFPE, 5) => "FPE_FLTUND",
+        (SIGFPE, 6) => "FPE_FLTRES",
+        (SIGFPE, 7) => "FPE_FLTINV",
+        (SIGFPE, 8) => "FPE_FLTSUB",
+        (SIGTRAP, 1) => "TRAP_BRKPT",
+        (SIGTRAP, 2) => "TRAP_TRACE",
+        (_, 0) => "SI_USER",
+        (_, 0x80) => "SI_KERNEL",
+        (_, -1) => "SI_QUEUE",
+        (_, -2) => "SI_TIMER",
+        (_, -3) => "SI_MESGQ",
+        (_, -4) => "SI_ASYNCIO",
+        (_, -5) => "SI_SIGIO",
+        (_, -6) => "SI_TKILL",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// Reads notes through the file so that errors carry file offsets, without
+// running past the end of the descriptor.
+fn note_reader<'data>(content: &'data [u8], note: &ElfNote) -> ElfReader<'data> {
+    let end = (note.note_data_offset + note.note_data.len()).min(content.len());
+    ElfReader::at(&content[..end], note.note_data_offset)
+}
+
+fn fixed_string(bytes: &[u8]) -> String {
+    let end = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+// struct elf_prpsinfo. 32-bit Arm and x86 keep 16-bit ids, which shows in
+// the size of the descriptor.
+fn parse_prpsinfo(
+    content: &[u8],
+    note: &ElfNote,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfCoreProcess, ElfError> {
+    let mut reader = note_reader(content, note);
+    reader.skip("pr_state", 1)?;
+    let state = reader.read_u8("pr_sname")? as char;
+    reader.skip("pr_zomb", 2)?;
+    if let ElfPlatformType::Bit64 = platform {
+        reader.skip("pr_padding", 4)?;
+    }
+    reader.read_word("pr_flag", endian, platform)?;
+    let (uid, gid) = match (platform, note.note_data.len()) {
+        (ElfPlatformType::Bit32, 124) => (
+            u32::from(reader.read_u16("pr_uid", endian)?),
+            u32::from(reader.read_u16("pr_gid", endian)?),
+        ),
+        _ => (
+            reader.read_u32("pr_uid", endian)?,
+            reader.read_u32("pr_gid", endian)?,
+        ),
+    };
+    let pid = reader.read_u32("pr_pid", endian)?;
+    let ppid = reader.read_u32("pr_ppid", endian)?;
+    reader.skip("pr_pgrp", 8)?;
+    let name = fixed_string(reader.read_slice("pr_fname", 16)?);
+    let arguments = fixed_string(reader.read_slice("pr_psargs", 80)?);
+
+    Ok(ElfCoreProcess {
+        name,
+        arguments: arguments.trim_end().to_string(),
+        pid,
+        ppid,
+        uid,
+        gid,
+        state,
+    })
+}
+
+// struct elf_prstatus: signal info, ids and times, then the general purpose
+// registers and pr_fpvalid.
+fn parse_prstatus(
+    content: &[u8],
+    note: &ElfNote,
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfCoreThread, ElfError> {
+    let mut reader = note_reader(content, note);
+    reader.skip("pr_info", 12)?;
+    let signal = reader.read_u16("pr_cursig", endian)?;
+    reader.skip("pr_padding", 2)?;
+    let pending = reader.read_word("pr_sigpend", endian, platform)?;
+    let held = reader.read_word("pr_sighold", endian, platform)?;
+    let pid = reader.read_u32("pr_pid", endian)?;
+    reader.skip("pr_ppid", 12)?;
+    let mut times = [0; 4];
+    for time in times.iter_mut() {
+        *time = reader.read_word("pr_time", endian, platform)? as u64;
+    }
+    let word = match platform {
+        ElfPlatformType::Bit32 => 4,
+        ElfPlatformType::Bit64 => 8,
+    };
+    reader.skip("pr_cutime", 4 * word)?;
+
+    // pr_fpvalid follows the registers, padded to a word in 64-bit files
+    let registers_offset = reader.pointer() - note.note_data_offset;
+    let count = note.note_data.len().saturating_sub(registers_offset + 4) / word;
+    let names: &[&str] = match (machine, platform) {
+        (ElfInstructionSet::AmdX86_64, ElfPlatformType::Bit64) => &X86_64_REGISTERS,
+        (ElfInstructionSet::X86, ElfPlatformType::Bit32) => &X86_REGISTERS,
+        (ElfInstructionSet::Arm64bit, ElfPlatformType::Bit64) => &AARCH64_REGISTERS,
+        (ElfInstructionSet::Arm, ElfPlatformType::Bit32) => &ARM_REGISTERS,
+        (ElfInstructionSet::RiscV, _) => &RISCV_REGISTERS,
+        _ => &[],
+    };
+    let mut registers = Vec::with_capacity(count);
+    for index in 0..count {
+        let name = match names.len() == count {
+            true => names[index].to_string(),
+            false => format!("r{index}"),
+        };
+        let value = reader.read_word("pr_reg", endian, platform)? as u64;
+        registers.push(ElfCoreRegister { name, value });
+    }
+
+    Ok(ElfCoreThread {
+        thread_pid: pid,
+        thread_signal: ElfCoreSignalNumber(u32::from(signal)),
+        thread_pending: ElfCoreAddress(pending as u64),
+        thread_held: ElfCoreAddress(held as u64),
+        thread_user_time: ElfCoreTime {
+            seconds: times[0],
+            microseconds: times[1],
+        },
+        thread_system_time: ElfCoreTime {
+            seconds: times[2],
+            microseconds: times[3],
+        },
+        registers: ElfCoreRegisters {
+            inner: registers,
+            digits: word * 2,
+        },
+    })
+}
+
+// siginfo_t: si_signo, si_errno, si_code, then a union whose member depends
+// on the signal and on who sent it.
+fn parse_siginfo(
+    content: &[u8],
+    note: &ElfNote,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfCoreSignal, ElfError> {
+    let mut reader = note_reader(content, note);
+    let signal = reader.read_u32("si_signo", endian)?;
+    let errno = reader.read_u32("si_errno", endian)? as i32;
+    let code = reader.read_u32("si_code", endian)? as i32;
+    if let ElfPlatformType::Bit64 = platform {
+        reader.skip("si_padding", 4)?;
+    }
+
+    let mut info = ElfCoreSignal {
+        signal: ElfCoreSignalNumber(signal),
+        code,
+        code_name: signal_code_name(signal, code),
+        errno,
+        ..Default::default()
+    };
+    let fault = matches!(signal, SIGILL | SIGTRAP | SIGBUS | SIGFPE | SIGSEGV);
+    if fault && code > 0 && code != 0x80 {
+        info.address = Some(reader.read_word("si_addr", endian, platform)? as u64);
+    } else if code <= 0 {
+        info.sender_pid = Some(reader.read_u32("si_pid", endian)?);
+        info.sender_uid = Some(reader.read_u32("si_uid", endian)?);
+    }
+
+    Ok(info)
+}
+
+// Reads a NUL terminated string from the memory dumped in the PT_LOAD segments.
+fn memory_string(
+    content: &[u8],
+    program_header: &ElfProgramHeader,
+    address: u64,
+) -> Option<String> {
+    let segment = program_header.inner.iter().find(|entry| {
+        let start = entry.segment_vaddr.0 as u64;
+        matches!(entry.segment_type, ElfSegmentType::PtLoad)
+            && address >= start
+            && address - start < entry.segment_file_size.0 as u64
+    })?;
+    let delta = (address - segment.segment_vaddr.0 as u64) as usize;
+    let bytes = slice_at(
+        content,
+        "core memory",
+        segment.segment_offset.0.checked_add(delta)?,
+        segment.segment_file_size.0 - delta,
+    )
+    .ok()?;
+
+    Some(fixed_string(bytes))
+}
+
+// Pairs of a_type and a_val words up to AT_NULL.
+fn parse_auxv(
+    content: &[u8],
+    note: &ElfNote,
+    program_header: &ElfProgramHeader,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<Vec<ElfAuxvEntry>, ElfError> {
+    let mut reader = note_reader(content, note);
+    let end = note.note_data_offset + note.note_data.len();
+    let mut auxv = Vec::new();
+    while reader.pointer() < end {
+        let auxv_type = ElfAuxvType(reader.read_word("a_type", endian, platform)? as u64);
+        let value = reader.read_word("a_val", endian, platform)? as u64;
+        let meaning = match auxv_type.0 {
+            ElfAuxvType::AT_NULL => break,
+            ElfAuxvType::AT_PLATFORM | ElfAuxvType::AT_BASE_PLATFORM | ElfAuxvType::AT_EXECFN => {
+                memory_string(content, program_header, value).unwrap_or_default()
+            }
+            4..=6 | 11..=14 | 17 | 23 | 27 | 28 | 51 => value.to_string(),
+            _ => String::new(),
+        };
+        auxv.push(ElfAuxvEntry {
+            auxv_type,
+            auxv_value: ElfCoreAddress(value),
+            auxv_meaning: meaning,
+        });
+    }
+
+    Ok(auxv)
+}
+
+// A count and the page size, (start, end, page offset) for every mapping,
+// then the paths one after the other.
+fn parse_file_note(
+    content: &[u8],
+    note: &ElfNote,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<Vec<ElfCoreFile>, ElfError> {
+    let mut reader = note_reader(content, note);
+    let count = reader.read_word("nt_file count", endian, platform)?;
+    let page_size = reader.read_word("nt_file page size", endian, platform)? as u64;
+    let word = match platform {
+        ElfPlatformType::Bit32 => 4,
+        ElfPlatformType::Bit64 => 8,
+    };
+    let mut files = Vec::with_capacity(reader.capacity_for(count, 3 * word));
+    for _ in 0..count {
+        let start = reader.read_word("nt_file start", endian, platform)? as u64;
+        let end = reader.read_word("nt_file end", endian, platform)? as u64;
+        let page_offset = reader.read_word("nt_file offset", endian, platform)? as u64;
+        files.push(ElfCoreFile {
+            file_start: ElfCoreAddress(start),
+            file_end: ElfCoreAddress(end),
+            file_offset: ElfCoreAddress(page_offset.wrapping_mul(page_size)),
+            file_path: String::new(),
+        });
+    }
+    let names = &note.note_data[reader.pointer() - note.note_data_offset..];
+    for (file, name) in files.iter_mut().zip(names.split(|byte| *byte == 0)) {
+        file.file_path = String::from_utf8_lossy(name).into_owned();
+    }
+
+    Ok(files)
+}
+
+fn parse_memory_map(
+    program_header: &ElfProgramHeader,
+    files: &[ElfCoreFile],
+    auxv: &[ElfAuxvEntry],
+) -> Vec<ElfCoreMapping> {
+    let vdso = auxv
+        .iter()
+        .find(|entry| entry.auxv_type.0 == ElfAuxvType::AT_SYSINFO_EHDR)
+        .map(|entry| entry.auxv_value.0);
+
+    program_header
+        .inner
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| matches!(entry.segment_type, ElfSegmentType::PtLoad))
+        .map(|(segment_index, entry)| {
+            let start = entry.segment_vaddr.0 as u64;
+            let file = files
+                .iter()
+                .find(|file| file.file_start.0 <= start && start < file.file_end.0);
+            let (mapping_path, mapping_offset) = match file {
+                Some(file) => (
+                    file.file_path.clone(),
+                    Some(file.file_offset.0.wrapping_add(start - file.file_start.0)),
+                ),
+                None if vdso == Some(start) => ("[vdso]".to_string(), None),
+                None => (String::new(), None),
+            };
+            ElfCoreMapping {
+                segment_index,
+                segment_start: ElfCoreAddress(start),
+                segment_end: ElfCoreAddress(start.wrapping_add(entry.segment_memory_size.0 as u64)),
+                segment_flags: ElfSegmentFlags(entry.segment_flags.0),
+                segment_file_size: ElfCoreAddress(entry.segment_file_size.0 as u64),
+                mapping_path,
+                mapping_offset: ElfCoreOffset(mapping_offset),
+            }
+        })
+        .collect()
+}
+
+pub fn parse_core(
+    content: &[u8],
+    header: &ElfHeader,
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+) -> Result<ElfCore, ElfError> {
+    if !matches!(header.object_file_type, ElfObjectFileType::EtCore) {
+        return Err(ElfError::InvalidValue {
+            field: "e_type",
+            offset: 0x10,
+            expected: "ET_CORE",
+            actual: u64::from(header.object_file_type.value()),
+        });
+    }
+
+    let endian = &header.endianness;
+    let platform = &header.platform_type;
+    let note_tables = parse_note_tables(
+        content,
+        program_header,
+        section_header,
+        &header.instruction_set,
+        endian,
+        platform,
+    )?;
+    let mut core = ElfCore::default();
+    let notes = note_tables
+        .inner
+        .iter()
+        .flat_map(|table| table.inner.iter())
+        .filter(|note| note.note_owner == "CORE");
+    for note in notes {
+        match note.note_type.value {
+            NT_PRSTATUS => core.threads.push(parse_prstatus(
+                content,
+                note,
+                &header.instruction_set,
+                endian,
+                platform,
+            )?),
+            NT_PRPSINFO => core.process = Some(parse_prpsinfo(content, note, endian, platform)?),
+            NT_SIGINFO if core.signal.is_none() => {
+                core.signal = Some(parse_siginfo(content, note, endian, platform)?)
+            }
+            NT_AUXV => core.auxv = parse_auxv(content, note, program_header, endian, platform)?,
+            NT_FILE => core.files = parse_file_note(content, note, endian, platform)?,
+            _ => {}
+        }
+    }
+    core.memory_map = parse_memory_map(program_header, &core.files, &core.auxv);
+
+    Ok(core)
+}
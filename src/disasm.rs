@@ -0,0 +1,415 @@
+use serde::Serialize;
+
+use crate::{
+    ElfError, ElfHeader, ElfInstructionSet, ElfObjectFileType, ElfPlatformType, ElfSectionBytes,
+    ElfSectionFlags, ElfSectionHeader, ElfSectionHeaderEntry, ElfSymbol, ElfSymbolTables,
+    ElfSymbolType, entry_offset, section_data,
+};
+
+mod aarch64;
+mod riscv;
+mod x86;
+
+// Offset of sh_flags in a section header entry, the same for both classes
+const SECTION_FLAGS_OFFSET: usize = 8;
+// Width of the raw bytes column, in bytes
+const MAX_BYTES_SHOWN: usize = 8;
+
+// The instructions of an executable section, or of a single function in it
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDisassembly {
+    pub section_name: String,
+    // Set when only the code of this symbol was disassembled
+    pub symbol_name: Option<String>,
+    pub instruction_set: ElfInstructionSet,
+    pub inner: Vec<ElfInstruction>,
+    // Hex digits of the addresses in symbol labels, 16 for 64-bit files
+    #[serde(skip)]
+    pub address_digits: usize,
+}
+
+impl ElfDisassembly {
+    pub fn inner(self) -> Vec<ElfInstruction> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfDisassembly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Disassembly of section {}:", self.section_name)?;
+        // Longer x86 instructions push their mnemonic out of line
+        let bytes_width = self
+            .inner
+            .iter()
+            .map(|instruction| instruction.bytes.0.len().min(MAX_BYTES_SHOWN) * 3)
+            .max()
+            .unwrap_or_default();
+
+        for (index, instruction) in self.inner.iter().enumerate() {
+            if let Some(label) = &instruction.label {
+                writeln!(f)?;
+                write!(
+                    f,
+                    "\n{:0width$x} <{label}>:",
+                    instruction.address,
+                    width = self.address_digits
+                )?;
+            } else if index == 0 {
+                writeln!(f)?;
+            }
+
+            let bytes = instruction
+                .bytes
+                .0
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<Vec<String>>()
+                .join(" ");
+            let text = match instruction.operands.is_empty() {
+                true => instruction.mnemonic.clone(),
+                false => format!("{:<7} {}", instruction.mnemonic, instruction.operands),
+            };
+            write!(
+                f,
+                "\n{:>8x}: {bytes:<bytes_width$} {text}",
+                instruction.address
+            )?;
+            if let Some(symbol) = &instruction.target_symbol {
+                write!(f, " <{symbol}>")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfInstruction {
+    pub address: usize,
+    pub bytes: ElfSectionBytes,
+    pub mnemonic: String,
+    pub operands: String,
+    // Symbol defined at this address
+    pub label: Option<String>,
+    // Address a branch, call or PC-relative access refers to
+    pub target: Option<usize>,
+    // `target` as `symbol+0xoffset`, relative to the closest symbol before it
+    pub target_symbol: Option<String>,
+}
+
+// What a decoder makes of the bytes at an address
+struct DecodedInstruction {
+    length: usize,
+    mnemonic: String,
+    operands: String,
+    target: Option<u64>,
+}
+
+impl DecodedInstruction {
+    // Trailing bytes too short to hold an instruction
+    fn bytes(bytes: &[u8]) -> Self {
+        let operands = bytes
+            .iter()
+            .map(|byte| format!("0x{byte:02x}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        DecodedInstruction {
+            length: bytes.len(),
+            mnemonic: ".byte".to_string(),
+            operands,
+            target: None,
+        }
+    }
+}
+
+enum Decoder {
+    X86(Box<x86::X86Decoder>),
+    AArch64,
+    RiscV(u32),
+}
+
+impl Decoder {
+    fn new(header: &ElfHeader) -> Result<Self, ElfError> {
+        let is_64 = matches!(header.platform_type, ElfPlatformType::Bit64);
+        match header.instruction_set {
+            ElfInstructionSet::X86 => Ok(Decoder::X86(Box::new(x86::X86Decoder::new(32)))),
+            ElfInstructionSet::AmdX86_64 => Ok(Decoder::X86(Box::new(x86::X86Decoder::new(64)))),
+            ElfInstructionSet::Arm64bit => Ok(Decoder::AArch64),
+            ElfInstructionSet::RiscV => Ok(Decoder::RiscV(if is_64 { 64 } else { 32 })),
+            _ => Err(ElfError::InvalidValue {
+                field: "e_machine",
+                offset: 0x12,
+                expected: "x86, x86-64, AArch64 or RISC-V to disassemble",
+                actual: u64::from(header.instruction_set.value()),
+            }),
+        }
+    }
+
+    fn decode(&mut self, bytes: &[u8], address: u64) -> DecodedInstruction {
+        match self {
+            Decoder::X86(decoder) => decoder.decode(bytes, address),
+            Decoder::AArch64 => aarch64::decode(bytes, address),
+            Decoder::RiscV(xlen) => riscv::decode(bytes, address, *xlen),
+        }
+    }
+}
+
+// A symbol that can label code, see `code_symbols`
+struct CodeSymbol {
+    address: usize,
+    section: usize,
+    size: usize,
+    is_function: bool,
+    name: String,
+}
+
+// Symbols naming a place in a section, from .symtab or, in stripped files,
+// .dynsym. Section and file symbols are left out, as are the `$x`/`$d`
+// mapping symbols of Arm and RISC-V objects. Sorted by address with
+// functions last, so that they win when several symbols share an address.
+fn code_symbols(symbol_tables: &ElfSymbolTables) -> Vec<CodeSymbol> {
+    let has_symtab = symbol_tables
+        .inner
+        .iter()
+        .any(|table| table.section_name == ".symtab");
+    let mut symbols = symbol_tables
+        .inner
+        .iter()
+        .filter(|table| !has_symtab || table.section_name == ".symtab")
+        .flat_map(|table| table.inner.iter())
+        .filter(|symbol| {
+            !matches!(
+                symbol.symbol_type,
+                ElfSymbolType::SttSection | ElfSymbolType::SttFile
+            ) && !symbol.symbol_name.0.is_empty()
+                && !symbol.symbol_name.0.starts_with('$')
+        })
+        .filter_map(|symbol: &ElfSymbol| {
+            // Versioned .dynsym names keep only the symbol
+            let name = symbol.symbol_name.0.split('@').next().unwrap_or_default();
+            Some(CodeSymbol {
+                address: symbol.symbol_value.0,
+                section: symbol.symbol_section_index.section()?,
+                size: symbol.symbol_size.0,
+                is_function: matches!(symbol.symbol_type, ElfSymbolType::SttFunc),
+                name: name.to_string(),
+            })
+        })
+        .collect::<Vec<CodeSymbol>>();
+    symbols.sort_by(|a, b| {
+        a.address
+            .cmp(&b.address)
+            .then(a.is_function.cmp(&b.is_function))
+    });
+
+    symbols
+}
+
+// The symbol `address` falls in, as `name` or `name+0xoffset`. Only symbols
+// of `section`, the one holding the address, are considered: an address past
+// the last symbol of a section, such as a PLT stub, is left unnamed rather
+// than attributed to a symbol of an earlier section.
+fn symbolize(symbols: &[&CodeSymbol], section: usize, address: usize) -> Option<String> {
+    let index = symbols.partition_point(|symbol| symbol.address <= address);
+    let nearest = symbols.get(index.checked_sub(1)?)?.address;
+    let symbol = symbols[..index]
+        .iter()
+        .rev()
+        .take_while(|symbol| symbol.address == nearest)
+        .find(|symbol| symbol.section == section)?;
+    match address - symbol.address {
+        0 => Some(symbol.name.clone()),
+        offset => Some(format!("{}+0x{offset:x}", symbol.name)),
+    }
+}
+
+// The index of the loaded section whose addresses hold `address`. TLS
+// sections are skipped, their addresses overlap the sections that follow.
+fn containing_section(section_header: &ElfSectionHeader, address: usize) -> Option<usize> {
+    section_header.inner.iter().position(|entry| {
+        entry.section_flags.contains(ElfSectionFlags::SHF_ALLOC)
+            && !entry.section_flags.contains(ElfSectionFlags::SHF_TLS)
+            && address.wrapping_sub(entry.section_addr.0) < entry.section_size.0
+    })
+}
+
+fn check_executable(
+    header: &ElfHeader,
+    index: usize,
+    entry: &ElfSectionHeaderEntry,
+) -> Result<(), ElfError> {
+    if entry.section_flags.contains(ElfSectionFlags::SHF_EXECINSTR) {
+        return Ok(());
+    }
+    let offset = entry_offset(
+        "section header",
+        header.section_header_offset.0,
+        index,
+        header.section_header_entry_size.0 as usize,
+    )?;
+
+    Err(ElfError::InvalidValue {
+        field: "sh_flags",
+        offset: offset.saturating_add(SECTION_FLAGS_OFFSET),
+        expected: "SHF_EXECINSTR set on the section to disassemble",
+        actual: entry.section_flags.0,
+    })
+}
+
+// Decodes `data[start..end]` of section `index` for the machine in the header.
+fn disassemble(
+    content: &[u8],
+    header: &ElfHeader,
+    section_header: &ElfSectionHeader,
+    index: usize,
+    entry: &ElfSectionHeaderEntry,
+    symbols: &[CodeSymbol],
+    range: std::ops::Range<usize>,
+) -> Result<Vec<ElfInstruction>, ElfError> {
+    let mut decoder = Decoder::new(header)?;
+    let data = section_data(content, entry)?;
+    let data = data
+        .get(range.start.min(data.len())..range.end.min(data.len()))
+        .unwrap_or_default();
+    let base = entry.section_addr.0.wrapping_add(range.start);
+
+    // Symbol values of relocatable objects are offsets into their own
+    // section, only those of the same section can be told apart
+    let relocatable = matches!(header.object_file_type, ElfObjectFileType::EtRel);
+    let labels = symbols
+        .iter()
+        .filter(|symbol| symbol.section == index)
+        .collect::<Vec<&CodeSymbol>>();
+    let targets = match relocatable {
+        true => labels.clone(),
+        false => symbols.iter().collect(),
+    };
+
+    let mut instructions = Vec::new();
+    let mut position = 0;
+    let mut next_label = labels.partition_point(|symbol| symbol.address < base);
+    while position < data.len() {
+        let address = base.wrapping_add(position);
+        let decoded = decoder.decode(&data[position..], address as u64);
+        let length = decoded.length.clamp(1, data.len() - position);
+
+        let mut label = None;
+        while let Some(symbol) = labels.get(next_label) {
+            if symbol.address >= address.wrapping_add(length) {
+                break;
+            }
+            if symbol.address == address {
+                label = Some(symbol.name.clone());
+            }
+            next_label += 1;
+        }
+        let target = decoded.target.map(|target| target as usize);
+
+        instructions.push(ElfInstruction {
+            address,
+            bytes: ElfSectionBytes(data[position..position + length].to_vec()),
+            mnemonic: decoded.mnemonic,
+            operands: decoded.operands,
+            label,
+            target,
+            target_symbol: target.and_then(|target| {
+                let section = match relocatable {
+                    true => index,
+                    false => containing_section(section_header, target)?,
+                };
+                symbolize(&targets, section, target)
+            }),
+        });
+        position += length;
+    }
+
+    Ok(instructions)
+}
+
+fn address_digits(header: &ElfHeader) -> usize {
+    match header.platform_type {
+        ElfPlatformType::Bit32 => 8,
+        ElfPlatformType::Bit64 => 16,
+    }
+}
+
+// Disassembles the section selected by name or index, see
+// `ElfSectionHeader::find`. The section must be flagged SHF_EXECINSTR.
+pub fn parse_disassembly(
+    content: &[u8],
+    header: &ElfHeader,
+    section_header: &ElfSectionHeader,
+    symbol_tables: &ElfSymbolTables,
+    selector: &str,
+) -> Result<ElfDisassembly, ElfError> {
+    let (index, entry) = section_header
+        .find(selector)
+        .ok_or_else(|| ElfError::SectionNotFound(selector.to_string()))?;
+    check_executable(header, index, entry)?;
+    let symbols = code_symbols(symbol_tables);
+
+    Ok(ElfDisassembly {
+        section_name: entry.section_name.inner(),
+        symbol_name: None,
+        instruction_set: header.instruction_set.clone(),
+        inner: disassemble(
+            content,
+            header,
+            section_header,
+            index,
+            entry,
+            &symbols,
+            0..usize::MAX,
+        )?,
+        address_digits: address_digits(header),
+    })
+}
+
+// Disassembles the code of a single symbol, up to the next symbol or the end
+// of its section when its size is 0.
+pub fn parse_symbol_disassembly(
+    content: &[u8],
+    header: &ElfHeader,
+    section_header: &ElfSectionHeader,
+    symbol_tables: &ElfSymbolTables,
+    name: &str,
+) -> Result<ElfDisassembly, ElfError> {
+    let symbols = code_symbols(symbol_tables);
+    let symbol = symbols
+        .iter()
+        .find(|symbol| symbol.name == name)
+        .ok_or_else(|| ElfError::SymbolNotFound(name.to_string()))?;
+    let entry = section_header
+        .inner
+        .get(symbol.section)
+        .ok_or_else(|| ElfError::SectionNotFound(symbol.section.to_string()))?;
+    check_executable(header, symbol.section, entry)?;
+
+    let start = symbol.address.wrapping_sub(entry.section_addr.0);
+    let end = match symbol.size {
+        0 => symbols
+            .iter()
+            .find(|next| next.section == symbol.section && next.address > symbol.address)
+            .map_or(usize::MAX, |next| {
+                next.address.wrapping_sub(entry.section_addr.0)
+            }),
+        size => start.saturating_add(size),
+    };
+
+    Ok(ElfDisassembly {
+        section_name: entry.section_name.inner(),
+        symbol_name: Some(symbol.name.clone()),
+        instruction_set: header.instruction_set.clone(),
+        inner: disassemble(
+            content,
+            header,
+            section_header,
+            symbol.section,
+            entry,
+            &symbols,
+            start..end,
+        )?,
+        address_digits: address_digits(header),
+    })
+}
@@ -0,0 +1,1792 @@
+// A64 decoder for the base instruction set, the common FP and Advanced SIMD
+// instructions, LSE atomics, pointer authentication and BTI. Syntax and
+// aliases follow the Arm ARM as printed by llvm-objdump, anything else is
+// shown as `.inst`.
+
+use super::DecodedInstruction;
+
+const CONDITIONS: [&str; 16] = [
+    "eq", "ne", "hs", "lo", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le", "al", "nv",
+];
+const SHIFTS: [&str; 4] = ["lsl", "lsr", "asr", "ror"];
+const EXTENDS: [&str; 8] = [
+    "uxtb", "uxth", "uxtw", "uxtx", "sxtb", "sxth", "sxtw", "sxtx",
+];
+
+fn bits(word: u32, high: u32, low: u32) -> u32 {
+    (word >> low) & ((1 << (high - low + 1)) - 1)
+}
+
+fn bit(word: u32, n: u32) -> bool {
+    (word >> n) & 1 == 1
+}
+
+fn sign_extend(value: u64, width: u32) -> i64 {
+    let shift = 64 - width;
+    ((value << shift) as i64) >> shift
+}
+
+// General purpose register where 31 is the zero register
+fn gpr(n: u32, x: bool) -> String {
+    match (n, x) {
+        (31, true) => "xzr".to_string(),
+        (31, false) => "wzr".to_string(),
+        (n, true) => format!("x{n}"),
+        (n, false) => format!("w{n}"),
+    }
+}
+
+// General purpose register where 31 is the stack pointer
+fn gpr_sp(n: u32, x: bool) -> String {
+    match (n, x) {
+        (31, true) => "sp".to_string(),
+        (31, false) => "wsp".to_string(),
+        _ => gpr(n, x),
+    }
+}
+
+// Scalar SIMD&FP register for an access of 1 << `scale` bytes
+fn fpr(n: u32, scale: u32) -> String {
+    let prefix = ["b", "h", "s", "d", "q"][scale as usize];
+    format!("{prefix}{n}")
+}
+
+// Register of a scalar FP instruction from its `ftype` field
+fn fp_type_scale(ftype: u32) -> Option<u32> {
+    match ftype {
+        0b00 => Some(2),
+        0b01 => Some(3),
+        0b11 => Some(1),
+        _ => None,
+    }
+}
+
+fn instruction(mnemonic: &str, operands: String) -> Option<DecodedInstruction> {
+    Some(DecodedInstruction {
+        length: 4,
+        mnemonic: mnemonic.to_string(),
+        operands,
+        target: None,
+    })
+}
+
+fn branch(mnemonic: &str, operands: String, target: u64) -> Option<DecodedInstruction> {
+    Some(DecodedInstruction {
+        length: 4,
+        mnemonic: mnemonic.to_string(),
+        operands,
+        target: Some(target),
+    })
+}
+
+fn offset_target(address: u64, offset: i64) -> u64 {
+    address.wrapping_add(offset as u64)
+}
+
+pub(super) fn decode(bytes: &[u8], address: u64) -> DecodedInstruction {
+    let Some(word) = bytes.get(..4) else {
+        return DecodedInstruction::bytes(bytes);
+    };
+    let word = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+
+    let decoded = match bits(word, 28, 25) {
+        // The only allocated encoding of the reserved group
+        0b0000 if bits(word, 31, 16) == 0 => instruction("udf", format!("#{}", bits(word, 15, 0))),
+        0b1000 | 0b1001 => data_processing_immediate(word, address),
+        0b1010 | 0b1011 => branch_system(word, address),
+        0b0100 | 0b0110 | 0b1100 | 0b1110 => load_store(word, address),
+        0b0101 | 0b1101 => data_processing_register(word),
+        0b0111 | 0b1111 => simd_fp(word),
+        _ => None,
+    };
+
+    decoded.unwrap_or_else(|| DecodedInstruction {
+        length: 4,
+        mnemonic: ".inst".to_string(),
+        operands: format!("0x{word:08x}"),
+        target: None,
+    })
+}
+
+// DecodeBitMasks from the Arm ARM, returns the `wmask` of a logical immediate
+fn decode_bit_mask(n: u32, imms: u32, immr: u32, x: bool) -> Option<u64> {
+    let combined = (n << 6) | (!imms & 0x3F);
+    if combined == 0 {
+        return None;
+    }
+    let len = 31 - combined.leading_zeros();
+    if len < 1 || (!x && len > 5) {
+        return None;
+    }
+    let size = 1u32 << len;
+    let levels = size - 1;
+    let s = imms & levels;
+    let r = immr & levels;
+    if s == levels {
+        return None;
+    }
+
+    let welem = (1u64 << (s + 1)) - 1;
+    let element = match r {
+        0 => welem,
+        r => ((welem >> r) | (welem << (size - r))) & mask(size),
+    };
+    let mut value = element;
+    let mut filled = size;
+    while filled < 64 {
+        value |= value << filled;
+        filled *= 2;
+    }
+
+    Some(match x {
+        true => value,
+        false => value & 0xFFFF_FFFF,
+    })
+}
+
+fn mask(width: u32) -> u64 {
+    match width {
+        64 => u64::MAX,
+        width => (1u64 << width) - 1,
+    }
+}
+
+// MoveWidePreferred from the Arm ARM, ORR with an immediate a MOVZ or MOVN
+// could build is not shown as `mov`
+fn move_wide_preferred(x: bool, n: u32, imms: u32, immr: u32) -> bool {
+    let width = if x { 64 } else { 32 };
+    if x && n != 1 || !x && (n != 0 || imms & 0x20 != 0) {
+        return false;
+    }
+    if imms < 16 {
+        return (immr.wrapping_neg() % 16) <= 15 - imms;
+    }
+    if imms >= width - 15 {
+        return immr % 16 <= imms - (width - 15);
+    }
+
+    false
+}
+
+fn data_processing_immediate(word: u32, address: u64) -> Option<DecodedInstruction> {
+    let x = bit(word, 31);
+    let rd = bits(word, 4, 0);
+    let rn = bits(word, 9, 5);
+
+    match bits(word, 25, 23) {
+        // ADR, ADRP
+        0b000 | 0b001 => {
+            let imm = sign_extend(((bits(word, 23, 5) << 2) | bits(word, 30, 29)) as u64, 21);
+            match bit(word, 31) {
+                false => branch(
+                    "adr",
+                    format!("{}, #{imm}", gpr(rd, true)),
+                    offset_target(address, imm),
+                ),
+                true => {
+                    let target = offset_target(address & !0xFFF, imm << 12);
+                    branch("adrp", format!("{}, 0x{target:x}", gpr(rd, true)), target)
+                }
+            }
+        }
+        // Add/subtract (immediate)
+        0b010 => {
+            let sub = bit(word, 30);
+            let set_flags = bit(word, 29);
+            let imm = bits(word, 21, 10);
+            let shift = match bit(word, 22) {
+                true => ", lsl #12",
+                false => "",
+            };
+            let mnemonic = match (sub, set_flags) {
+                (false, false) => "add",
+                (false, true) => "adds",
+                (true, false) => "sub",
+                (true, true) => "subs",
+            };
+            if set_flags && rd == 31 {
+                let alias = if sub { "cmp" } else { "cmn" };
+                return instruction(alias, format!("{}, #{imm}{shift}", gpr_sp(rn, x)));
+            }
+            if !set_flags && !sub && imm == 0 && !bit(word, 22) && (rd == 31 || rn == 31) {
+                return instruction("mov", format!("{}, {}", gpr_sp(rd, x), gpr_sp(rn, x)));
+            }
+            let destination = match set_flags {
+                true => gpr(rd, x),
+                false => gpr_sp(rd, x),
+            };
+            instruction(
+                mnemonic,
+                format!("{destination}, {}, #{imm}{shift}", gpr_sp(rn, x)),
+            )
+        }
+        // Logical (immediate)
+        0b100 => {
+            let n = bits(word, 22, 22);
+            let immr = bits(word, 21, 16);
+            let imms = bits(word, 15, 10);
+            if !x && n == 1 {
+                return None;
+            }
+            let imm = decode_bit_mask(n, imms, immr, x)?;
+            let opc = bits(word, 30, 29);
+            if opc == 0b11 && rd == 31 {
+                return instruction("tst", format!("{}, #0x{imm:x}", gpr(rn, x)));
+            }
+            if opc == 0b01 && rn == 31 && !move_wide_preferred(x, n, imms, immr) {
+                return instruction("mov", format!("{}, #{}", gpr_sp(rd, x), signed(imm, x)));
+            }
+            let (mnemonic, destination) = match opc {
+                0b00 => ("and", gpr_sp(rd, x)),
+                0b01 => ("orr", gpr_sp(rd, x)),
+                0b10 => ("eor", gpr_sp(rd, x)),
+                _ => ("ands", gpr(rd, x)),
+            };
+            instruction(
+                mnemonic,
+                format!("{destination}, {}, #0x{imm:x}", gpr(rn, x)),
+            )
+        }
+        // Move wide (immediate)
+        0b101 => {
+            let hw = bits(word, 22, 21);
+            if !x && hw > 1 {
+                return None;
+            }
+            let imm = bits(word, 20, 5) as u64;
+            let shift = hw * 16;
+            match bits(word, 30, 29) {
+                0b00 => {
+                    let value = !(imm << shift);
+                    let value = if x { value } else { value & 0xFFFF_FFFF };
+                    if (imm != 0 || hw == 0) && (x || imm != 0xFFFF) {
+                        return instruction(
+                            "mov",
+                            format!("{}, #{}", gpr(rd, x), signed(value, x)),
+                        );
+                    }
+                    instruction("movn", format!("{}, #{imm}{}", gpr(rd, x), lsl(shift)))
+                }
+                0b10 => {
+                    if imm != 0 || hw == 0 {
+                        return instruction(
+                            "mov",
+                            format!("{}, #{}", gpr(rd, x), signed(imm << shift, x)),
+                        );
+                    }
+                    instruction("movz", format!("{}, #{imm}{}", gpr(rd, x), lsl(shift)))
+                }
+                0b11 => instruction("movk", format!("{}, #{imm}{}", gpr(rd, x), lsl(shift))),
+                _ => None,
+            }
+        }
+        // Bitfield
+        0b110 => bitfield(word),
+        // Extract
+        0b111 => {
+            if bits(word, 30, 29) != 0 || bit(word, 21) || bit(word, 22) != x {
+                return None;
+            }
+            let rm = bits(word, 20, 16);
+            let imms = bits(word, 15, 10);
+            if !x && imms > 31 {
+                return None;
+            }
+            match rn == rm {
+                true => instruction("ror", format!("{}, {}, #{imms}", gpr(rd, x), gpr(rn, x))),
+                false => instruction(
+                    "extr",
+                    format!("{}, {}, {}, #{imms}", gpr(rd, x), gpr(rn, x), gpr(rm, x)),
+                ),
+            }
+        }
+        _ => None,
+    }
+}
+
+fn lsl(shift: u32) -> String {
+    match shift {
+        0 => String::new(),
+        shift => format!(", lsl #{shift}"),
+    }
+}
+
+// Immediate of a `mov` alias, printed as a signed value of the register width
+fn signed(value: u64, x: bool) -> i64 {
+    match x {
+        true => value as i64,
+        false => value as u32 as i32 as i64,
+    }
+}
+
+fn bitfield(word: u32) -> Option<DecodedInstruction> {
+    let x = bit(word, 31);
+    let n = bit(word, 22);
+    if x != n {
+        return None;
+    }
+    let immr = bits(word, 21, 16);
+    let imms = bits(word, 15, 10);
+    let size = if x { 64 } else { 32 };
+    if !x && (immr > 31 || imms > 31) {
+        return None;
+    }
+    let rd = gpr(bits(word, 4, 0), x);
+    let rn_index = bits(word, 9, 5);
+    let rn = gpr(rn_index, x);
+
+    match bits(word, 30, 29) {
+        // SBFM
+        0b00 => {
+            if imms == size - 1 {
+                return instruction("asr", format!("{rd}, {rn}, #{immr}"));
+            }
+            if imms < immr {
+                return instruction(
+                    "sbfiz",
+                    format!("{rd}, {rn}, #{}, #{}", (size - immr) % size, imms + 1),
+                );
+            }
+            if immr == 0 {
+                let alias = match imms {
+                    7 => Some("sxtb"),
+                    15 => Some("sxth"),
+                    31 if x => Some("sxtw"),
+                    _ => None,
+                };
+                if let Some(alias) = alias {
+                    return instruction(alias, format!("{rd}, {}", gpr(rn_index, false)));
+                }
+            }
+            instruction("sbfx", format!("{rd}, {rn}, #{immr}, #{}", imms - immr + 1))
+        }
+        // BFM
+        0b01 => {
+            if imms < immr {
+                if rn_index == 31 {
+                    return instruction(
+                        "bfc",
+                        format!("{rd}, #{}, #{}", (size - immr) % size, imms + 1),
+                    );
+                }
+                return instruction(
+                    "bfi",
+                    format!("{rd}, {rn}, #{}, #{}", (size - immr) % size, imms + 1),
+                );
+            }
+            instruction(
+                "bfxil",
+                format!("{rd}, {rn}, #{immr}, #{}", imms - immr + 1),
+            )
+        }
+        // UBFM
+        0b10 => {
+            if imms != size - 1 && imms + 1 == immr {
+                return instruction("lsl", format!("{rd}, {rn}, #{}", size - 1 - imms));
+            }
+            if imms == size - 1 {
+                return instruction("lsr", format!("{rd}, {rn}, #{immr}"));
+            }
+            if imms < immr {
+                return instruction(
+                    "ubfiz",
+                    format!("{rd}, {rn}, #{}, #{}", (size - immr) % size, imms + 1),
+                );
+            }
+            if immr == 0 && !x {
+                match imms {
+                    7 => return instruction("uxtb", format!("{rd}, {rn}")),
+                    15 => return instruction("uxth", format!("{rd}, {rn}")),
+                    _ => {}
+                }
+            }
+            instruction("ubfx", format!("{rd}, {rn}, #{immr}, #{}", imms - immr + 1))
+        }
+        _ => None,
+    }
+}
+
+const HINTS: [(u32, &str); 27] = [
+    (0, "nop"),
+    (1, "yield"),
+    (2, "wfe"),
+    (3, "wfi"),
+    (4, "sev"),
+    (5, "sevl"),
+    (7, "xpaclri"),
+    (8, "pacia1716"),
+    (10, "pacib1716"),
+    (12, "autia1716"),
+    (14, "autib1716"),
+    (16, "esb"),
+    (17, "psb csync"),
+    (18, "tsb csync"),
+    (20, "csdb"),
+    (24, "paciaz"),
+    (25, "paciasp"),
+    (26, "pacibz"),
+    (27, "pacibsp"),
+    (28, "autiaz"),
+    (29, "autiasp"),
+    (30, "autibz"),
+    (31, "autibsp"),
+    (32, "bti"),
+    (34, "bti"),
+    (36, "bti"),
+    (38, "bti"),
+];
+
+const BARRIER_OPTIONS: [&str; 16] = [
+    "#0x0", "oshld", "oshst", "osh", "#0x4", "nshld", "nshst", "nsh", "#0x8", "ishld", "ishst",
+    "ish", "#0xc", "ld", "st", "sy",
+];
+
+// Encoding of a system register as (op0, op1, CRn, CRm, op2)
+type SystemRegister = (u32, u32, u32, u32, u32);
+
+// System registers worth naming
+const SYSTEM_REGISTERS: [(SystemRegister, &str); 14] = [
+    ((3, 3, 13, 0, 2), "TPIDR_EL0"),
+    ((3, 3, 13, 0, 3), "TPIDRRO_EL0"),
+    ((3, 3, 4, 2, 0), "NZCV"),
+    ((3, 3, 4, 2, 1), "DAIF"),
+    ((3, 3, 4, 4, 0), "FPCR"),
+    ((3, 3, 4, 4, 1), "FPSR"),
+    ((3, 3, 0, 0, 1), "CTR_EL0"),
+    ((3, 3, 0, 0, 7), "DCZID_EL0"),
+    ((3, 3, 14, 0, 0), "CNTFRQ_EL0"),
+    ((3, 3, 14, 0, 1), "CNTPCT_EL0"),
+    ((3, 3, 14, 0, 2), "CNTVCT_EL0"),
+    ((3, 0, 0, 0, 0), "MIDR_EL1"),
+    ((3, 0, 4, 2, 2), "CurrentEL"),
+    ((3, 0, 4, 1, 0), "SP_EL0"),
+];
+
+fn system_register(word: u32) -> String {
+    let key = (
+        2 + bits(word, 19, 19),
+        bits(word, 18, 16),
+        bits(word, 15, 12),
+        bits(word, 11, 8),
+        bits(word, 7, 5),
+    );
+    match SYSTEM_REGISTERS
+        .iter()
+        .find(|(encoding, _)| *encoding == key)
+    {
+        Some((_, name)) => name.to_string(),
+        None => format!("S{}_{}_C{}_C{}_{}", key.0, key.1, key.2, key.3, key.4),
+    }
+}
+
+fn branch_system(word: u32, address: u64) -> Option<DecodedInstruction> {
+    let rt = bits(word, 4, 0);
+
+    // B, BL
+    if bits(word, 30, 26) == 0b00101 {
+        let target = offset_target(address, sign_extend(bits(word, 25, 0) as u64, 26) << 2);
+        let mnemonic = if bit(word, 31) { "bl" } else { "b" };
+        return branch(mnemonic, format!("0x{target:x}"), target);
+    }
+    // CBZ, CBNZ
+    if bits(word, 30, 25) == 0b011010 {
+        let target = offset_target(address, sign_extend(bits(word, 23, 5) as u64, 19) << 2);
+        let mnemonic = if bit(word, 24) { "cbnz" } else { "cbz" };
+        return branch(
+            mnemonic,
+            format!("{}, 0x{target:x}", gpr(rt, bit(word, 31))),
+            target,
+        );
+    }
+    // TBZ, TBNZ
+    if bits(word, 30, 25) == 0b011011 {
+        let target = offset_target(address, sign_extend(bits(word, 18, 5) as u64, 14) << 2);
+        let bit_number = (bits(word, 31, 31) << 5) | bits(word, 23, 19);
+        let mnemonic = if bit(word, 24) { "tbnz" } else { "tbz" };
+        return branch(
+            mnemonic,
+            format!("{}, #{bit_number}, 0x{target:x}", gpr(rt, bit(word, 31))),
+            target,
+        );
+    }
+    // B.cond
+    if bits(word, 31, 24) == 0b0101_0100 && !bit(word, 4) {
+        let target = offset_target(address, sign_extend(bits(word, 23, 5) as u64, 19) << 2);
+        let mnemonic = format!("b.{}", CONDITIONS[bits(word, 3, 0) as usize]);
+        return branch(&mnemonic, format!("0x{target:x}"), target);
+    }
+    // Exception generation
+    if bits(word, 31, 24) == 0b1101_0100 {
+        let imm = bits(word, 20, 5);
+        let mnemonic = match (bits(word, 23, 21), bits(word, 4, 0)) {
+            (0b000, 0b00001) => "svc",
+            (0b000, 0b00010) => "hvc",
+            (0b000, 0b00011) => "smc",
+            (0b001, 0b00000) => "brk",
+            (0b010, 0b00000) => "hlt",
+            _ => return None,
+        };
+        return match imm {
+            0 => instruction(mnemonic, "#0".to_string()),
+            imm => instruction(mnemonic, format!("#0x{imm:x}")),
+        };
+    }
+    // System
+    if bits(word, 31, 22) == 0b11_0101_0100 {
+        return system(word);
+    }
+    // Unconditional branch (register)
+    if bits(word, 31, 25) == 0b1101011 {
+        return branch_register(word);
+    }
+
+    None
+}
+
+fn system(word: u32) -> Option<DecodedInstruction> {
+    let load = bit(word, 21);
+    let op0 = bits(word, 20, 19);
+    let op1 = bits(word, 18, 16);
+    let crn = bits(word, 15, 12);
+    let crm = bits(word, 11, 8);
+    let op2 = bits(word, 7, 5);
+    let rt = bits(word, 4, 0);
+
+    if !load && op0 == 0 && op1 == 0b011 && crn == 0b0010 && rt == 31 {
+        let hint = (crm << 3) | op2;
+        return match HINTS.iter().find(|(value, _)| *value == hint) {
+            Some((32, name)) => instruction(name, String::new()),
+            Some((34, name)) => instruction(name, "c".to_string()),
+            Some((36, name)) => instruction(name, "j".to_string()),
+            Some((38, name)) => instruction(name, "jc".to_string()),
+            Some((_, name)) => instruction(name, String::new()),
+            None => instruction("hint", format!("#{hint}")),
+        };
+    }
+    if !load && op0 == 0 && op1 == 0b011 && crn == 0b0011 && rt == 31 {
+        return match op2 {
+            0b010 if crm == 15 => instruction("clrex", String::new()),
+            0b010 => instruction("clrex", format!("#{crm}")),
+            0b100 => instruction("dsb", BARRIER_OPTIONS[crm as usize].to_string()),
+            0b101 => instruction("dmb", BARRIER_OPTIONS[crm as usize].to_string()),
+            0b110 if crm == 15 => instruction("isb", String::new()),
+            0b110 => instruction("isb", format!("#{crm}")),
+            _ => None,
+        };
+    }
+    if op0 == 0b01 {
+        let operands = format!("#{op1}, c{crn}, c{crm}, #{op2}");
+        return match (load, rt) {
+            (false, 31) => instruction("sys", operands),
+            (false, _) => instruction("sys", format!("{operands}, {}", gpr(rt, true))),
+            (true, _) => instruction(
+                "sysl",
+                format!("{}, #{op1}, c{crn}, c{crm}, #{op2}", gpr(rt, true)),
+            ),
+        };
+    }
+    if op0 >= 0b10 {
+        return match load {
+            true => instruction(
+                "mrs",
+                format!("{}, {}", gpr(rt, true), system_register(word)),
+            ),
+            false => instruction(
+                "msr",
+                format!("{}, {}", system_register(word), gpr(rt, true)),
+            ),
+        };
+    }
+
+    None
+}
+
+fn branch_register(word: u32) -> Option<DecodedInstruction> {
+    let opc = bits(word, 24, 21);
+    let op2 = bits(word, 20, 16);
+    let op3 = bits(word, 15, 10);
+    let rn = bits(word, 9, 5);
+    let op4 = bits(word, 4, 0);
+    if op2 != 0b11111 {
+        return None;
+    }
+
+    match (opc, op3, op4) {
+        (0b0000, 0, 0) => instruction("br", gpr(rn, true)),
+        (0b0001, 0, 0) => instruction("blr", gpr(rn, true)),
+        (0b0010, 0, 0) if rn == 30 => instruction("ret", String::new()),
+        (0b0010, 0, 0) => instruction("ret", gpr(rn, true)),
+        (0b0100, 0, 0) if rn == 31 => instruction("eret", String::new()),
+        (0b0101, 0, 0) if rn == 31 => instruction("drps", String::new()),
+        (0b0010, 0b000010, 31) if rn == 31 => instruction("retaa", String::new()),
+        (0b0010, 0b000011, 31) if rn == 31 => instruction("retab", String::new()),
+        (0b0100, 0b000010, 31) if rn == 31 => instruction("eretaa", String::new()),
+        (0b0100, 0b000011, 31) if rn == 31 => instruction("eretab", String::new()),
+        (0b0000, 0b000010, 31) => instruction("braaz", gpr(rn, true)),
+        (0b0000, 0b000011, 31) => instruction("brabz", gpr(rn, true)),
+        (0b0001, 0b000010, 31) => instruction("blraaz", gpr(rn, true)),
+        (0b0001, 0b000011, 31) => instruction("blrabz", gpr(rn, true)),
+        (0b1000, 0b000010, _) => {
+            instruction("braa", format!("{}, {}", gpr(rn, true), gpr_sp(op4, true)))
+        }
+        (0b1000, 0b000011, _) => {
+            instruction("brab", format!("{}, {}", gpr(rn, true), gpr_sp(op4, true)))
+        }
+        (0b1001, 0b000010, _) => {
+            instruction("blraa", format!("{}, {}", gpr(rn, true), gpr_sp(op4, true)))
+        }
+        (0b1001, 0b000011, _) => {
+            instruction("blrab", format!("{}, {}", gpr(rn, true), gpr_sp(op4, true)))
+        }
+        _ => None,
+    }
+}
+
+// Mnemonic and register of a single register load or store from size, V and
+// opc, with the log2 of the access size. `prefix` picks the ldur/ldtr forms.
+fn load_store_register_kind(word: u32, prefix: &str) -> Option<(String, String, u32)> {
+    let size = bits(word, 31, 30);
+    let vector = bit(word, 26);
+    let opc = bits(word, 23, 22);
+    let rt = bits(word, 4, 0);
+
+    if vector {
+        let (load, scale) = match (size, opc) {
+            (0b00, 0b00) => (false, 0),
+            (0b00, 0b01) => (true, 0),
+            (0b00, 0b10) => (false, 4),
+            (0b00, 0b11) => (true, 4),
+            (_, 0b00) => (false, size),
+            (_, 0b01) => (true, size),
+            _ => return None,
+        };
+        if prefix == "t" {
+            return None;
+        }
+        let base = if load { "ld" } else { "st" };
+        return Some((
+            format!("{base}{}r", prefix_u(prefix)),
+            fpr(rt, scale),
+            scale,
+        ));
+    }
+
+    let suffix = ["b", "h", "", ""][size as usize];
+    let (mnemonic, x) = match (size, opc) {
+        (_, 0b00) => (format!("st{}r{suffix}", prefix_u(prefix)), size == 3),
+        (_, 0b01) => (format!("ld{}r{suffix}", prefix_u(prefix)), size == 3),
+        (0b00 | 0b01, 0b10) => (format!("ld{}rs{suffix}", prefix_u(prefix)), true),
+        (0b00 | 0b01, 0b11) => (format!("ld{}rs{suffix}", prefix_u(prefix)), false),
+        (0b10, 0b10) => (format!("ld{}rsw", prefix_u(prefix)), true),
+        (0b11, 0b10) if prefix != "t" => (format!("prf{}m", prefix_u(prefix)), true),
+        _ => return None,
+    };
+    let register = match mnemonic.starts_with("prf") {
+        true => prefetch_operation(rt),
+        false => gpr(rt, x),
+    };
+
+    Some((mnemonic, register, size))
+}
+
+fn prefix_u(prefix: &str) -> &str {
+    match prefix {
+        "u" => "u",
+        "t" => "t",
+        _ => "",
+    }
+}
+
+fn prefetch_operation(rt: u32) -> String {
+    let kind = match bits(rt, 4, 3) {
+        0b00 => "pld",
+        0b01 => "pli",
+        0b10 => "pst",
+        _ => return format!("#{rt}"),
+    };
+    let target = match bits(rt, 2, 1) {
+        0b00 => "l1",
+        0b01 => "l2",
+        0b10 => "l3",
+        _ => return format!("#{rt}"),
+    };
+    let policy = if bit(rt, 0) { "strm" } else { "keep" };
+    format!("{kind}{target}{policy}")
+}
+
+fn load_store(word: u32, address: u64) -> Option<DecodedInstruction> {
+    let rt = bits(word, 4, 0);
+    let rn = bits(word, 9, 5);
+
+    // Load register (literal)
+    if bits(word, 29, 27) == 0b011 && bits(word, 25, 24) == 0b00 {
+        let target = offset_target(address, sign_extend(bits(word, 23, 5) as u64, 19) << 2);
+        let (mnemonic, register) = match (bits(word, 31, 30), bit(word, 26)) {
+            (0b00, false) => ("ldr", gpr(rt, false)),
+            (0b01, false) => ("ldr", gpr(rt, true)),
+            (0b10, false) => ("ldrsw", gpr(rt, true)),
+            (0b11, false) => ("prfm", prefetch_operation(rt)),
+            (0b00, true) => ("ldr", fpr(rt, 2)),
+            (0b01, true) => ("ldr", fpr(rt, 3)),
+            (0b10, true) => ("ldr", fpr(rt, 4)),
+            _ => return None,
+        };
+        return branch(mnemonic, format!("{register}, 0x{target:x}"), target);
+    }
+
+    // Load/store register pair
+    if bits(word, 29, 27) == 0b101 {
+        return load_store_pair(word);
+    }
+
+    // Load/store exclusive and ordered
+    if bits(word, 29, 24) == 0b001000 && !bit(word, 26) {
+        return load_store_exclusive(word);
+    }
+
+    if bits(word, 29, 27) != 0b111 {
+        return None;
+    }
+    let base = gpr_sp(rn, true);
+
+    // Load/store register (unsigned immediate)
+    if bits(word, 25, 24) == 0b01 {
+        let (mnemonic, register, scale) = load_store_register_kind(word, "")?;
+        let offset = bits(word, 21, 10) << scale;
+        return match offset {
+            0 => instruction(&mnemonic, format!("{register}, [{base}]")),
+            offset => instruction(&mnemonic, format!("{register}, [{base}, #{offset}]")),
+        };
+    }
+    if bits(word, 25, 24) != 0b00 {
+        return None;
+    }
+
+    if !bit(word, 21) {
+        let imm = sign_extend(bits(word, 20, 12) as u64, 9);
+        return match bits(word, 11, 10) {
+            0b00 => {
+                let (mnemonic, register, _) = load_store_register_kind(word, "u")?;
+                match imm {
+                    0 => instruction(&mnemonic, format!("{register}, [{base}]")),
+                    imm => instruction(&mnemonic, format!("{register}, [{base}, #{imm}]")),
+                }
+            }
+            0b01 => {
+                let (mnemonic, register, _) = load_store_register_kind(word, "")?;
+                if mnemonic.starts_with("prf") {
+                    return None;
+                }
+                instruction(&mnemonic, format!("{register}, [{base}], #{imm}"))
+            }
+            0b10 => {
+                let (mnemonic, register, _) = load_store_register_kind(word, "t")?;
+                match imm {
+                    0 => instruction(&mnemonic, format!("{register}, [{base}]")),
+                    imm => instruction(&mnemonic, format!("{register}, [{base}, #{imm}]")),
+                }
+            }
+            _ => {
+                let (mnemonic, register, _) = load_store_register_kind(word, "")?;
+                if mnemonic.starts_with("prf") {
+                    return None;
+                }
+                instruction(&mnemonic, format!("{register}, [{base}, #{imm}]!"))
+            }
+        };
+    }
+
+    match bits(word, 11, 10) {
+        // Load/store register (register offset)
+        0b10 => {
+            let (mnemonic, register, scale) = load_store_register_kind(word, "")?;
+            let option = bits(word, 15, 13);
+            let rm = bits(word, 20, 16);
+            let index = match option {
+                0b010 | 0b110 => gpr(rm, false),
+                0b011 | 0b111 => gpr(rm, true),
+                _ => return None,
+            };
+            let shift = match bit(word, 12) {
+                true => format!(" #{scale}"),
+                false => String::new(),
+            };
+            let extend = match (option, bit(word, 12)) {
+                (0b011, false) => String::new(),
+                (0b011, true) => format!(", lsl{shift}"),
+                (option, _) => format!(", {}{shift}", EXTENDS[option as usize]),
+            };
+            instruction(&mnemonic, format!("{register}, [{base}, {index}{extend}]"))
+        }
+        // Atomic memory operations
+        0b00 if !bit(word, 26) => atomic(word),
+        _ => None,
+    }
+}
+
+fn load_store_pair(word: u32) -> Option<DecodedInstruction> {
+    let opc = bits(word, 31, 30);
+    let vector = bit(word, 26);
+    let load = bit(word, 22);
+    let rt = bits(word, 4, 0);
+    let rt2 = bits(word, 14, 10);
+    let base = gpr_sp(bits(word, 9, 5), true);
+
+    let (scale, register): (u32, fn(u32) -> String) = match (vector, opc) {
+        (false, 0b00) => (2, |n| gpr(n, false)),
+        (false, 0b01) if load => (2, |n| gpr(n, true)),
+        (false, 0b10) => (3, |n| gpr(n, true)),
+        (true, 0b00) => (2, |n| fpr(n, 2)),
+        (true, 0b01) => (3, |n| fpr(n, 3)),
+        (true, 0b10) => (4, |n| fpr(n, 4)),
+        _ => return None,
+    };
+    let mode = bits(word, 24, 23);
+    let mnemonic = match (mode, load, !vector && opc == 0b01) {
+        (0b00, false, _) => "stnp",
+        (0b00, true, _) => "ldnp",
+        (_, true, true) => "ldpsw",
+        (_, false, _) => "stp",
+        (_, true, _) => "ldp",
+    };
+    if mode == 0b00 && !vector && opc == 0b01 {
+        return None;
+    }
+    let offset = sign_extend(bits(word, 21, 15) as u64, 7) << scale;
+    let registers = format!("{}, {}", register(rt), register(rt2));
+
+    match (mode, offset) {
+        (0b01, offset) => instruction(mnemonic, format!("{registers}, [{base}], #{offset}")),
+        (0b11, offset) => instruction(mnemonic, format!("{registers}, [{base}, #{offset}]!")),
+        (_, 0) => instruction(mnemonic, format!("{registers}, [{base}]")),
+        (_, offset) => instruction(mnemonic, format!("{registers}, [{base}, #{offset}]")),
+    }
+}
+
+fn load_store_exclusive(word: u32) -> Option<DecodedInstruction> {
+    let size = bits(word, 31, 30);
+    let o2 = bit(word, 23);
+    let load = bit(word, 22);
+    let o1 = bit(word, 21);
+    let rs = bits(word, 20, 16);
+    let o0 = bit(word, 15);
+    let rt2 = bits(word, 14, 10);
+    let rt = bits(word, 4, 0);
+    let base = gpr_sp(bits(word, 9, 5), true);
+    let x = size == 0b11;
+    let suffix = ["b", "h", "", ""][size as usize];
+
+    if o1 {
+        // Exclusive pairs only, compare and swap needs LSE
+        if o2 || size < 0b10 {
+            return None;
+        }
+        let mnemonic = match (load, o0) {
+            (false, false) => "stxp",
+            (false, true) => "stlxp",
+            (true, false) => "ldxp",
+            (true, true) => "ldaxp",
+        };
+        let pair = format!("{}, {}", gpr(rt, x), gpr(rt2, x));
+        return match load {
+            true => instruction(mnemonic, format!("{pair}, [{base}]")),
+            false => instruction(mnemonic, format!("{}, {pair}, [{base}]", gpr(rs, false))),
+        };
+    }
+
+    let mnemonic = match (o2, load, o0) {
+        (false, false, false) => "stxr",
+        (false, false, true) => "stlxr",
+        (false, true, false) => "ldxr",
+        (false, true, true) => "ldaxr",
+        (true, false, false) => "stllr",
+        (true, false, true) => "stlr",
+        (true, true, false) => "ldlar",
+        (true, true, true) => "ldar",
+    };
+    let mnemonic = format!("{mnemonic}{suffix}");
+    match (o2, load) {
+        (false, false) => instruction(
+            &mnemonic,
+            format!("{}, {}, [{base}]", gpr(rs, false), gpr(rt, x)),
+        ),
+        _ => instruction(&mnemonic, format!("{}, [{base}]", gpr(rt, x))),
+    }
+}
+
+fn atomic(word: u32) -> Option<DecodedInstruction> {
+    let size = bits(word, 31, 30);
+    let acquire = bit(word, 23);
+    let release = bit(word, 22);
+    let rs = bits(word, 20, 16);
+    let o3 = bit(word, 15);
+    let opc = bits(word, 14, 12);
+    let rt = bits(word, 4, 0);
+    let base = gpr_sp(bits(word, 9, 5), true);
+    let x = size == 0b11;
+
+    let operation = match (o3, opc) {
+        (false, 0b000) => "add",
+        (false, 0b001) => "clr",
+        (false, 0b010) => "eor",
+        (false, 0b011) => "set",
+        (false, 0b100) => "smax",
+        (false, 0b101) => "smin",
+        (false, 0b110) => "umax",
+        (false, 0b111) => "umin",
+        (true, 0b000) => "swp",
+        _ => return None,
+    };
+    let ordering = match (acquire, release) {
+        (false, false) => "",
+        (true, false) => "a",
+        (false, true) => "l",
+        (true, true) => "al",
+    };
+    let suffix = ["b", "h", "", ""][size as usize];
+
+    // Without acquire semantics the loaded value can be discarded: st<op>
+    if operation != "swp" && rt == 31 && !acquire {
+        return instruction(
+            &format!("st{operation}{ordering}{suffix}"),
+            format!("{}, [{base}]", gpr(rs, x)),
+        );
+    }
+    let mnemonic = match operation {
+        "swp" => format!("swp{ordering}{suffix}"),
+        operation => format!("ld{operation}{ordering}{suffix}"),
+    };
+    instruction(
+        &mnemonic,
+        format!("{}, {}, [{base}]", gpr(rs, x), gpr(rt, x)),
+    )
+}
+
+fn data_processing_register(word: u32) -> Option<DecodedInstruction> {
+    let x = bit(word, 31);
+    let rd = bits(word, 4, 0);
+    let rn = bits(word, 9, 5);
+    let rm = bits(word, 20, 16);
+
+    if !bit(word, 28) {
+        let shift = bits(word, 23, 22);
+        let amount = bits(word, 15, 10);
+        // Extended register forms use these bits for the extend and shift
+        if !x && amount > 31 && !(bit(word, 24) && bit(word, 21)) {
+            return None;
+        }
+        let shifted = match amount {
+            0 if shift == 0 => gpr(rm, x),
+            amount => format!("{}, {} #{amount}", gpr(rm, x), SHIFTS[shift as usize]),
+        };
+
+        // Logical (shifted register)
+        if !bit(word, 24) {
+            let invert = bit(word, 21);
+            let opc = bits(word, 30, 29);
+            if opc == 0b01 && rn == 31 {
+                if !invert && shift == 0 && amount == 0 {
+                    return instruction("mov", format!("{}, {}", gpr(rd, x), gpr(rm, x)));
+                }
+                if invert {
+                    return instruction("mvn", format!("{}, {shifted}", gpr(rd, x)));
+                }
+            }
+            if opc == 0b11 && !invert && rd == 31 {
+                return instruction("tst", format!("{}, {shifted}", gpr(rn, x)));
+            }
+            let mnemonic = match (opc, invert) {
+                (0b00, false) => "and",
+                (0b00, true) => "bic",
+                (0b01, false) => "orr",
+                (0b01, true) => "orn",
+                (0b10, false) => "eor",
+                (0b10, true) => "eon",
+                (_, false) => "ands",
+                (_, true) => "bics",
+            };
+            return instruction(
+                mnemonic,
+                format!("{}, {}, {shifted}", gpr(rd, x), gpr(rn, x)),
+            );
+        }
+
+        let sub = bit(word, 30);
+        let set_flags = bit(word, 29);
+        let mnemonic = match (sub, set_flags) {
+            (false, false) => "add",
+            (false, true) => "adds",
+            (true, false) => "sub",
+            (true, true) => "subs",
+        };
+
+        // Add/subtract (shifted register)
+        if !bit(word, 21) {
+            if shift == 0b11 {
+                return None;
+            }
+            if set_flags && rd == 31 {
+                let alias = if sub { "cmp" } else { "cmn" };
+                return instruction(alias, format!("{}, {shifted}", gpr(rn, x)));
+            }
+            if sub && rn == 31 {
+                let alias = if set_flags { "negs" } else { "neg" };
+                return instruction(alias, format!("{}, {shifted}", gpr(rd, x)));
+            }
+            return instruction(
+                mnemonic,
+                format!("{}, {}, {shifted}", gpr(rd, x), gpr(rn, x)),
+            );
+        }
+
+        // Add/subtract (extended register)
+        if bits(word, 23, 22) != 0 {
+            return None;
+        }
+        let option = bits(word, 15, 13);
+        let amount = bits(word, 12, 10);
+        if amount > 4 {
+            return None;
+        }
+        let wide = x && option & 0b011 == 0b011;
+        let default_extend = if x { 0b011 } else { 0b010 };
+        let uses_sp = rd == 31 && !set_flags || rn == 31;
+        let extend = match (uses_sp && option == default_extend, amount) {
+            (true, 0) => String::new(),
+            (true, amount) => format!(", lsl #{amount}"),
+            (false, 0) => format!(", {}", EXTENDS[option as usize]),
+            (false, amount) => format!(", {} #{amount}", EXTENDS[option as usize]),
+        };
+        let source = format!("{}{extend}", gpr(rm, wide));
+        if set_flags && rd == 31 {
+            let alias = if sub { "cmp" } else { "cmn" };
+            return instruction(alias, format!("{}, {source}", gpr_sp(rn, x)));
+        }
+        let destination = match set_flags {
+            true => gpr(rd, x),
+            false => gpr_sp(rd, x),
+        };
+        return instruction(
+            mnemonic,
+            format!("{destination}, {}, {source}", gpr_sp(rn, x)),
+        );
+    }
+
+    if bit(word, 24) {
+        return data_processing_3_source(word);
+    }
+
+    match bits(word, 23, 21) {
+        // Add/subtract with carry
+        0b000 => {
+            if bits(word, 15, 10) != 0 {
+                return None;
+            }
+            let sub = bit(word, 30);
+            let set_flags = bit(word, 29);
+            if sub && rn == 31 {
+                let alias = if set_flags { "ngcs" } else { "ngc" };
+                return instruction(alias, format!("{}, {}", gpr(rd, x), gpr(rm, x)));
+            }
+            let mnemonic = match (sub, set_flags) {
+                (false, false) => "adc",
+                (false, true) => "adcs",
+                (true, false) => "sbc",
+                (true, true) => "sbcs",
+            };
+            instruction(
+                mnemonic,
+                format!("{}, {}, {}", gpr(rd, x), gpr(rn, x), gpr(rm, x)),
+            )
+        }
+        // Conditional compare
+        0b010 => {
+            if !bit(word, 29) || bit(word, 10) || bit(word, 4) {
+                return None;
+            }
+            let mnemonic = if bit(word, 30) { "ccmp" } else { "ccmn" };
+            let second = match bit(word, 11) {
+                true => format!("#{rm}"),
+                false => gpr(rm, x),
+            };
+            instruction(
+                mnemonic,
+                format!(
+                    "{}, {second}, #{}, {}",
+                    gpr(rn, x),
+                    bits(word, 3, 0),
+                    CONDITIONS[bits(word, 15, 12) as usize]
+                ),
+            )
+        }
+        // Conditional select
+        0b100 => {
+            if bit(word, 29) || bit(word, 11) {
+                return None;
+            }
+            let cond = bits(word, 15, 12);
+            let inverted = CONDITIONS[(cond ^ 1) as usize];
+            let op = bit(word, 30);
+            let increment = bit(word, 10);
+            let (d, n, m) = (gpr(rd, x), gpr(rn, x), gpr(rm, x));
+            let aliasable = cond < 14;
+            match (op, increment) {
+                (false, false) => instruction(
+                    "csel",
+                    format!("{d}, {n}, {m}, {}", CONDITIONS[cond as usize]),
+                ),
+                (false, true) if aliasable && rn == 31 && rm == 31 => {
+                    instruction("cset", format!("{d}, {inverted}"))
+                }
+                (false, true) if aliasable && rn == rm => {
+                    instruction("cinc", format!("{d}, {n}, {inverted}"))
+                }
+                (true, false) if aliasable && rn == 31 && rm == 31 => {
+                    instruction("csetm", format!("{d}, {inverted}"))
+                }
+                (true, false) if aliasable && rn == rm => {
+                    instruction("cinv", format!("{d}, {n}, {inverted}"))
+                }
+                (true, true) if aliasable && rn == rm => {
+                    instruction("cneg", format!("{d}, {n}, {inverted}"))
+                }
+                (op, increment) => {
+                    let mnemonic = match (op, increment) {
+                        (false, _) => "csinc",
+                        (true, false) => "csinv",
+                        (true, true) => "csneg",
+                    };
+                    instruction(
+                        mnemonic,
+                        format!("{d}, {n}, {m}, {}", CONDITIONS[cond as usize]),
+                    )
+                }
+            }
+        }
+        // Data processing (1 and 2 source)
+        0b110 => {
+            let opcode = bits(word, 15, 10);
+            if bit(word, 29) {
+                return None;
+            }
+            if bit(word, 30) {
+                if rm != 0 {
+                    return None;
+                }
+                let mnemonic = match (opcode, x) {
+                    (0b000000, _) => "rbit",
+                    (0b000001, _) => "rev16",
+                    (0b000010, false) => "rev",
+                    (0b000010, true) => "rev32",
+                    (0b000011, true) => "rev",
+                    (0b000100, _) => "clz",
+                    (0b000101, _) => "cls",
+                    _ => return None,
+                };
+                return instruction(mnemonic, format!("{}, {}", gpr(rd, x), gpr(rn, x)));
+            }
+            let mnemonic = match opcode {
+                0b000010 => "udiv",
+                0b000011 => "sdiv",
+                0b001000 => "lsl",
+                0b001001 => "lsr",
+                0b001010 => "asr",
+                0b001011 => "ror",
+                _ => return None,
+            };
+            instruction(
+                mnemonic,
+                format!("{}, {}, {}", gpr(rd, x), gpr(rn, x), gpr(rm, x)),
+            )
+        }
+        _ => None,
+    }
+}
+
+fn data_processing_3_source(word: u32) -> Option<DecodedInstruction> {
+    let x = bit(word, 31);
+    let rd = bits(word, 4, 0);
+    let rn = bits(word, 9, 5);
+    let rm = bits(word, 20, 16);
+    let ra = bits(word, 14, 10);
+    let subtract = bit(word, 15);
+    if bits(word, 30, 29) != 0 {
+        return None;
+    }
+
+    let (mnemonic, alias, long) = match (bits(word, 23, 21), subtract) {
+        (0b000, false) => ("madd", "mul", false),
+        (0b000, true) => ("msub", "mneg", false),
+        (0b001, false) if x => ("smaddl", "smull", true),
+        (0b001, true) if x => ("smsubl", "smnegl", true),
+        (0b101, false) if x => ("umaddl", "umull", true),
+        (0b101, true) if x => ("umsubl", "umnegl", true),
+        (0b010, false) if x => {
+            return instruction(
+                "smulh",
+                format!("{}, {}, {}", gpr(rd, true), gpr(rn, true), gpr(rm, true)),
+            );
+        }
+        (0b110, false) if x => {
+            return instruction(
+                "umulh",
+                format!("{}, {}, {}", gpr(rd, true), gpr(rn, true), gpr(rm, true)),
+            );
+        }
+        _ => return None,
+    };
+    let sources = match long {
+        true => format!("{}, {}, {}", gpr(rd, true), gpr(rn, false), gpr(rm, false)),
+        false => format!("{}, {}, {}", gpr(rd, x), gpr(rn, x), gpr(rm, x)),
+    };
+
+    match ra {
+        31 => instruction(alias, sources),
+        ra => instruction(mnemonic, format!("{sources}, {}", gpr(ra, x))),
+    }
+}
+
+// VFPExpandImm for the 8-bit immediate of FMOV
+fn fp_immediate(imm8: u32) -> f64 {
+    let sign = if imm8 & 0x80 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((imm8 >> 4) & 0x7) as i32;
+    let exponent = if exponent & 0x4 != 0 {
+        exponent - 8
+    } else {
+        exponent
+    } + 1;
+    let fraction = 1.0 + (imm8 & 0xF) as f64 / 16.0;
+    sign * fraction * 2f64.powi(exponent)
+}
+
+fn simd_fp(word: u32) -> Option<DecodedInstruction> {
+    // Scalar floating point
+    if bits(word, 30, 30) == 0 && bits(word, 28, 24) == 0b11110 && !bit(word, 29) {
+        return scalar_fp(word);
+    }
+    // Floating point data processing (3 source)
+    if bits(word, 31, 24) == 0b0001_1111 {
+        let scale = fp_type_scale(bits(word, 23, 22))?;
+        let mnemonic = match (bit(word, 21), bit(word, 15)) {
+            (false, false) => "fmadd",
+            (false, true) => "fmsub",
+            (true, false) => "fnmadd",
+            (true, true) => "fnmsub",
+        };
+        return instruction(
+            mnemonic,
+            format!(
+                "{}, {}, {}, {}",
+                fpr(bits(word, 4, 0), scale),
+                fpr(bits(word, 9, 5), scale),
+                fpr(bits(word, 20, 16), scale),
+                fpr(bits(word, 14, 10), scale)
+            ),
+        );
+    }
+    if bit(word, 31) {
+        return None;
+    }
+
+    advanced_simd(word)
+}
+
+fn scalar_fp(word: u32) -> Option<DecodedInstruction> {
+    let rd = bits(word, 4, 0);
+    let rn = bits(word, 9, 5);
+    let rm = bits(word, 20, 16);
+    let ftype = bits(word, 23, 22);
+    let x = bit(word, 31);
+
+    if !bit(word, 21) {
+        return None;
+    }
+
+    // Conversion between floating point and integer
+    if bits(word, 15, 10) == 0 {
+        let rmode = bits(word, 20, 19);
+        let opcode = bits(word, 18, 16);
+        if (rmode, opcode) == (0b01, 0b110) && x && ftype == 0b10 {
+            return instruction("fmov", format!("{}, v{rn}.d[1]", gpr(rd, true)));
+        }
+        if (rmode, opcode) == (0b01, 0b111) && x && ftype == 0b10 {
+            return instruction("fmov", format!("v{rd}.d[1], {}", gpr(rn, true)));
+        }
+        let scale = fp_type_scale(ftype)?;
+        let to_integer = match (rmode, opcode) {
+            (0b00, 0b000) => Some("fcvtns"),
+            (0b00, 0b001) => Some("fcvtnu"),
+            (0b01, 0b000) => Some("fcvtps"),
+            (0b01, 0b001) => Some("fcvtpu"),
+            (0b10, 0b000) => Some("fcvtms"),
+            (0b10, 0b001) => Some("fcvtmu"),
+            (0b11, 0b000) => Some("fcvtzs"),
+            (0b11, 0b001) => Some("fcvtzu"),
+            (0b00, 0b100) => Some("fcvtas"),
+            (0b00, 0b101) => Some("fcvtau"),
+            _ => None,
+        };
+        if let Some(mnemonic) = to_integer {
+            return instruction(mnemonic, format!("{}, {}", gpr(rd, x), fpr(rn, scale)));
+        }
+        return match (rmode, opcode) {
+            (0b00, 0b010) => instruction("scvtf", format!("{}, {}", fpr(rd, scale), gpr(rn, x))),
+            (0b00, 0b011) => instruction("ucvtf", format!("{}, {}", fpr(rd, scale), gpr(rn, x))),
+            (0b00, 0b110) if x == (scale == 3) || scale == 1 => {
+                instruction("fmov", format!("{}, {}", gpr(rd, x), fpr(rn, scale)))
+            }
+            (0b00, 0b111) if x == (scale == 3) || scale == 1 => {
+                instruction("fmov", format!("{}, {}", fpr(rd, scale), gpr(rn, x)))
+            }
+            _ => None,
+        };
+    }
+    if x {
+        return None;
+    }
+    let scale = fp_type_scale(ftype)?;
+
+    // Data processing (1 source)
+    if bits(word, 14, 10) == 0b10000 {
+        let opcode = bits(word, 20, 15);
+        let mnemonic = match opcode {
+            0b000000 => "fmov",
+            0b000001 => "fabs",
+            0b000010 => "fneg",
+            0b000011 => "fsqrt",
+            0b000100 | 0b000101 | 0b000111 => {
+                let target = fp_type_scale(opcode & 0b11)?;
+                if target == scale {
+                    return None;
+                }
+                return instruction("fcvt", format!("{}, {}", fpr(rd, target), fpr(rn, scale)));
+            }
+            0b001000 => "frintn",
+            0b001001 => "frintp",
+            0b001010 => "frintm",
+            0b001011 => "frintz",
+            0b001100 => "frinta",
+            0b001110 => "frintx",
+            0b001111 => "frinti",
+            _ => return None,
+        };
+        return instruction(mnemonic, format!("{}, {}", fpr(rd, scale), fpr(rn, scale)));
+    }
+    // Compare
+    if bits(word, 13, 10) == 0b1000 {
+        if bits(word, 15, 14) != 0 || bits(word, 2, 0) != 0 {
+            return None;
+        }
+        let mnemonic = if bit(word, 4) { "fcmpe" } else { "fcmp" };
+        return match bit(word, 3) {
+            true => instruction(mnemonic, format!("{}, #0.0", fpr(rn, scale))),
+            false => instruction(mnemonic, format!("{}, {}", fpr(rn, scale), fpr(rm, scale))),
+        };
+    }
+    // Immediate
+    if bits(word, 12, 10) == 0b100 {
+        if bits(word, 9, 5) != 0 {
+            return None;
+        }
+        return instruction(
+            "fmov",
+            format!(
+                "{}, #{:.8}",
+                fpr(rd, scale),
+                fp_immediate(bits(word, 20, 13))
+            ),
+        );
+    }
+    // Data processing (2 source)
+    if bits(word, 11, 10) == 0b10 {
+        let mnemonic = match bits(word, 15, 12) {
+            0b0000 => "fmul",
+            0b0001 => "fdiv",
+            0b0010 => "fadd",
+            0b0011 => "fsub",
+            0b0100 => "fmax",
+            0b0101 => "fmin",
+            0b0110 => "fmaxnm",
+            0b0111 => "fminnm",
+            0b1000 => "fnmul",
+            _ => return None,
+        };
+        return instruction(
+            mnemonic,
+            format!("{}, {}, {}", fpr(rd, scale), fpr(rn, scale), fpr(rm, scale)),
+        );
+    }
+    // Conditional select
+    if bits(word, 11, 10) == 0b11 {
+        return instruction(
+            "fcsel",
+            format!(
+                "{}, {}, {}, {}",
+                fpr(rd, scale),
+                fpr(rn, scale),
+                fpr(rm, scale),
+                CONDITIONS[bits(word, 15, 12) as usize]
+            ),
+        );
+    }
+
+    None
+}
+
+// Arrangement specifier of a vector register from size and Q
+fn arrangement(size: u32, q: bool) -> Option<&'static str> {
+    Some(match (size, q) {
+        (0b00, false) => "8b",
+        (0b00, true) => "16b",
+        (0b01, false) => "4h",
+        (0b01, true) => "8h",
+        (0b10, false) => "2s",
+        (0b10, true) => "4s",
+        (0b11, true) => "2d",
+        _ => return None,
+    })
+}
+
+fn advanced_simd(word: u32) -> Option<DecodedInstruction> {
+    let q = bit(word, 30);
+    let u = bit(word, 29);
+    let rd = bits(word, 4, 0);
+    let rn = bits(word, 9, 5);
+    let rm = bits(word, 20, 16);
+    let size = bits(word, 23, 22);
+
+    if bits(word, 28, 24) != 0b01110 && bits(word, 28, 19) != 0b01111_00000 {
+        return None;
+    }
+
+    // Modified immediate
+    if bits(word, 28, 19) == 0b01111_00000 && bit(word, 10) && !bit(word, 11) {
+        let cmode = bits(word, 15, 12);
+        let op = bit(word, 29);
+        let imm8 = (bits(word, 18, 16) << 5) | bits(word, 9, 5);
+        return match (op, cmode) {
+            (true, 0b1110) => {
+                let mut imm = 0u64;
+                for byte in 0..8 {
+                    if imm8 & (1 << byte) != 0 {
+                        imm |= 0xFF << (byte * 8);
+                    }
+                }
+                match q {
+                    true => instruction("movi", format!("v{rd}.2d, #0x{imm:016x}")),
+                    false => instruction("movi", format!("d{rd}, #0x{imm:016x}")),
+                }
+            }
+            (false, 0b1110) => {
+                let arrangement = if q { "16b" } else { "8b" };
+                instruction("movi", format!("v{rd}.{arrangement}, #{imm8}"))
+            }
+            (_, cmode) if cmode & 0b1001 == 0b0000 => {
+                let mnemonic = if op { "mvni" } else { "movi" };
+                let arrangement = if q { "4s" } else { "2s" };
+                let shift = (cmode >> 1 & 0b11) * 8;
+                instruction(
+                    mnemonic,
+                    format!("v{rd}.{arrangement}, #{imm8}{}", lsl(shift)),
+                )
+            }
+            _ => None,
+        };
+    }
+
+    // Copy: DUP, INS, UMOV, SMOV
+    if bits(word, 28, 21) == 0b0111_0000 && bit(word, 10) && !bit(word, 15) {
+        let imm5 = bits(word, 20, 16);
+        let imm4 = bits(word, 14, 11);
+        let element_size = imm5.trailing_zeros();
+        if element_size > 3 {
+            return None;
+        }
+        let element = ["b", "h", "s", "d"][element_size as usize];
+        let index = imm5 >> (element_size + 1);
+        return match (u, imm4) {
+            (false, 0b0000) => {
+                let arrangement = arrangement(element_size, q)?;
+                instruction(
+                    "dup",
+                    format!("v{rd}.{arrangement}, v{rn}.{element}[{index}]"),
+                )
+            }
+            (false, 0b0001) => {
+                let arrangement = arrangement(element_size, q)?;
+                instruction(
+                    "dup",
+                    format!("v{rd}.{arrangement}, {}", gpr(rn, element_size == 3)),
+                )
+            }
+            (false, 0b0011) if q => instruction(
+                "mov",
+                format!("v{rd}.{element}[{index}], {}", gpr(rn, element_size == 3)),
+            ),
+            (false, 0b0111) if element_size >= 2 && q == (element_size == 3) => {
+                instruction("mov", format!("{}, v{rn}.{element}[{index}]", gpr(rd, q)))
+            }
+            (false, 0b0111) if q == (element_size == 3) => {
+                instruction("umov", format!("{}, v{rn}.{element}[{index}]", gpr(rd, q)))
+            }
+            (false, 0b0101) if element_size < 2 || element_size == 2 && q => {
+                instruction("smov", format!("{}, v{rn}.{element}[{index}]", gpr(rd, q)))
+            }
+            (true, _) if q => {
+                let source = imm4 >> element_size;
+                instruction(
+                    "mov",
+                    format!("v{rd}.{element}[{index}], v{rn}.{element}[{source}]"),
+                )
+            }
+            _ => None,
+        };
+    }
+
+    // Three same
+    if bits(word, 28, 24) == 0b01110 && bit(word, 21) && bit(word, 10) {
+        let opcode = bits(word, 15, 11);
+        if opcode == 0b00011 {
+            let mnemonic = match (u, size) {
+                (false, 0b00) => "and",
+                (false, 0b01) => "bic",
+                (false, 0b10) if rn == rm => {
+                    let arrangement = if q { "16b" } else { "8b" };
+                    return instruction("mov", format!("v{rd}.{arrangement}, v{rn}.{arrangement}"));
+                }
+                (false, 0b10) => "orr",
+                (false, _) => "orn",
+                (true, 0b00) => "eor",
+                (true, 0b01) => "bsl",
+                (true, 0b10) => "bit",
+                (true, _) => "bif",
+            };
+            let arrangement = if q { "16b" } else { "8b" };
+            return instruction(
+                mnemonic,
+                format!("v{rd}.{arrangement}, v{rn}.{arrangement}, v{rm}.{arrangement}"),
+            );
+        }
+        let arrangement = arrangement(size, q)?;
+        let mnemonic = match (u, opcode) {
+            (false, 0b10000) => "add",
+            (true, 0b10000) => "sub",
+            (false, 0b10011) if size != 0b11 => "mul",
+            (false, 0b10001) => "cmtst",
+            (true, 0b10001) => "cmeq",
+            (false, 0b00110) => "cmgt",
+            (false, 0b00111) => "cmge",
+            (true, 0b00110) => "cmhi",
+            (true, 0b00111) => "cmhs",
+            (false, 0b01100) if size != 0b11 => "smax",
+            (false, 0b01101) if size != 0b11 => "smin",
+            (true, 0b01100) if size != 0b11 => "umax",
+            (true, 0b01101) if size != 0b11 => "umin",
+            (false, 0b10111) => "addp",
+            _ => return None,
+        };
+        return instruction(
+            mnemonic,
+            format!("v{rd}.{arrangement}, v{rn}.{arrangement}, v{rm}.{arrangement}"),
+        );
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+
+    // Checked against llvm-objdump, branch targets are relative to `ADDRESS`
+    const ADDRESS: u64 = 0x1000;
+    const CASES: [(u32, &str); 160] = [
+        // Data processing, immediate
+        (0x91004020, "add x0, x1, #16"),
+        (0x51400462, "sub w2, w3, #1, lsl #12"),
+        (0x52800540, "mov w0, #42"),
+        (0xf2a24681, "movk x1, #4660, lsl #16"),
+        (0x92401c20, "and x0, x1, #0xff"),
+        (0xd3442c20, "ubfx x0, x1, #4, #8"),
+        (0xd37be862, "lsl x2, x3, #5"),
+        (0x90000080, "adrp x0, 0x11000"),
+        (0x10000201, "adr x1, #64"),
+        (0x93c23020, "extr x0, x1, x2, #12"),
+        (0x13841c83, "ror w3, w4, #7"),
+        (0x93407c20, "sxtw x0, w1"),
+        (0x9349fc62, "asr x2, x3, #9"),
+        (0x937e24a4, "sbfiz x4, x5, #2, #10"),
+        (0x331d0ce6, "bfi w6, w7, #3, #4"),
+        (0xb3505d28, "bfxil x8, x9, #16, #8"),
+        (0x53001c20, "uxtb w0, w1"),
+        (0x92800020, "mov x0, #-2"),
+        (0x52a00021, "mov w1, #65536"),
+        (0xf1001020, "subs x0, x1, #4"),
+        (0x3100041f, "cmn w0, #1"),
+        (0x9100003f, "mov sp, x1"),
+        (0xf240081f, "tst x0, #0x7"),
+        (0xb200f3e0, "mov x0, #6148914691236517205"),
+        // Data processing, register
+        (0xab060ca4, "adds x4, x5, x6, lsl #3"),
+        (0xeb02003f, "cmp x1, x2"),
+        (0xaa0103e0, "mov x0, x1"),
+        (0x2a040062, "orr w2, w3, w4"),
+        (0x9b020c20, "madd x0, x1, x2, x3"),
+        (0x1b067ca4, "mul w4, w5, w6"),
+        (0x9ac90d07, "sdiv x7, x8, x9"),
+        (0x9a820020, "csel x0, x1, x2, eq"),
+        (0x1a9f07e0, "cset w0, ne"),
+        (0x8b224820, "add x0, x1, w2, uxtw #2"),
+        (0xcb2163ff, "sub sp, sp, x1"),
+        (0x9a020020, "adc x0, x1, x2"),
+        (0x7a050083, "sbcs w3, w4, w5"),
+        (0xda0103e0, "ngc x0, x1"),
+        (0xfa431804, "ccmp x0, #3, #4, ne"),
+        (0x3a420020, "ccmn w1, w2, #0, eq"),
+        (0x9a82b420, "csinc x0, x1, x2, lt"),
+        (0x1a84d483, "cinc w3, w4, gt"),
+        (0xda8654c5, "cneg x5, x6, mi"),
+        (0xda9f33e0, "csetm x0, hs"),
+        (0xdac00c20, "rev x0, x1"),
+        (0x5ac00462, "rev16 w2, w3"),
+        (0xdac010a4, "clz x4, x5"),
+        (0x5ac000e6, "rbit w6, w7"),
+        (0x9ac22020, "lsl x0, x1, x2"),
+        (0x1ac50883, "udiv w3, w4, w5"),
+        (0x9b227c20, "smull x0, w1, w2"),
+        (0x9bc57c83, "umulh x3, x4, x5"),
+        (0x9b08fce6, "mneg x6, x7, x8"),
+        (0x9b028c20, "msub x0, x1, x2, x3"),
+        (0xea621020, "bics x0, x1, x2, lsr #4"),
+        (0x2a2103e0, "mvn w0, w1"),
+        (0xcb0103e0, "neg x0, x1"),
+        (0xcac20c20, "eor x0, x1, x2, ror #3"),
+        // Branches
+        (0x14000040, "b 0x1100"),
+        (0x94000080, "bl 0x1200"),
+        (0x54000101, "b.ne 0x1020"),
+        (0xb4000080, "cbz x0, 0x1010"),
+        (0x37180041, "tbnz w1, #3, 0x1008"),
+        (0xd65f03c0, "ret"),
+        (0xd61f0200, "br x16"),
+        (0xd63f0100, "blr x8"),
+        // Loads and stores
+        (0xf9400420, "ldr x0, [x1, #8]"),
+        (0xb81f0fe2, "str w2, [sp, #-16]!"),
+        (0x38401483, "ldrb w3, [x4], #1"),
+        (0xf86778c5, "ldr x5, [x6, x7, lsl #3]"),
+        (0xa8c17bfd, "ldp x29, x30, [sp], #16"),
+        (0xa9be7bfd, "stp x29, x30, [sp, #-32]!"),
+        (0x58000800, "ldr x0, 0x1100"),
+        (0xc85f7c20, "ldxr x0, [x1]"),
+        (0xc802fc83, "stlxr w2, x3, [x4]"),
+        (0xb862d820, "ldr w0, [x1, w2, sxtw #2]"),
+        (0xf85f8020, "ldur x0, [x1, #-8]"),
+        (0xb9800420, "ldrsw x0, [x1, #4]"),
+        (0x79c00462, "ldrsh w2, [x3, #2]"),
+        (0x79000ca4, "strh w4, [x5, #6]"),
+        (0xc8dffc20, "ldar x0, [x1]"),
+        (0x889ffc62, "stlr w2, [x3]"),
+        (0xc87f8440, "ldaxp x0, x1, [x2]"),
+        (0x69410440, "ldpsw x0, x1, [x2, #8]"),
+        (0xad0107e0, "stp q0, q1, [sp, #32]"),
+        (0xfd400820, "ldr d0, [x1, #16]"),
+        (0xbc404462, "ldr s2, [x3], #4"),
+        // LSE atomics
+        (0xf8200041, "ldadd x0, x1, [x2]"),
+        (0xf82383e4, "swp x3, x4, [sp]"),
+        (0xb820003f, "stadd w0, [x1]"),
+        (0xf8e00041, "ldaddal x0, x1, [x2]"),
+        (0xb82310a4, "ldclr w3, w4, [x5]"),
+        // Floating point and Advanced SIMD
+        (0x1e622820, "fadd d0, d1, d2"),
+        (0x1e2e1000, "fmov s0, #1.00000000"),
+        (0x1e780020, "fcvtzs w0, d1"),
+        (0x9e620062, "scvtf d2, x3"),
+        (0x1e212000, "fcmp s0, s1"),
+        (0x3dc00000, "ldr q0, [x0]"),
+        (0x4ea28420, "add v0.4s, v1.4s, v2.4s"),
+        (0x4f07e7e0, "movi v0.16b, #255"),
+        (0x1f420c20, "fmadd d0, d1, d2, d3"),
+        (0x1f228c20, "fnmsub s0, s1, s2, s3"),
+        (0x1e614020, "fneg d0, d1"),
+        (0x1e21c062, "fsqrt s2, s3"),
+        (0x1e60c0a4, "fabs d4, d5"),
+        (0x1e22c020, "fcvt d0, s1"),
+        (0x1e604020, "fmov d0, d1"),
+        (0x9e660020, "fmov x0, d1"),
+        (0x1e270062, "fmov s2, w3"),
+        (0x1e220820, "fmul s0, s1, s2"),
+        (0x1e651883, "fdiv d3, d4, d5"),
+        (0x1e624820, "fmax d0, d1, d2"),
+        (0x1e602008, "fcmp d0, #0.0"),
+        (0x1e62cc20, "fcsel d0, d1, d2, gt"),
+        (0x1e709000, "fmov d0, #-2.50000000"),
+        (0x1e230020, "ucvtf s0, w1"),
+        (0x9e390020, "fcvtzu x0, s1"),
+        (0x6f05e540, "movi v0.2d, #0xff00ff00ff00ff00"),
+        (0x2f07e601, "movi d1, #0xffffffff00000000"),
+        (0x4f002602, "movi v2.4s, #16, lsl #8"),
+        (0x2f000423, "mvni v3.2s, #1"),
+        (0x4e0c0420, "dup v0.4s, v1.s[1]"),
+        (0x4e020c62, "dup v2.8h, w3"),
+        (0x0e073c20, "umov w0, v1.b[3]"),
+        (0x4e183c20, "mov x0, v1.d[1]"),
+        (0x4e141c20, "mov v0.s[2], w1"),
+        (0x6e0c6420, "mov v0.s[1], v1.s[3]"),
+        (0x4e0a2c20, "smov x0, v1.h[2]"),
+        (0x4ea11c20, "mov v0.16b, v1.16b"),
+        (0x6e221c20, "eor v0.16b, v1.16b, v2.16b"),
+        (0x6e628c20, "cmeq v0.8h, v1.8h, v2.8h"),
+        (0x4ee2bc20, "addp v0.2d, v1.2d, v2.2d"),
+        (0x6ea26420, "umax v0.4s, v1.4s, v2.4s"),
+        (0x0e229c20, "mul v0.8b, v1.8b, v2.8b"),
+        // System
+        (0xd503201f, "nop"),
+        (0xd50335df, "isb #5"),
+        (0xd4000001, "svc #0"),
+        (0xd53bd040, "mrs x0, TPIDR_EL0"),
+        (0xd4207d00, "brk #0x3e8"),
+        (0xd4000022, "hvc #0x1"),
+        (0xd5033bbf, "dmb ish"),
+        (0xd5033f9f, "dsb sy"),
+        (0xd5033fdf, "isb"),
+        (0xd51bd041, "msr TPIDR_EL0, x1"),
+        (0xd503203f, "yield"),
+        (0xd5092385, "sys #1, c2, c3, #4, x5"),
+        // Pointer authentication and BTI
+        (0xd503233f, "paciasp"),
+        (0xd50323bf, "autiasp"),
+        (0xd65f0bff, "retaa"),
+        (0xd503245f, "bti c"),
+        (0xd503237f, "pacibsp"),
+        (0xd50323ff, "autibsp"),
+        (0xd65f0fff, "retab"),
+        (0xd503249f, "bti j"),
+        (0xd50320ff, "xpaclri"),
+        (0xd61f083f, "braaz x1"),
+        (0xd73f0822, "blraa x1, x2"),
+        // Permanently undefined, and an unallocated encoding
+        (0x00000000, "udf #0"),
+        (0x00001234, "udf #4660"),
+        (0xffffffff, ".inst 0xffffffff"),
+    ];
+
+    #[test]
+    fn decodes_instructions() {
+        for (word, expected) in CASES {
+            let decoded = decode(&word.to_le_bytes(), ADDRESS);
+            let text = format!("{} {}", decoded.mnemonic, decoded.operands);
+            assert_eq!(text.trim_end(), expected, "{word:#010x}");
+            assert_eq!(decoded.length, 4, "{word:#010x}");
+        }
+    }
+
+    #[test]
+    fn short_tail_is_not_an_instruction() {
+        let decoded = decode(&[0x1f, 0x20], ADDRESS);
+        assert_eq!(decoded.mnemonic, ".byte");
+        assert_eq!(decoded.operands, "0x1f, 0x20");
+        assert_eq!(decoded.length, 2);
+    }
+}
@@ -0,0 +1,1195 @@
+// RV32 and RV64 decoder for the I, M, A, F, D and C extensions. Compressed
+// instructions are expanded to the base instruction they stand for and,
+// like llvm-objdump, printed in that form with the usual pseudo-instructions.
+
+use super::DecodedInstruction;
+
+const REGISTERS: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
+    "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+    "t5", "t6",
+];
+const FP_REGISTERS: [&str; 32] = [
+    "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2",
+    "fa3", "fa4", "fa5", "fa6", "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9",
+    "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+];
+const ROUNDING_MODES: [&str; 8] = ["rne", "rtz", "rdn", "rup", "rmm", "", "", "dyn"];
+
+// CSRs printed by name
+const CSRS: [(u32, &str); 22] = [
+    (0x001, "fflags"),
+    (0x002, "frm"),
+    (0x003, "fcsr"),
+    (0x100, "sstatus"),
+    (0x104, "sie"),
+    (0x105, "stvec"),
+    (0x140, "sscratch"),
+    (0x141, "sepc"),
+    (0x142, "scause"),
+    (0x143, "stval"),
+    (0x144, "sip"),
+    (0x180, "satp"),
+    (0x300, "mstatus"),
+    (0x301, "misa"),
+    (0x304, "mie"),
+    (0x305, "mtvec"),
+    (0x340, "mscratch"),
+    (0x341, "mepc"),
+    (0x342, "mcause"),
+    (0x343, "mtval"),
+    (0x344, "mip"),
+    (0xF14, "mhartid"),
+];
+
+fn bits(word: u32, high: u32, low: u32) -> u32 {
+    (word >> low) & ((1 << (high - low + 1)) - 1)
+}
+
+fn sign_extend(value: u32, width: u32) -> i64 {
+    let shift = 32 - width;
+    (((value << shift) as i32) >> shift) as i64
+}
+
+fn x(n: u32) -> &'static str {
+    REGISTERS[n as usize]
+}
+
+fn f(n: u32) -> &'static str {
+    FP_REGISTERS[n as usize]
+}
+
+fn csr_name(csr: u32) -> String {
+    match CSRS.iter().find(|(number, _)| *number == csr) {
+        Some((_, name)) => name.to_string(),
+        None => csr.to_string(),
+    }
+}
+
+fn instruction(length: usize, mnemonic: &str, operands: String) -> Option<DecodedInstruction> {
+    Some(DecodedInstruction {
+        length,
+        mnemonic: mnemonic.to_string(),
+        operands,
+        target: None,
+    })
+}
+
+fn branch(
+    length: usize,
+    mnemonic: &str,
+    operands: String,
+    target: u64,
+) -> Option<DecodedInstruction> {
+    Some(DecodedInstruction {
+        length,
+        mnemonic: mnemonic.to_string(),
+        operands,
+        target: Some(target),
+    })
+}
+
+pub(super) fn decode(bytes: &[u8], address: u64, xlen: u32) -> DecodedInstruction {
+    let Some(half) = bytes.get(..2) else {
+        return DecodedInstruction::bytes(bytes);
+    };
+    let half = u16::from_le_bytes([half[0], half[1]]) as u32;
+
+    let (word, length) = match half & 0b11 {
+        0b11 => match bytes.get(..4) {
+            Some(word) => (u32::from_le_bytes([word[0], word[1], word[2], word[3]]), 4),
+            None => return DecodedInstruction::bytes(bytes),
+        },
+        _ => match expand_compressed(half, xlen) {
+            Some(word) => (word, 2),
+            None => (0, 2),
+        },
+    };
+    let decoded = match (word, length) {
+        (0, 2) => None,
+        (word, length) => decode_word(word, length, address, xlen),
+    };
+
+    decoded.unwrap_or_else(|| match (half, length) {
+        (0, 2) => DecodedInstruction {
+            length,
+            mnemonic: "unimp".to_string(),
+            operands: String::new(),
+            target: None,
+        },
+        (_, 2) => DecodedInstruction {
+            length,
+            mnemonic: ".insn".to_string(),
+            operands: format!("0x{half:04x}"),
+            target: None,
+        },
+        _ => DecodedInstruction {
+            length,
+            mnemonic: ".insn".to_string(),
+            operands: format!("0x{word:08x}"),
+            target: None,
+        },
+    })
+}
+
+fn r_type(funct7: u32, rs2: u32, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    (funct7 << 25) | (rs2 << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+fn i_type(imm: i64, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    (((imm as u32) & 0xFFF) << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+fn s_type(imm: i64, rs2: u32, rs1: u32, funct3: u32, opcode: u32) -> u32 {
+    let imm = imm as u32;
+    (bits(imm, 11, 5) << 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | (bits(imm, 4, 0) << 7)
+        | opcode
+}
+
+fn b_type(imm: i64, rs2: u32, rs1: u32, funct3: u32) -> u32 {
+    let imm = imm as u32;
+    (bits(imm, 12, 12) << 31)
+        | (bits(imm, 10, 5) << 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | (bits(imm, 4, 1) << 8)
+        | (bits(imm, 11, 11) << 7)
+        | 0x63
+}
+
+fn j_type(imm: i64, rd: u32) -> u32 {
+    let imm = imm as u32;
+    (bits(imm, 20, 20) << 31)
+        | (bits(imm, 10, 1) << 21)
+        | (bits(imm, 11, 11) << 20)
+        | (bits(imm, 19, 12) << 12)
+        | (rd << 7)
+        | 0x6F
+}
+
+// Gathers the immediate bits scattered over a compressed instruction, as
+// (first instruction bit, last instruction bit, lowest immediate bit)
+fn gather(half: u32, fields: &[(u32, u32, u32)]) -> u32 {
+    fields
+        .iter()
+        .map(|(high, low, shift)| bits(half, *high, *low) << shift)
+        .fold(0, |imm, field| imm | field)
+}
+
+// The 32-bit instruction a compressed one expands to, `None` when reserved
+fn expand_compressed(half: u32, xlen: u32) -> Option<u32> {
+    let funct3 = bits(half, 15, 13);
+    let rd = bits(half, 11, 7);
+    let rs2 = bits(half, 6, 2);
+    // The 3-bit register fields name x8 to x15
+    let rd_prime = 8 + bits(half, 4, 2);
+    let rs1_prime = 8 + bits(half, 9, 7);
+    let imm6 = sign_extend(gather(half, &[(12, 12, 5), (6, 2, 0)]), 6);
+    let word_offset = gather(half, &[(12, 10, 3), (6, 6, 2), (5, 5, 6)]) as i64;
+    let double_offset = gather(half, &[(12, 10, 3), (6, 5, 6)]) as i64;
+    let rv64 = xlen == 64;
+
+    Some(match (bits(half, 1, 0), funct3) {
+        // C.ADDI4SPN
+        (0b00, 0b000) => {
+            let imm = gather(half, &[(12, 11, 4), (10, 7, 6), (6, 6, 2), (5, 5, 3)]);
+            if imm == 0 {
+                return None;
+            }
+            i_type(imm as i64, 2, 0b000, rd_prime, 0x13)
+        }
+        // C.FLD
+        (0b00, 0b001) => i_type(double_offset, rs1_prime, 0b011, rd_prime, 0x07),
+        // C.LW
+        (0b00, 0b010) => i_type(word_offset, rs1_prime, 0b010, rd_prime, 0x03),
+        // C.LD, C.FLW
+        (0b00, 0b011) if rv64 => i_type(double_offset, rs1_prime, 0b011, rd_prime, 0x03),
+        (0b00, 0b011) => i_type(word_offset, rs1_prime, 0b010, rd_prime, 0x07),
+        // C.FSD
+        (0b00, 0b101) => s_type(double_offset, rd_prime, rs1_prime, 0b011, 0x27),
+        // C.SW
+        (0b00, 0b110) => s_type(word_offset, rd_prime, rs1_prime, 0b010, 0x23),
+        // C.SD, C.FSW
+        (0b00, 0b111) if rv64 => s_type(double_offset, rd_prime, rs1_prime, 0b011, 0x23),
+        (0b00, 0b111) => s_type(word_offset, rd_prime, rs1_prime, 0b010, 0x27),
+
+        // C.ADDI, C.NOP
+        (0b01, 0b000) => i_type(imm6, rd, 0b000, rd, 0x13),
+        // C.ADDIW, C.JAL
+        (0b01, 0b001) if rv64 => {
+            if rd == 0 {
+                return None;
+            }
+            i_type(imm6, rd, 0b000, rd, 0x1B)
+        }
+        (0b01, 0b001) => j_type(jump_offset(half), 1),
+        // C.LI
+        (0b01, 0b010) => i_type(imm6, 0, 0b000, rd, 0x13),
+        // C.ADDI16SP
+        (0b01, 0b011) if rd == 2 => {
+            let imm = gather(
+                half,
+                &[(12, 12, 9), (6, 6, 4), (5, 5, 6), (4, 3, 7), (2, 2, 5)],
+            );
+            if imm == 0 {
+                return None;
+            }
+            i_type(sign_extend(imm, 10), 2, 0b000, 2, 0x13)
+        }
+        // C.LUI
+        (0b01, 0b011) => {
+            if imm6 == 0 {
+                return None;
+            }
+            (((imm6 as u32) & 0xFFFFF) << 12) | (rd << 7) | 0x37
+        }
+        (0b01, 0b100) => {
+            let rd = rs1_prime;
+            let shamt = gather(half, &[(12, 12, 5), (6, 2, 0)]);
+            match bits(half, 11, 10) {
+                // C.SRLI, C.SRAI
+                0b00 => i_type(shamt as i64, rd, 0b101, rd, 0x13),
+                0b01 => i_type((shamt | 0x400) as i64, rd, 0b101, rd, 0x13),
+                // C.ANDI
+                0b10 => i_type(imm6, rd, 0b111, rd, 0x13),
+                _ => {
+                    let rs2 = rd_prime;
+                    match (bits(half, 12, 12), bits(half, 6, 5)) {
+                        (0, 0b00) => r_type(0x20, rs2, rd, 0b000, rd, 0x33),
+                        (0, 0b01) => r_type(0x00, rs2, rd, 0b100, rd, 0x33),
+                        (0, 0b10) => r_type(0x00, rs2, rd, 0b110, rd, 0x33),
+                        (0, _) => r_type(0x00, rs2, rd, 0b111, rd, 0x33),
+                        (_, 0b00) if rv64 => r_type(0x20, rs2, rd, 0b000, rd, 0x3B),
+                        (_, 0b01) if rv64 => r_type(0x00, rs2, rd, 0b000, rd, 0x3B),
+                        _ => return None,
+                    }
+                }
+            }
+        }
+        // C.J
+        (0b01, 0b101) => j_type(jump_offset(half), 0),
+        // C.BEQZ, C.BNEZ
+        (0b01, 0b110 | 0b111) => {
+            let imm = gather(
+                half,
+                &[(12, 12, 8), (11, 10, 3), (6, 5, 6), (4, 3, 1), (2, 2, 5)],
+            );
+            b_type(sign_extend(imm, 9), 0, rs1_prime, funct3 & 1)
+        }
+
+        // C.SLLI
+        (0b10, 0b000) => {
+            let shamt = gather(half, &[(12, 12, 5), (6, 2, 0)]);
+            i_type(shamt as i64, rd, 0b001, rd, 0x13)
+        }
+        // C.FLDSP
+        (0b10, 0b001) => i_type(double_stack_offset(half), 2, 0b011, rd, 0x07),
+        // C.LWSP
+        (0b10, 0b010) => {
+            if rd == 0 {
+                return None;
+            }
+            i_type(word_stack_offset(half), 2, 0b010, rd, 0x03)
+        }
+        // C.LDSP, C.FLWSP
+        (0b10, 0b011) if rv64 => {
+            if rd == 0 {
+                return None;
+            }
+            i_type(double_stack_offset(half), 2, 0b011, rd, 0x03)
+        }
+        (0b10, 0b011) => i_type(word_stack_offset(half), 2, 0b010, rd, 0x07),
+        (0b10, 0b100) => match (bits(half, 12, 12), rd, rs2) {
+            // C.JR
+            (0, 0, 0) => return None,
+            (0, rs1, 0) => i_type(0, rs1, 0b000, 0, 0x67),
+            // C.MV
+            (0, rd, rs2) => r_type(0, rs2, 0, 0b000, rd, 0x33),
+            // C.EBREAK
+            (_, 0, 0) => 0x0010_0073,
+            // C.JALR
+            (_, rs1, 0) => i_type(0, rs1, 0b000, 1, 0x67),
+            // C.ADD
+            (_, rd, rs2) => r_type(0, rs2, rd, 0b000, rd, 0x33),
+        },
+        // C.FSDSP
+        (0b10, 0b101) => s_type(double_store_offset(half), rs2, 2, 0b011, 0x27),
+        // C.SWSP
+        (0b10, 0b110) => s_type(word_store_offset(half), rs2, 2, 0b010, 0x23),
+        // C.SDSP, C.FSWSP
+        (0b10, 0b111) if rv64 => s_type(double_store_offset(half), rs2, 2, 0b011, 0x23),
+        (0b10, 0b111) => s_type(word_store_offset(half), rs2, 2, 0b010, 0x27),
+        _ => return None,
+    })
+}
+
+fn jump_offset(half: u32) -> i64 {
+    let imm = gather(
+        half,
+        &[
+            (12, 12, 11),
+            (11, 11, 4),
+            (10, 9, 8),
+            (8, 8, 10),
+            (7, 7, 6),
+            (6, 6, 7),
+            (5, 3, 1),
+            (2, 2, 5),
+        ],
+    );
+    sign_extend(imm, 12)
+}
+
+fn word_stack_offset(half: u32) -> i64 {
+    gather(half, &[(12, 12, 5), (6, 4, 2), (3, 2, 6)]) as i64
+}
+
+fn double_stack_offset(half: u32) -> i64 {
+    gather(half, &[(12, 12, 5), (6, 5, 3), (4, 2, 6)]) as i64
+}
+
+fn word_store_offset(half: u32) -> i64 {
+    gather(half, &[(12, 9, 2), (8, 7, 6)]) as i64
+}
+
+fn double_store_offset(half: u32) -> i64 {
+    gather(half, &[(12, 10, 3), (9, 7, 6)]) as i64
+}
+
+// Addresses of RV32 code wrap around at 4 GiB
+fn wrap(address: u64, xlen: u32) -> u64 {
+    match xlen {
+        32 => address & 0xFFFF_FFFF,
+        _ => address,
+    }
+}
+
+fn decode_word(word: u32, length: usize, address: u64, xlen: u32) -> Option<DecodedInstruction> {
+    let rd = bits(word, 11, 7);
+    let rs1 = bits(word, 19, 15);
+    let rs2 = bits(word, 24, 20);
+    let funct3 = bits(word, 14, 12);
+    let funct7 = bits(word, 31, 25);
+    let imm_i = sign_extend(bits(word, 31, 20), 12);
+    let imm_s = sign_extend((bits(word, 31, 25) << 5) | bits(word, 11, 7), 12);
+    let rv64 = xlen == 64;
+
+    match bits(word, 6, 0) {
+        // LUI, AUIPC
+        0x37 => instruction(length, "lui", format!("{}, {}", x(rd), word >> 12)),
+        0x17 => instruction(length, "auipc", format!("{}, {}", x(rd), word >> 12)),
+        // JAL
+        0x6F => {
+            let imm = (bits(word, 31, 31) << 20)
+                | (bits(word, 19, 12) << 12)
+                | (bits(word, 20, 20) << 11)
+                | (bits(word, 30, 21) << 1);
+            let target = wrap(address.wrapping_add(sign_extend(imm, 21) as u64), xlen);
+            match rd {
+                0 => branch(length, "j", format!("0x{target:x}"), target),
+                1 => branch(length, "jal", format!("0x{target:x}"), target),
+                rd => branch(length, "jal", format!("{}, 0x{target:x}", x(rd)), target),
+            }
+        }
+        // JALR
+        0x67 if funct3 == 0 => match (rd, rs1, imm_i) {
+            (0, 1, 0) => instruction(length, "ret", String::new()),
+            (0, rs1, 0) => instruction(length, "jr", x(rs1).to_string()),
+            (1, rs1, 0) => instruction(length, "jalr", x(rs1).to_string()),
+            (0, rs1, imm) => instruction(length, "jr", format!("{imm}({})", x(rs1))),
+            (1, rs1, imm) => instruction(length, "jalr", format!("{imm}({})", x(rs1))),
+            (rd, rs1, imm) => instruction(length, "jalr", format!("{}, {imm}({})", x(rd), x(rs1))),
+        },
+        // Branches
+        0x63 => {
+            let imm = (bits(word, 31, 31) << 12)
+                | (bits(word, 7, 7) << 11)
+                | (bits(word, 30, 25) << 5)
+                | (bits(word, 11, 8) << 1);
+            let target = wrap(address.wrapping_add(sign_extend(imm, 13) as u64), xlen);
+            let mnemonic = match funct3 {
+                0b000 => "beq",
+                0b001 => "bne",
+                0b100 => "blt",
+                0b101 => "bge",
+                0b110 => "bltu",
+                0b111 => "bgeu",
+                _ => return None,
+            };
+            let (mnemonic, operands) = match (mnemonic, rs1, rs2) {
+                ("beq", rs1, 0) => ("beqz", x(rs1).to_string()),
+                ("bne", rs1, 0) => ("bnez", x(rs1).to_string()),
+                ("blt", rs1, 0) => ("bltz", x(rs1).to_string()),
+                ("bge", rs1, 0) => ("bgez", x(rs1).to_string()),
+                ("blt", 0, rs2) => ("bgtz", x(rs2).to_string()),
+                ("bge", 0, rs2) => ("blez", x(rs2).to_string()),
+                (mnemonic, rs1, rs2) => (mnemonic, format!("{}, {}", x(rs1), x(rs2))),
+            };
+            branch(
+                length,
+                mnemonic,
+                format!("{operands}, 0x{target:x}"),
+                target,
+            )
+        }
+        // Loads
+        0x03 => {
+            let mnemonic = match funct3 {
+                0b000 => "lb",
+                0b001 => "lh",
+                0b010 => "lw",
+                0b011 if rv64 => "ld",
+                0b100 => "lbu",
+                0b101 => "lhu",
+                0b110 if rv64 => "lwu",
+                _ => return None,
+            };
+            instruction(length, mnemonic, format!("{}, {imm_i}({})", x(rd), x(rs1)))
+        }
+        // Stores
+        0x23 => {
+            let mnemonic = match funct3 {
+                0b000 => "sb",
+                0b001 => "sh",
+                0b010 => "sw",
+                0b011 if rv64 => "sd",
+                _ => return None,
+            };
+            instruction(length, mnemonic, format!("{}, {imm_s}({})", x(rs2), x(rs1)))
+        }
+        0x13 => op_immediate(word, length, xlen),
+        0x1B if rv64 => {
+            let shamt = bits(word, 24, 20);
+            match (funct3, funct7) {
+                (0b000, _) if imm_i == 0 => {
+                    instruction(length, "sext.w", format!("{}, {}", x(rd), x(rs1)))
+                }
+                (0b000, _) => {
+                    instruction(length, "addiw", format!("{}, {}, {imm_i}", x(rd), x(rs1)))
+                }
+                (0b001, 0x00) => {
+                    instruction(length, "slliw", format!("{}, {}, {shamt}", x(rd), x(rs1)))
+                }
+                (0b101, 0x00) => {
+                    instruction(length, "srliw", format!("{}, {}, {shamt}", x(rd), x(rs1)))
+                }
+                (0b101, 0x20) => {
+                    instruction(length, "sraiw", format!("{}, {}, {shamt}", x(rd), x(rs1)))
+                }
+                _ => None,
+            }
+        }
+        0x33 => op_register(word, length),
+        0x3B if rv64 => {
+            let mnemonic = match (funct7, funct3) {
+                (0x00, 0b000) => "addw",
+                (0x20, 0b000) if rs1 == 0 => {
+                    return instruction(length, "negw", format!("{}, {}", x(rd), x(rs2)));
+                }
+                (0x20, 0b000) => "subw",
+                (0x00, 0b001) => "sllw",
+                (0x00, 0b101) => "srlw",
+                (0x20, 0b101) => "sraw",
+                (0x01, 0b000) => "mulw",
+                (0x01, 0b100) => "divw",
+                (0x01, 0b101) => "divuw",
+                (0x01, 0b110) => "remw",
+                (0x01, 0b111) => "remuw",
+                _ => return None,
+            };
+            instruction(
+                length,
+                mnemonic,
+                format!("{}, {}, {}", x(rd), x(rs1), x(rs2)),
+            )
+        }
+        // FENCE, FENCE.TSO, FENCE.I
+        0x0F if rd == 0 && rs1 == 0 => match funct3 {
+            0b000 if bits(word, 31, 20) == 0x833 => instruction(length, "fence.tso", String::new()),
+            0b000 if bits(word, 31, 28) == 0 => {
+                let set = |bits: u32| -> String {
+                    ["i", "o", "r", "w"]
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| bits & (8 >> i) != 0)
+                        .map(|(_, name)| *name)
+                        .collect()
+                };
+                let predecessor = bits(word, 27, 24);
+                let successor = bits(word, 23, 20);
+                match (predecessor, successor) {
+                    (0xF, 0xF) => instruction(length, "fence", String::new()),
+                    (0x1, 0x0) => instruction(length, "pause", String::new()),
+                    _ => instruction(
+                        length,
+                        "fence",
+                        format!("{}, {}", set(predecessor), set(successor)),
+                    ),
+                }
+            }
+            0b001 if imm_i == 0 => instruction(length, "fence.i", String::new()),
+            _ => None,
+        },
+        0x73 => system(word, length),
+        0x2F => atomic(word, length, rv64),
+        // FLW, FLD
+        0x07 => {
+            let mnemonic = match funct3 {
+                0b010 => "flw",
+                0b011 => "fld",
+                _ => return None,
+            };
+            instruction(length, mnemonic, format!("{}, {imm_i}({})", f(rd), x(rs1)))
+        }
+        // FSW, FSD
+        0x27 => {
+            let mnemonic = match funct3 {
+                0b010 => "fsw",
+                0b011 => "fsd",
+                _ => return None,
+            };
+            instruction(length, mnemonic, format!("{}, {imm_s}({})", f(rs2), x(rs1)))
+        }
+        // Fused multiply-add
+        0x43 | 0x47 | 0x4B | 0x4F => {
+            let precision = match bits(word, 26, 25) {
+                0b00 => "s",
+                0b01 => "d",
+                _ => return None,
+            };
+            let operation = match bits(word, 6, 0) {
+                0x43 => "fmadd",
+                0x47 => "fmsub",
+                0x4B => "fnmsub",
+                _ => "fnmadd",
+            };
+            let rs3 = bits(word, 31, 27);
+            instruction(
+                length,
+                &format!("{operation}.{precision}"),
+                format!(
+                    "{}, {}, {}, {}{}",
+                    f(rd),
+                    f(rs1),
+                    f(rs2),
+                    f(rs3),
+                    rounding_mode(funct3)?
+                ),
+            )
+        }
+        0x53 => op_fp(word, length, rv64),
+        _ => None,
+    }
+}
+
+// Suffix naming a static rounding mode, nothing for the dynamic one
+fn rounding_mode(funct3: u32) -> Option<String> {
+    match ROUNDING_MODES[funct3 as usize] {
+        "" => None,
+        "dyn" => Some(String::new()),
+        mode => Some(format!(", {mode}")),
+    }
+}
+
+fn op_immediate(word: u32, length: usize, xlen: u32) -> Option<DecodedInstruction> {
+    let rd = bits(word, 11, 7);
+    let rs1 = bits(word, 19, 15);
+    let funct3 = bits(word, 14, 12);
+    let imm = sign_extend(bits(word, 31, 20), 12);
+    let shamt_high = if xlen == 64 { 25 } else { 24 };
+    let shamt = bits(word, shamt_high, 20);
+    let shift_kind = bits(word, 31, shamt_high + 1) << (shamt_high + 1 - 25);
+
+    let (d, s) = (x(rd), x(rs1));
+    match funct3 {
+        0b000 => match (rd, rs1, imm) {
+            (0, 0, 0) => instruction(length, "nop", String::new()),
+            (_, 0, imm) => instruction(length, "li", format!("{d}, {imm}")),
+            (_, _, 0) => instruction(length, "mv", format!("{d}, {s}")),
+            _ => instruction(length, "addi", format!("{d}, {s}, {imm}")),
+        },
+        0b010 => instruction(length, "slti", format!("{d}, {s}, {imm}")),
+        0b011 if imm == 1 => instruction(length, "seqz", format!("{d}, {s}")),
+        0b011 => instruction(length, "sltiu", format!("{d}, {s}, {imm}")),
+        0b100 if imm == -1 => instruction(length, "not", format!("{d}, {s}")),
+        0b100 => instruction(length, "xori", format!("{d}, {s}, {imm}")),
+        0b110 => instruction(length, "ori", format!("{d}, {s}, {imm}")),
+        0b111 => instruction(length, "andi", format!("{d}, {s}, {imm}")),
+        0b001 if shift_kind == 0 => instruction(length, "slli", format!("{d}, {s}, {shamt}")),
+        0b101 if shift_kind == 0 => instruction(length, "srli", format!("{d}, {s}, {shamt}")),
+        0b101 if shift_kind == 0x20 => instruction(length, "srai", format!("{d}, {s}, {shamt}")),
+        _ => None,
+    }
+}
+
+fn op_register(word: u32, length: usize) -> Option<DecodedInstruction> {
+    let rd = bits(word, 11, 7);
+    let rs1 = bits(word, 19, 15);
+    let rs2 = bits(word, 24, 20);
+    let (d, s1, s2) = (x(rd), x(rs1), x(rs2));
+
+    let mnemonic = match (bits(word, 31, 25), bits(word, 14, 12)) {
+        (0x00, 0b000) => "add",
+        (0x20, 0b000) if rs1 == 0 => return instruction(length, "neg", format!("{d}, {s2}")),
+        (0x20, 0b000) => "sub",
+        (0x00, 0b001) => "sll",
+        (0x00, 0b010) if rs2 == 0 => return instruction(length, "sltz", format!("{d}, {s1}")),
+        (0x00, 0b010) if rs1 == 0 => return instruction(length, "sgtz", format!("{d}, {s2}")),
+        (0x00, 0b010) => "slt",
+        (0x00, 0b011) if rs1 == 0 => return instruction(length, "snez", format!("{d}, {s2}")),
+        (0x00, 0b011) => "sltu",
+        (0x00, 0b100) => "xor",
+        (0x00, 0b101) => "srl",
+        (0x20, 0b101) => "sra",
+        (0x00, 0b110) => "or",
+        (0x00, 0b111) => "and",
+        (0x01, 0b000) => "mul",
+        (0x01, 0b001) => "mulh",
+        (0x01, 0b010) => "mulhsu",
+        (0x01, 0b011) => "mulhu",
+        (0x01, 0b100) => "div",
+        (0x01, 0b101) => "divu",
+        (0x01, 0b110) => "rem",
+        (0x01, 0b111) => "remu",
+        _ => return None,
+    };
+    if mnemonic == "add" && rs1 == 0 {
+        return instruction(length, "mv", format!("{d}, {s2}"));
+    }
+
+    instruction(length, mnemonic, format!("{d}, {s1}, {s2}"))
+}
+
+fn system(word: u32, length: usize) -> Option<DecodedInstruction> {
+    let rd = bits(word, 11, 7);
+    let rs1 = bits(word, 19, 15);
+    let funct3 = bits(word, 14, 12);
+    let csr = bits(word, 31, 20);
+
+    if funct3 == 0 {
+        if rd != 0 {
+            return None;
+        }
+        let mnemonic = match (csr, rs1) {
+            (0x000, 0) => "ecall",
+            (0x001, 0) => "ebreak",
+            (0x102, 0) => "sret",
+            (0x302, 0) => "mret",
+            (0x105, 0) => "wfi",
+            (csr, rs1) if csr >> 5 == 0x09 => {
+                let rs2 = csr & 0x1F;
+                return match (rs1, rs2) {
+                    (0, 0) => instruction(length, "sfence.vma", String::new()),
+                    (rs1, 0) => instruction(length, "sfence.vma", x(rs1).to_string()),
+                    (rs1, rs2) => {
+                        instruction(length, "sfence.vma", format!("{}, {}", x(rs1), x(rs2)))
+                    }
+                };
+            }
+            _ => return None,
+        };
+        return instruction(length, mnemonic, String::new());
+    }
+
+    let name = csr_name(csr);
+    let immediate = funct3 & 0b100 != 0;
+    let source = match immediate {
+        true => rs1.to_string(),
+        false => x(rs1).to_string(),
+    };
+    // Aliases of the counter and floating point CSRs
+    let alias = match (funct3, csr, rd, rs1) {
+        (0b010, 0xC00, _, 0) => Some(("rdcycle", x(rd).to_string())),
+        (0b010, 0xC01, _, 0) => Some(("rdtime", x(rd).to_string())),
+        (0b010, 0xC02, _, 0) => Some(("rdinstret", x(rd).to_string())),
+        (0b010, 0xC80, _, 0) => Some(("rdcycleh", x(rd).to_string())),
+        (0b010, 0xC81, _, 0) => Some(("rdtimeh", x(rd).to_string())),
+        (0b010, 0xC82, _, 0) => Some(("rdinstreth", x(rd).to_string())),
+        (0b010, 0x001, _, 0) => Some(("frflags", x(rd).to_string())),
+        (0b010, 0x002, _, 0) => Some(("frrm", x(rd).to_string())),
+        (0b010, 0x003, _, 0) => Some(("frcsr", x(rd).to_string())),
+        (0b001, 0x001..=0x003, rd, _) => {
+            let mnemonic = ["fsflags", "fsrm", "fscsr"][csr as usize - 1];
+            match rd {
+                0 => Some((mnemonic, source.clone())),
+                rd => Some((mnemonic, format!("{}, {source}", x(rd)))),
+            }
+        }
+        _ => None,
+    };
+    if let Some((mnemonic, operands)) = alias {
+        return instruction(length, mnemonic, operands);
+    }
+
+    let mnemonic = match funct3 & 0b011 {
+        0b01 => "csrrw",
+        0b10 => "csrrs",
+        0b11 => "csrrc",
+        _ => return None,
+    };
+    let suffix = if immediate { "i" } else { "" };
+    match (mnemonic, rd, rs1) {
+        ("csrrs", rd, 0) if !immediate => instruction(length, "csrr", format!("{}, {name}", x(rd))),
+        (mnemonic, 0, _) => {
+            let alias = format!("csr{}{suffix}", &mnemonic[4..]);
+            instruction(length, &alias, format!("{name}, {source}"))
+        }
+        (mnemonic, rd, _) => instruction(
+            length,
+            &format!("{mnemonic}{suffix}"),
+            format!("{}, {name}, {source}", x(rd)),
+        ),
+    }
+}
+
+fn atomic(word: u32, length: usize, rv64: bool) -> Option<DecodedInstruction> {
+    let rd = bits(word, 11, 7);
+    let rs1 = bits(word, 19, 15);
+    let rs2 = bits(word, 24, 20);
+    let width = match bits(word, 14, 12) {
+        0b010 => "w",
+        0b011 if rv64 => "d",
+        _ => return None,
+    };
+    let ordering = match bits(word, 26, 25) {
+        0b00 => "",
+        0b01 => ".rl",
+        0b10 => ".aq",
+        _ => ".aqrl",
+    };
+    let operation = match bits(word, 31, 27) {
+        0b00010 if rs2 == 0 => {
+            return instruction(
+                length,
+                &format!("lr.{width}{ordering}"),
+                format!("{}, ({})", x(rd), x(rs1)),
+            );
+        }
+        0b00011 => "sc",
+        0b00001 => "amoswap",
+        0b00000 => "amoadd",
+        0b00100 => "amoxor",
+        0b01100 => "amoand",
+        0b01000 => "amoor",
+        0b10000 => "amomin",
+        0b10100 => "amomax",
+        0b11000 => "amominu",
+        0b11100 => "amomaxu",
+        _ => return None,
+    };
+
+    instruction(
+        length,
+        &format!("{operation}.{width}{ordering}"),
+        format!("{}, {}, ({})", x(rd), x(rs2), x(rs1)),
+    )
+}
+
+fn op_fp(word: u32, length: usize, rv64: bool) -> Option<DecodedInstruction> {
+    let rd = bits(word, 11, 7);
+    let rs1 = bits(word, 19, 15);
+    let rs2 = bits(word, 24, 20);
+    let rm = bits(word, 14, 12);
+    let funct7 = bits(word, 31, 25);
+    let precision = match funct7 & 0b11 {
+        0b00 => "s",
+        0b01 => "d",
+        _ => return None,
+    };
+    let integer = |rs2: u32| -> Option<&'static str> {
+        match rs2 {
+            0b00000 => Some("w"),
+            0b00001 => Some("wu"),
+            0b00010 if rv64 => Some("l"),
+            0b00011 if rv64 => Some("lu"),
+            _ => None,
+        }
+    };
+
+    match funct7 >> 2 {
+        // FADD, FSUB, FMUL, FDIV
+        0b00000..=0b00011 => {
+            let operation = ["fadd", "fsub", "fmul", "fdiv"][(funct7 >> 2) as usize];
+            instruction(
+                length,
+                &format!("{operation}.{precision}"),
+                format!("{}, {}, {}{}", f(rd), f(rs1), f(rs2), rounding_mode(rm)?),
+            )
+        }
+        // FSQRT
+        0b01011 if rs2 == 0 => instruction(
+            length,
+            &format!("fsqrt.{precision}"),
+            format!("{}, {}{}", f(rd), f(rs1), rounding_mode(rm)?),
+        ),
+        // Sign injection
+        0b00100 => {
+            let (operation, alias) = match rm {
+                0b000 => ("fsgnj", "fmv"),
+                0b001 => ("fsgnjn", "fneg"),
+                0b010 => ("fsgnjx", "fabs"),
+                _ => return None,
+            };
+            match rs1 == rs2 {
+                true => instruction(
+                    length,
+                    &format!("{alias}.{precision}"),
+                    format!("{}, {}", f(rd), f(rs1)),
+                ),
+                false => instruction(
+                    length,
+                    &format!("{operation}.{precision}"),
+                    format!("{}, {}, {}", f(rd), f(rs1), f(rs2)),
+                ),
+            }
+        }
+        // FMIN, FMAX
+        0b00101 => {
+            let operation = match rm {
+                0b000 => "fmin",
+                0b001 => "fmax",
+                _ => return None,
+            };
+            instruction(
+                length,
+                &format!("{operation}.{precision}"),
+                format!("{}, {}, {}", f(rd), f(rs1), f(rs2)),
+            )
+        }
+        // FCVT.S.D, FCVT.D.S
+        0b01000 => match (precision, rs2) {
+            ("s", 0b00001) => instruction(
+                length,
+                "fcvt.s.d",
+                format!("{}, {}{}", f(rd), f(rs1), rounding_mode(rm)?),
+            ),
+            ("d", 0b00000) => instruction(length, "fcvt.d.s", format!("{}, {}", f(rd), f(rs1))),
+            _ => None,
+        },
+        // FEQ, FLT, FLE
+        0b10100 => {
+            let operation = match rm {
+                0b010 => "feq",
+                0b001 => "flt",
+                0b000 => "fle",
+                _ => return None,
+            };
+            instruction(
+                length,
+                &format!("{operation}.{precision}"),
+                format!("{}, {}, {}", x(rd), f(rs1), f(rs2)),
+            )
+        }
+        // FCVT to an integer
+        0b11000 => {
+            let integer = integer(rs2)?;
+            instruction(
+                length,
+                &format!("fcvt.{integer}.{precision}"),
+                format!("{}, {}{}", x(rd), f(rs1), rounding_mode(rm)?),
+            )
+        }
+        // FCVT from an integer, exact when widening a word to a double
+        0b11010 => {
+            let integer = integer(rs2)?;
+            let rounding = match (precision, integer) {
+                ("d", "w" | "wu") => String::new(),
+                _ => rounding_mode(rm)?,
+            };
+            instruction(
+                length,
+                &format!("fcvt.{precision}.{integer}"),
+                format!("{}, {}{rounding}", f(rd), x(rs1)),
+            )
+        }
+        // FMV to an integer register, FCLASS
+        0b11100 if rs2 == 0 => {
+            let integer = if precision == "s" { "w" } else { "d" };
+            match rm {
+                0b000 if precision == "s" || rv64 => instruction(
+                    length,
+                    &format!("fmv.x.{integer}"),
+                    format!("{}, {}", x(rd), f(rs1)),
+                ),
+                0b001 => instruction(
+                    length,
+                    &format!("fclass.{precision}"),
+                    format!("{}, {}", x(rd), f(rs1)),
+                ),
+                _ => None,
+            }
+        }
+        // FMV from an integer register
+        0b11110 if rs2 == 0 && rm == 0 && (precision == "s" || rv64) => {
+            let integer = if precision == "s" { "w" } else { "d" };
+            instruction(
+                length,
+                &format!("fmv.{integer}.x"),
+                format!("{}, {}", f(rd), x(rs1)),
+            )
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+
+    // Checked against llvm-objdump, branch targets are relative to `ADDRESS`.
+    // Encodings with the two low bits set are 4 bytes long, the others are
+    // compressed 2 byte instructions.
+    const ADDRESS: u64 = 0x1000;
+    const RV64_CASES: [(u32, &str); 201] = [
+        // I, with the usual pseudo-instructions
+        (0xffb58513, "addi a0, a1, -5"),
+        (0x06400293, "li t0, 100"),
+        (0x00010413, "mv s0, sp"),
+        (0x00000013, "nop"),
+        (0x12345537, "lui a0, 74565"),
+        (0x00001097, "auipc ra, 1"),
+        (0x00361593, "slli a1, a2, 3"),
+        (0x43f75693, "srai a3, a4, 63"),
+        (0x0015051b, "addiw a0, a0, 1"),
+        (0x0006059b, "sext.w a1, a2"),
+        (0x00c58533, "add a0, a1, a2"),
+        (0x407302b3, "sub t0, t1, t2"),
+        (0x00c5b533, "sltu a0, a1, a2"),
+        (0x40b00533, "neg a0, a1"),
+        (0x00b54533, "xor a0, a0, a1"),
+        (0x00c5953b, "sllw a0, a1, a2"),
+        (0x00410503, "lb a0, 4(sp)"),
+        (0xffe55583, "lhu a1, -2(a0)"),
+        (0x00813083, "ld ra, 8(sp)"),
+        (0x00813823, "sd s0, 16(sp)"),
+        (0x00052023, "sw zero, 0(a0)"),
+        (0x0015b513, "seqz a0, a1"),
+        (0x0055b513, "sltiu a0, a1, 5"),
+        (0xfff6a613, "slti a2, a3, -1"),
+        (0xfff5c513, "not a0, a1"),
+        (0x0075c513, "xori a0, a1, 7"),
+        (0x0106e613, "ori a2, a3, 16"),
+        (0x0ff7f713, "andi a4, a5, 255"),
+        (0x0285d513, "srli a0, a1, 40"),
+        (0x0035951b, "slliw a0, a1, 3"),
+        (0x0046d61b, "srliw a2, a3, 4"),
+        (0x41f7d71b, "sraiw a4, a5, 31"),
+        (0x40c5853b, "subw a0, a1, a2"),
+        (0x40b0053b, "negw a0, a1"),
+        (0x40c5d53b, "sraw a0, a1, a2"),
+        (0x00c59533, "sll a0, a1, a2"),
+        (0x00f756b3, "srl a3, a4, a5"),
+        (0x40c5d533, "sra a0, a1, a2"),
+        (0x00c5a533, "slt a0, a1, a2"),
+        (0x00b03533, "snez a0, a1"),
+        (0x0005a533, "sltz a0, a1"),
+        (0x00b02533, "sgtz a0, a1"),
+        (0x00c5e533, "or a0, a1, a2"),
+        (0x00f776b3, "and a3, a4, a5"),
+        (0xff811503, "lh a0, -8(sp)"),
+        (0x00c62583, "lw a1, 12(a2)"),
+        (0x0006c603, "lbu a2, 0(a3)"),
+        (0x00476683, "lwu a3, 4(a4)"),
+        (0x00a580a3, "sb a0, 1(a1)"),
+        (0xfeb61f23, "sh a1, -2(a2)"),
+        // Branches and jumps
+        (0x00b50863, "beq a0, a1, 0x1010"),
+        (0xfe051ce3, "bnez a0, 0xff8"),
+        (0x02c5d063, "bge a1, a2, 0x1020"),
+        (0x00e6e263, "bltu a3, a4, 0x1004"),
+        (0x100000ef, "jal 0x1100"),
+        (0xff1ff06f, "j 0xff0"),
+        (0x000780e7, "jalr a5"),
+        (0x00008067, "ret"),
+        (0x00050067, "jr a0"),
+        (0x00b54863, "blt a0, a1, 0x1010"),
+        (0xfed678e3, "bgeu a2, a3, 0xff0"),
+        (0x00054463, "bltz a0, 0x1008"),
+        (0x0005d463, "bgez a1, 0x1008"),
+        (0x00c05463, "blez a2, 0x1008"),
+        (0x00d04463, "bgtz a3, 0x1008"),
+        (0x00070463, "beqz a4, 0x1008"),
+        (0x040002ef, "jal t0, 0x1040"),
+        (0x01050367, "jalr t1, 16(a0)"),
+        (0xff8500e7, "jalr -8(a0)"),
+        // System and CSRs
+        (0x00000073, "ecall"),
+        (0x00100073, "ebreak"),
+        (0x0ff0000f, "fence"),
+        (0x0000100f, "fence.i"),
+        (0x30002573, "csrr a0, mstatus"),
+        (0x30559073, "csrw mtvec, a1"),
+        (0x0015a573, "csrrs a0, fflags, a1"),
+        (0x0330000f, "fence rw, rw"),
+        (0x0210000f, "fence r, w"),
+        (0x8330000f, "fence.tso"),
+        (0x3005b573, "csrrc a0, mstatus, a1"),
+        (0x3402d573, "csrrwi a0, mscratch, 5"),
+        (0x30446073, "csrsi mie, 8"),
+        (0x30047073, "csrci mstatus, 8"),
+        (0x00202573, "frrm a0"),
+        (0x00159073, "fsflags a1"),
+        (0xc0002573, "rdcycle a0"),
+        (0xc01025f3, "rdtime a1"),
+        (0xc0202673, "rdinstret a2"),
+        (0x7c002573, "csrr a0, 1984"),
+        (0x10500073, "wfi"),
+        (0x30200073, "mret"),
+        (0x10200073, "sret"),
+        // M
+        (0x02c58533, "mul a0, a1, a2"),
+        (0x02c59533, "mulh a0, a1, a2"),
+        (0x02c5d533, "divu a0, a1, a2"),
+        (0x02c5e53b, "remw a0, a1, a2"),
+        (0x02c5b533, "mulhu a0, a1, a2"),
+        (0x02c5a533, "mulhsu a0, a1, a2"),
+        (0x02c5c533, "div a0, a1, a2"),
+        (0x02c5e533, "rem a0, a1, a2"),
+        (0x02c5f533, "remu a0, a1, a2"),
+        (0x02c5853b, "mulw a0, a1, a2"),
+        (0x02c5c53b, "divw a0, a1, a2"),
+        (0x02c5d53b, "divuw a0, a1, a2"),
+        (0x02c5f53b, "remuw a0, a1, a2"),
+        // A
+        (0x1005a52f, "lr.w a0, (a1)"),
+        (0x1ad7362f, "sc.d.rl a2, a3, (a4)"),
+        (0x06b6252f, "amoadd.w.aqrl a0, a1, (a2)"),
+        (0x08b6352f, "amoswap.d a0, a1, (a2)"),
+        (0x1405b52f, "lr.d.aq a0, (a1)"),
+        (0x18d7262f, "sc.w a2, a3, (a4)"),
+        (0x40b6252f, "amoor.w a0, a1, (a2)"),
+        (0x64b6352f, "amoand.d.aq a0, a1, (a2)"),
+        (0x20b6252f, "amoxor.w a0, a1, (a2)"),
+        (0x80b6252f, "amomin.w a0, a1, (a2)"),
+        (0xa0b6352f, "amomax.d a0, a1, (a2)"),
+        (0xc2b6252f, "amominu.w.rl a0, a1, (a2)"),
+        (0xe0b6352f, "amomaxu.d a0, a1, (a2)"),
+        // F and D
+        (0x00452507, "flw fa0, 4(a0)"),
+        (0x00813427, "fsd fs0, 8(sp)"),
+        (0x00c5f553, "fadd.s fa0, fa1, fa2"),
+        (0x12c59553, "fmul.d fa0, fa1, fa2, rtz"),
+        (0x6ac5f543, "fmadd.d fa0, fa1, fa2, fa3"),
+        (0x5800f053, "fsqrt.s ft0, ft1"),
+        (0xe0050553, "fmv.x.w a0, fa0"),
+        (0xc0051553, "fcvt.w.s a0, fa0, rtz"),
+        (0xd2257553, "fcvt.d.l fa0, a0"),
+        (0xa2b52553, "feq.d a0, fa0, fa1"),
+        (0x22b58553, "fmv.d fa0, fa1"),
+        (0x20b59553, "fneg.s fa0, fa1"),
+        (0xe2051553, "fclass.d a0, fa0"),
+        (0x00a12427, "fsw fa0, 8(sp)"),
+        (0xff053587, "fld fa1, -16(a0)"),
+        (0x0ac5f553, "fsub.d fa0, fa1, fa2"),
+        (0x18c5a553, "fdiv.s fa0, fa1, fa2, rdn"),
+        (0x68c5f547, "fmsub.s fa0, fa1, fa2, fa3"),
+        (0x6ac5f54b, "fnmsub.d fa0, fa1, fa2, fa3"),
+        (0x68c5854f, "fnmadd.s fa0, fa1, fa2, fa3, rne"),
+        (0x22c58553, "fsgnj.d fa0, fa1, fa2"),
+        (0x20c59553, "fsgnjn.s fa0, fa1, fa2"),
+        (0x22c5a553, "fsgnjx.d fa0, fa1, fa2"),
+        (0x22b5a553, "fabs.d fa0, fa1"),
+        (0x28c58553, "fmin.s fa0, fa1, fa2"),
+        (0x2ac59553, "fmax.d fa0, fa1, fa2"),
+        (0x4015f553, "fcvt.s.d fa0, fa1"),
+        (0x42058553, "fcvt.d.s fa0, fa1"),
+        (0xa0b51553, "flt.s a0, fa0, fa1"),
+        (0xa2b50553, "fle.d a0, fa0, fa1"),
+        (0xc0157553, "fcvt.wu.s a0, fa0"),
+        (0xc2253553, "fcvt.l.d a0, fa0, rup"),
+        (0xd0057553, "fcvt.s.w fa0, a0"),
+        (0xd2050553, "fcvt.d.w fa0, a0"),
+        (0xd0354553, "fcvt.s.lu fa0, a0, rmm"),
+        (0xf0050553, "fmv.w.x fa0, a0"),
+        (0xf2050553, "fmv.d.x fa0, a0"),
+        (0xe2050553, "fmv.x.d a0, fa0"),
+        (0xe0051553, "fclass.s a0, fa0"),
+        // C, printed as the instruction it expands to
+        (0x0505, "addi a0, a0, 1"),
+        (0x55f5, "li a1, -3"),
+        (0x852e, "mv a0, a1"),
+        (0x952e, "add a0, a0, a1"),
+        (0x41c8, "lw a0, 4(a1)"),
+        (0xe580, "sd s0, 8(a1)"),
+        (0x60a2, "ld ra, 8(sp)"),
+        (0xec06, "sd ra, 24(sp)"),
+        (0x713d, "addi sp, sp, -32"),
+        (0x0808, "addi a0, sp, 16"),
+        (0xa031, "j 0x100c"),
+        (0xc501, "beqz a0, 0x1008"),
+        (0xfdf5, "bnez a1, 0xffc"),
+        (0x8082, "ret"),
+        (0x9502, "jalr a0"),
+        (0x050a, "slli a0, a0, 2"),
+        (0x8185, "srli a1, a1, 1"),
+        (0x891d, "andi a0, a0, 7"),
+        (0x8d0d, "sub a0, a0, a1"),
+        (0x9d2d, "addw a0, a0, a1"),
+        (0x9c01, "subw s0, s0, s0"),
+        (0x6511, "lui a0, 4"),
+        (0x9002, "ebreak"),
+        (0x0001, "nop"),
+        (0x2588, "fld fa0, 8(a1)"),
+        (0x357d, "addiw a0, a0, -1"),
+        (0x6121, "addi sp, sp, 64"),
+        (0x65fd, "lui a1, 31"),
+        (0x850d, "srai a0, a0, 3"),
+        (0x8d6d, "and a0, a0, a1"),
+        (0x8e55, "or a2, a2, a3"),
+        (0x8f3d, "xor a4, a4, a5"),
+        (0x8502, "jr a0"),
+        (0x4532, "lw a0, 12(sp)"),
+        (0xc42e, "sw a1, 8(sp)"),
+        (0x2542, "fld fa0, 16(sp)"),
+        (0xac2e, "fsd fa1, 24(sp)"),
+        (0xa588, "fsd fa0, 8(a1)"),
+        (0xc1c8, "sw a0, 4(a1)"),
+        (0x6988, "ld a0, 16(a1)"),
+        (0x4501, "li a0, 0"),
+        (0x157e, "slli a0, a0, 63"),
+        // Unallocated encodings
+        (0x0000, "unimp"),
+        (0xffffffff, ".insn 0xffffffff"),
+    ];
+    const RV32_CASES: [(u32, &str); 11] = [
+        (0x2801, "jal 0x1010"),
+        (0x61c8, "flw fa0, 4(a1)"),
+        (0x01f59513, "slli a0, a1, 31"),
+        (0x00c58533, "add a0, a1, a2"),
+        (0x6522, "flw fa0, 8(sp)"),
+        (0xe62e, "fsw fa1, 12(sp)"),
+        (0xe1c8, "fsw fa0, 4(a1)"),
+        (0x3fc5, "jal 0xff0"),
+        (0xc8002573, "rdcycleh a0"),
+        (0x41f5d513, "srai a0, a1, 31"),
+        (0x0004, ".insn 0x0004"),
+    ];
+
+    fn check(cases: &[(u32, &str)], xlen: u32) {
+        for (word, expected) in cases {
+            let decoded = decode(&word.to_le_bytes(), ADDRESS, xlen);
+            let text = format!("{} {}", decoded.mnemonic, decoded.operands);
+            let length = match word & 0b11 {
+                0b11 => 4,
+                _ => 2,
+            };
+            assert_eq!(text.trim_end(), *expected, "{word:#010x}");
+            assert_eq!(decoded.length, length, "{word:#010x}");
+        }
+    }
+
+    #[test]
+    fn decodes_rv64_instructions() {
+        check(&RV64_CASES, 64);
+    }
+
+    #[test]
+    fn decodes_rv32_instructions() {
+        check(&RV32_CASES, 32);
+    }
+}
@@ -0,0 +1,62 @@
+// x86 and x86-64 decoding through iced-x86, printed in the AT&T syntax of
+// GNU objdump.
+
+use iced_x86::{Decoder, DecoderOptions, Formatter, GasFormatter, OpKind};
+
+use super::DecodedInstruction;
+
+pub(super) struct X86Decoder {
+    bitness: u32,
+    formatter: GasFormatter,
+}
+
+impl X86Decoder {
+    pub(super) fn new(bitness: u32) -> Self {
+        let mut formatter = GasFormatter::new();
+        let options = formatter.options_mut();
+        options.set_uppercase_hex(false);
+        options.set_small_hex_numbers_in_decimal(false);
+        options.set_branch_leading_zeros(false);
+        options.set_rip_relative_addresses(true);
+
+        X86Decoder { bitness, formatter }
+    }
+
+    pub(super) fn decode(&mut self, bytes: &[u8], address: u64) -> DecodedInstruction {
+        let mut decoder = Decoder::with_ip(self.bitness, bytes, address, DecoderOptions::NONE);
+        let instruction = decoder.decode();
+        if instruction.is_invalid() {
+            return DecodedInstruction {
+                length: 1,
+                mnemonic: "(bad)".to_string(),
+                operands: String::new(),
+                target: None,
+            };
+        }
+
+        let mut mnemonic = String::new();
+        self.formatter.format_mnemonic(&instruction, &mut mnemonic);
+        let mut operands = String::new();
+        self.formatter
+            .format_all_operands(&instruction, &mut operands);
+
+        let branch = (0..instruction.op_count()).any(|operand| {
+            matches!(
+                instruction.op_kind(operand),
+                OpKind::NearBranch16 | OpKind::NearBranch32 | OpKind::NearBranch64
+            )
+        });
+        let target = match (branch, instruction.is_ip_rel_memory_operand()) {
+            (true, _) => Some(instruction.near_branch_target()),
+            (false, true) => Some(instruction.ip_rel_memory_address()),
+            (false, false) => None,
+        };
+
+        DecodedInstruction {
+            length: instruction.len(),
+            mnemonic,
+            operands,
+            target,
+        }
+    }
+}
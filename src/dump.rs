@@ -0,0 +1,199 @@
+use serde::{Serialize, Serializer};
+
+use crate::{
+    ElfEndianness, ElfError, ElfSectionCompression, ElfSectionFlags, ElfSectionHeader,
+    ElfSectionHeaderType, section_contents,
+};
+
+// Bytes shown per line of a hex dump
+const HEXDUMP_WIDTH: usize = 16;
+
+// The content of a single section, see `parse_section_dump`
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionDump {
+    pub section_index: usize,
+    pub section_name: String,
+    // Virtual address of the first byte, 0 for sections that are not loaded
+    pub section_addr: usize,
+    pub section_offset: usize,
+    // Decompressed content for compressed sections
+    pub data: ElfSectionBytes,
+    // Set when `data` was decompressed, its offsets are then into the
+    // decompressed content instead of the file
+    pub compression: ElfSectionCompression,
+    // Set for SHF_STRINGS and SHT_STRTAB sections, which are listed as strings.
+    // Any sh_entsize other than 0, 1, 2 and 4 is left to the hex dump.
+    pub strings: Option<Vec<ElfSectionString>>,
+}
+
+impl ElfSectionDump {
+    fn write_hexdump(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.compression.0 {
+            Some(header) => writeln!(
+                f,
+                "Hex dump of section '{}' (decompressed from {}):",
+                self.section_name, header.compression_type
+            )?,
+            None => writeln!(f, "Hex dump of section '{}':", self.section_name)?,
+        }
+        if self.data.0.is_empty() {
+            return write!(f, "Section has no data in the file.");
+        }
+
+        let file_offset = match self.compression.0 {
+            Some(_) => 0,
+            None => self.section_offset,
+        };
+        for (line, bytes) in self.data.0.chunks(HEXDUMP_WIDTH).enumerate() {
+            let position = line * HEXDUMP_WIDTH;
+            let hex = (0..HEXDUMP_WIDTH)
+                .map(|i| match bytes.get(i) {
+                    Some(byte) => format!("{byte:02x}"),
+                    None => "  ".to_string(),
+                })
+                .collect::<Vec<String>>();
+            let ascii = bytes
+                .iter()
+                .map(|byte| match byte.is_ascii_graphic() || *byte == b' ' {
+                    true => *byte as char,
+                    false => '.',
+                })
+                .collect::<String>();
+            if line != 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "  {:#010x}  {:08x}  {}  {}  |{}|",
+                self.section_addr.wrapping_add(position),
+                file_offset.wrapping_add(position),
+                hex[..HEXDUMP_WIDTH / 2].join(" "),
+                hex[HEXDUMP_WIDTH / 2..].join(" "),
+                ascii
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for ElfSectionDump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(strings) = &self.strings else {
+            return self.write_hexdump(f);
+        };
+
+        write!(f, "String dump of section '{}':", self.section_name)?;
+        for string in strings {
+            write!(f, "\n  [{:6x}]  {}", string.offset, string.string)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Raw section content, serialised as space separated hex bytes like `data` in
+// the sections data entries
+#[derive(Debug, Default)]
+pub struct ElfSectionBytes(pub Vec<u8>);
+
+impl Serialize for ElfSectionBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let hex = self
+            .0
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect::<Vec<String>>()
+            .join(" ");
+        serializer.serialize_str(&hex)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElfSectionString {
+    // Offset of the string in the section
+    pub offset: usize,
+    pub string: String,
+}
+
+// Decodes one string of `unit` byte characters: UTF-8, UTF-16 or UTF-32 in
+// the byte order of the file.
+fn decode_string(bytes: &[u8], unit: usize, endian: &ElfEndianness) -> String {
+    match unit {
+        2 => char::decode_utf16(bytes.chunks_exact(2).map(|c| endian.u16_from(c)))
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect(),
+        4 => bytes
+            .chunks_exact(4)
+            .map(|c| char::from_u32(endian.u32_from(c)).unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect(),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+// Splits a string section into strings terminated by a zero character of
+// sh_entsize bytes, skipping the empty ones that pad it. An sh_entsize of 0
+// means single bytes, `None` for any other than 0, 1, 2 and 4.
+fn section_strings(
+    data: &[u8],
+    entry_size: usize,
+    endian: &ElfEndianness,
+) -> Option<Vec<ElfSectionString>> {
+    let unit = match entry_size {
+        0 | 1 => 1,
+        2 | 4 => entry_size,
+        _ => return None,
+    };
+
+    let mut strings = Vec::new();
+    let mut start = 0;
+    for (index, character) in data.chunks(unit).enumerate() {
+        let end = index * unit;
+        if character.iter().any(|byte| *byte != 0) {
+            continue;
+        }
+        if end > start {
+            strings.push(ElfSectionString {
+                offset: start,
+                string: decode_string(&data[start..end], unit, endian),
+            });
+        }
+        start = end + character.len();
+    }
+    if start < data.len() {
+        strings.push(ElfSectionString {
+            offset: start,
+            string: decode_string(&data[start..], unit, endian),
+        });
+    }
+
+    Some(strings)
+}
+
+// Extracts the section selected by name or index, see `ElfSectionHeader::find`.
+pub fn parse_section_dump(
+    content: &[u8],
+    section_header: &ElfSectionHeader,
+    selector: &str,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionDump, ElfError> {
+    let (section_index, entry) = section_header
+        .find(selector)
+        .ok_or_else(|| ElfError::SectionNotFound(selector.to_string()))?;
+    let data = section_contents(content, entry)?;
+    let is_strings = entry.section_flags.contains(ElfSectionFlags::SHF_STRINGS)
+        || matches!(entry.section_header_type, ElfSectionHeaderType::ShtStrtab);
+
+    Ok(ElfSectionDump {
+        section_index,
+        section_name: entry.section_name.inner(),
+        section_addr: entry.section_addr.0,
+        section_offset: entry.section_offset.0,
+        strings: match is_strings {
+            true => section_strings(&data, entry.section_entry_size.0, endian),
+            false => None,
+        },
+        data: ElfSectionBytes(data.into_owned()),
+        compression: ElfSectionCompression(entry.section_compression.0.clone()),
+    })
+}
@@ -0,0 +1,953 @@
+use std::{borrow::Cow, convert::Infallible};
+
+use gimli::{AttributeValue, DebuggingInformationEntry, EndianSlice, RunTimeEndian, UnitRef};
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfEndianness, ElfError, ElfHeader, ElfInstructionSet, ElfObjectFileType, ElfRelocation,
+    ElfRelocationTables, ElfSectionHeader, section_contents, serialize_named,
+};
+
+type DwarfReader<'data> = EndianSlice<'data, RunTimeEndian>;
+
+// Abstract origins and specifications followed to name a DIE
+const MAX_ORIGIN_DEPTH: usize = 8;
+// DIEs looked at to write a single type, so that hostile files with deep or
+// wide type graphs cannot make it explode
+const TYPE_BUDGET: usize = 64;
+
+// The compilation units of .debug_info with their line tables
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDwarf {
+    pub inner: Vec<ElfDwarfUnit>,
+}
+
+impl ElfDwarf {
+    pub fn inner(self) -> Vec<ElfDwarfUnit> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfDwarf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDwarfUnit {
+    // Offset of the unit header in .debug_info
+    pub offset: usize,
+    pub version: u16,
+    pub address_size: u8,
+    // DW_AT_name of the unit, usually the main source file
+    pub name: String,
+    pub comp_dir: String,
+    pub producer: String,
+    pub language: ElfDwarfLanguage,
+    // Every DIE of the unit in depth-first order, the unit itself first
+    pub entries: Vec<ElfDwarfEntry>,
+    // Rows of the line-number program, one sequence after the other
+    pub lines: Vec<ElfDwarfLine>,
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfDwarfEntry {
+    // Offset of the DIE in .debug_info, the target of DW_FORM_ref_addr
+    pub offset: ElfDwarfOffset,
+    // 0 for the unit DIE, children are one deeper than their parent
+    pub depth: usize,
+    pub tag: ElfDwarfTag,
+    pub name: ElfDwarfString,
+    // DW_AT_type written as C, e.g. `const char *`
+    pub type_name: ElfDwarfString,
+    pub byte_size: ElfDwarfNumber,
+    pub low_pc: ElfDwarfPc,
+    pub high_pc: ElfDwarfPc,
+    pub decl_file: ElfDwarfString,
+    pub decl_line: ElfDwarfNumber,
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfDwarfLine {
+    pub address: ElfDwarfAddress,
+    pub file: String,
+    // 0 for code that does not come from any line
+    pub line: u64,
+    // 0 for the left edge of the line
+    pub column: u64,
+    pub flags: ElfDwarfLineFlags,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDwarfOffset(pub usize);
+
+impl std::fmt::Display for ElfDwarfOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDwarfAddress(pub u64);
+
+impl std::fmt::Display for ElfDwarfAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+// `None` for DIEs that cover no code or data
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDwarfPc(pub Option<u64>);
+
+impl std::fmt::Display for ElfDwarfPc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(pc) => write!(f, "{:X}", pc),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDwarfString(pub Option<String>);
+
+impl std::fmt::Display for ElfDwarfString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(string) => write!(f, "{}", string),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDwarfNumber(pub Option<u64>);
+
+impl std::fmt::Display for ElfDwarfNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(number) => write!(f, "{}", number),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfDwarfTag(pub u16);
+
+impl ElfDwarfTag {
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ElfDwarfTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match gimli::DwTag(self.0).static_string() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "DW_TAG_<{:#x}>", self.0),
+        }
+    }
+}
+
+impl Serialize for ElfDwarfTag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+// DW_AT_language of a unit, `None` when the producer left it out
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfDwarfLanguage(pub Option<u16>);
+
+impl std::fmt::Display for ElfDwarfLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self
+            .0
+            .map(|language| (language, gimli::DwLang(language).static_string()))
+        {
+            Some((_, Some(name))) => write!(f, "{}", name),
+            Some((language, None)) => write!(f, "DW_LANG_<{:#x}>", language),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+impl Serialize for ElfDwarfLanguage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(language) => serialize_named(serializer, u64::from(language), self),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+// Register flags of a line table row, named like llvm-dwarfdump does
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDwarfLineFlags(pub Vec<&'static str>);
+
+impl std::fmt::Display for ElfDwarfLineFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join(" "))
+    }
+}
+
+// Where an address comes from in the source. Code inlined into a function
+// gives one frame per call, innermost first.
+#[derive(Debug, Default, Serialize)]
+pub struct ElfAddr2Line {
+    pub address: u64,
+    pub inner: Vec<ElfSourceFrame>,
+}
+
+impl ElfAddr2Line {
+    pub fn inner(self) -> Vec<ElfSourceFrame> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfAddr2Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let frames = self
+            .inner
+            .iter()
+            .map(|frame| frame.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "{}", frames.join("\n"))
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfSourceFrame {
+    pub function: ElfDwarfString,
+    pub file: ElfDwarfString,
+    // 0 when the line is unknown
+    pub line: u64,
+    pub column: u64,
+    // Set on every frame but the outermost when the code was inlined
+    pub inlined: bool,
+}
+
+impl std::fmt::Display for ElfSourceFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.function)
+    }
+}
+
+// Section data gimli reads from, with the relocations of relocatable
+// objects applied
+struct DebugSections<'data> {
+    inner: Vec<(&'static str, usize, Cow<'data, [u8]>)>,
+    endian: RunTimeEndian,
+}
+
+impl<'data> DebugSections<'data> {
+    fn load(
+        content: &'data [u8],
+        header: &ElfHeader,
+        section_header: &ElfSectionHeader,
+        relocation_tables: &ElfRelocationTables,
+    ) -> Result<Self, ElfError> {
+        let endian = match header.endianness {
+            ElfEndianness::Little => RunTimeEndian::Little,
+            ElfEndianness::Big => RunTimeEndian::Big,
+        };
+        let relocatable = matches!(header.object_file_type, ElfObjectFileType::EtRel);
+        let mut inner = Vec::new();
+        for id in SECTION_IDS {
+            let name = id.name();
+            // Legacy compressed sections are named .zdebug_* instead of .debug_*
+            let legacy = name.replacen(".debug", ".zdebug", 1);
+            let Some((_, entry)) = section_header
+                .find(name)
+                .or_else(|| section_header.find(&legacy))
+            else {
+                continue;
+            };
+            let mut data = section_contents(content, entry)?;
+            let relocations = relocation_tables
+                .inner
+                .iter()
+                .filter(|table| relocatable && table.applies_to == entry.section_name.0)
+                .flat_map(|table| table.inner.iter());
+            for relocation in relocations {
+                relocate(
+                    data.to_mut(),
+                    entry.section_offset.0,
+                    relocation,
+                    &header.instruction_set,
+                    &header.endianness,
+                )?;
+            }
+            inner.push((name, entry.section_offset.0, data));
+        }
+
+        Ok(DebugSections { inner, endian })
+    }
+
+    fn data(&self, name: &str) -> &[u8] {
+        self.inner
+            .iter()
+            .find(|(section, _, _)| *section == name)
+            .map_or(&[], |(_, _, data)| data)
+    }
+
+    // Turns a gimli error into one pointing at `offset` in section `name`
+    fn error(&self, name: &'static str, offset: usize) -> impl Fn(gimli::Error) -> ElfError {
+        let base = self
+            .inner
+            .iter()
+            .find(|(section, _, _)| *section == name)
+            .map_or(0, |(_, offset, _)| *offset);
+        move |err| ElfError::Dwarf {
+            section: name,
+            offset: base.saturating_add(offset),
+            message: err.to_string(),
+        }
+    }
+
+    fn dwarf(&self) -> gimli::Dwarf<DwarfReader<'_>> {
+        let load = |id: gimli::SectionId| -> Result<DwarfReader<'_>, Infallible> {
+            Ok(EndianSlice::new(self.data(id.name()), self.endian))
+        };
+        let Ok(dwarf) = gimli::Dwarf::load(load);
+        dwarf
+    }
+}
+
+// The sections `gimli::Dwarf::load` asks for
+const SECTION_IDS: [gimli::SectionId; 16] = [
+    gimli::SectionId::DebugAbbrev,
+    gimli::SectionId::DebugAddr,
+    gimli::SectionId::DebugAranges,
+    gimli::SectionId::DebugInfo,
+    gimli::SectionId::DebugLine,
+    gimli::SectionId::DebugLineStr,
+    gimli::SectionId::DebugMacinfo,
+    gimli::SectionId::DebugMacro,
+    gimli::SectionId::DebugNames,
+    gimli::SectionId::DebugStr,
+    gimli::SectionId::DebugStrOffsets,
+    gimli::SectionId::DebugTypes,
+    gimli::SectionId::DebugLoc,
+    gimli::SectionId::DebugLocLists,
+    gimli::SectionId::DebugRanges,
+    gimli::SectionId::DebugRngLists,
+];
+
+// How a relocation found in a debug section changes the bytes it points at
+enum DebugRelocation {
+    // Stores S + A in that many bytes
+    Set(usize),
+    // Adds or subtracts S + A, RISC-V writes label differences this way
+    Add(usize),
+    Sub(usize),
+}
+
+// References from debug sections to other sections of a relocatable object
+// are 0 until linked. Only the absolute relocations compilers emit there are
+// known, others are left alone.
+fn debug_relocation(machine: &ElfInstructionSet, r_type: u32) -> Option<DebugRelocation> {
+    let relocation = match (machine, r_type) {
+        (ElfInstructionSet::AmdX86_64, 1) => DebugRelocation::Set(8), // R_X86_64_64
+        (ElfInstructionSet::AmdX86_64, 10 | 11) => DebugRelocation::Set(4), // R_X86_64_32(S)
+        (ElfInstructionSet::X86, 1) => DebugRelocation::Set(4),       // R_386_32
+        (ElfInstructionSet::Arm, 2) => DebugRelocation::Set(4),       // R_ARM_ABS32
+        (ElfInstructionSet::Arm64bit, 257) => DebugRelocation::Set(8), // R_AARCH64_ABS64
+        (ElfInstructionSet::Arm64bit, 258) => DebugRelocation::Set(4), // R_AARCH64_ABS32
+        (ElfInstructionSet::RiscV, 1) => DebugRelocation::Set(4),     // R_RISCV_32
+        (ElfInstructionSet::RiscV, 2) => DebugRelocation::Set(8),     // R_RISCV_64
+        (ElfInstructionSet::RiscV, 33..=36) => DebugRelocation::Add(1 << (r_type - 33)), // R_RISCV_ADD8..64
+        (ElfInstructionSet::RiscV, 37..=40) => DebugRelocation::Sub(1 << (r_type - 37)), // R_RISCV_SUB8..64
+        (ElfInstructionSet::RiscV, 54..=56) => DebugRelocation::Set(1 << (r_type - 54)), // R_RISCV_SET8..32
+        _ => return None,
+    };
+
+    Some(relocation)
+}
+
+fn relocate(
+    data: &mut [u8],
+    section_offset: usize,
+    relocation: &ElfRelocation,
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+) -> Result<(), ElfError> {
+    let Some(kind) = debug_relocation(machine, relocation.relocation_type.value) else {
+        return Ok(());
+    };
+    let size = match kind {
+        DebugRelocation::Set(size) | DebugRelocation::Add(size) | DebugRelocation::Sub(size) => {
+            size
+        }
+    };
+    let start = relocation.relocation_offset.0;
+    let available = data.len().saturating_sub(start);
+    let Some(slot) = data.get_mut(start..start.saturating_add(size)) else {
+        return Err(ElfError::Truncated {
+            field: "relocated debug data",
+            offset: section_offset.saturating_add(start),
+            expected: size,
+            actual: available,
+        });
+    };
+
+    let mut bytes = [0u8; 8];
+    match endian {
+        ElfEndianness::Little => bytes[..size].copy_from_slice(slot),
+        ElfEndianness::Big => bytes[8 - size..].copy_from_slice(slot),
+    }
+    let current = match endian {
+        ElfEndianness::Little => u64::from_le_bytes(bytes),
+        ElfEndianness::Big => u64::from_be_bytes(bytes),
+    };
+    // SHT_REL entries keep their addend in the relocated field
+    let addend = match relocation.relocation_addend.0 {
+        Some(addend) => addend as u64,
+        None => current,
+    };
+    let value = (relocation.symbol_value.0 as u64).wrapping_add(addend);
+    let value = match kind {
+        DebugRelocation::Set(_) => value,
+        DebugRelocation::Add(_) => current.wrapping_add(value),
+        DebugRelocation::Sub(_) => current.wrapping_sub(value),
+    };
+
+    match endian {
+        ElfEndianness::Little => slot.copy_from_slice(&value.to_le_bytes()[..size]),
+        ElfEndianness::Big => slot.copy_from_slice(&value.to_be_bytes()[8 - size..]),
+    }
+    Ok(())
+}
+
+fn to_string(value: DwarfReader<'_>) -> String {
+    value.to_string_lossy().into_owned()
+}
+
+// Joins a path to the directory it is relative to, absolute paths are kept
+fn join_path(directory: &str, path: String) -> String {
+    match directory.is_empty() || path.starts_with('/') {
+        true => path,
+        false => format!("{}/{}", directory.trim_end_matches('/'), path),
+    }
+}
+
+// Full paths of the files in the line table header of a unit, by file index
+fn file_names<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+) -> Result<Vec<Option<String>>, gimli::Error> {
+    let Some(program) = &unit.line_program else {
+        return Ok(Vec::new());
+    };
+    let header = program.header();
+    let comp_dir = unit.comp_dir.map(to_string).unwrap_or_default();
+
+    (0..=header.file_names().len() as u64)
+        .map(|index| {
+            let Some(file) = header.file(index) else {
+                return Ok(None);
+            };
+            let mut path = to_string(unit.attr_string(file.path_name())?);
+            if let Some(directory) = file.directory(header) {
+                path = join_path(&to_string(unit.attr_string(directory)?), path);
+            }
+            Ok(Some(join_path(&comp_dir, path)))
+        })
+        .collect()
+}
+
+fn file_name(
+    files: &[Option<String>],
+    value: Option<AttributeValue<DwarfReader<'_>>>,
+) -> Option<String> {
+    match value? {
+        AttributeValue::FileIndex(index) => files.get(index as usize)?.clone(),
+        _ => None,
+    }
+}
+
+fn attr_string<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    entry: &DebuggingInformationEntry<DwarfReader<'data>>,
+    name: gimli::DwAt,
+) -> Result<Option<String>, gimli::Error> {
+    match entry.attr_value(name) {
+        Some(value) => Ok(Some(to_string(unit.attr_string(value)?))),
+        None => Ok(None),
+    }
+}
+
+// DW_AT_name of a DIE, or of the declaration or abstract instance it
+// completes for out-of-line and inlined copies of functions
+fn entry_name<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    entry: &DebuggingInformationEntry<DwarfReader<'data>>,
+    depth: usize,
+) -> Result<Option<String>, gimli::Error> {
+    if let Some(name) = attr_string(unit, entry, gimli::DW_AT_name)? {
+        return Ok(Some(name));
+    }
+    if depth >= MAX_ORIGIN_DEPTH {
+        return Ok(None);
+    }
+    for origin in [gimli::DW_AT_abstract_origin, gimli::DW_AT_specification] {
+        if let Some(AttributeValue::UnitRef(offset)) = entry.attr_value(origin) {
+            return entry_name(unit, &unit.entry(offset)?, depth + 1);
+        }
+    }
+
+    Ok(None)
+}
+
+fn unsigned(value: Option<AttributeValue<DwarfReader<'_>>>) -> Option<u64> {
+    value?.udata_value()
+}
+
+// Adds a pointer, reference or qualifier to a C type, binding it to the
+// pointer when there is one: `char *const` but `const char`
+fn qualify(inner: String, qualifier: &str, prefix: bool) -> String {
+    match (inner.ends_with(['*', '&']), prefix) {
+        (true, _) => format!("{inner}{qualifier}"),
+        (false, true) => format!("{qualifier} {inner}"),
+        (false, false) => format!("{inner} {qualifier}"),
+    }
+}
+
+// The type a DW_AT_type attribute refers to, written as C. Each DIE looked
+// at takes one from `budget`, what is left once it runs out shows as `...`.
+fn type_name<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    value: Option<AttributeValue<DwarfReader<'data>>>,
+    budget: &mut usize,
+) -> Result<String, gimli::Error> {
+    let offset = match value {
+        None => return Ok("void".to_string()),
+        Some(AttributeValue::UnitRef(offset)) => offset,
+        Some(AttributeValue::DebugInfoRef(offset)) => return Ok(format!("<{:#x}>", offset.0)),
+        Some(_) => return Ok("?".to_string()),
+    };
+    if *budget == 0 {
+        return Ok("...".to_string());
+    }
+    *budget -= 1;
+    let entry = unit.entry(offset)?;
+    let inner = entry.attr_value(gimli::DW_AT_type);
+
+    let name = match entry.tag() {
+        gimli::DW_TAG_pointer_type
+        | gimli::DW_TAG_reference_type
+        | gimli::DW_TAG_rvalue_reference_type => {
+            let sigil = match entry.tag() {
+                gimli::DW_TAG_pointer_type => "*",
+                gimli::DW_TAG_reference_type => "&",
+                _ => "&&",
+            };
+            match subroutine(unit, inner)? {
+                Some(function) => function_type(unit, &function, &format!("({sigil})"), budget)?,
+                None => qualify(type_name(unit, inner, budget)?, sigil, false),
+            }
+        }
+        gimli::DW_TAG_const_type => qualify(type_name(unit, inner, budget)?, "const", true),
+        gimli::DW_TAG_volatile_type => qualify(type_name(unit, inner, budget)?, "volatile", true),
+        gimli::DW_TAG_restrict_type => qualify(type_name(unit, inner, budget)?, "restrict", true),
+        gimli::DW_TAG_array_type => {
+            let mut bounds = String::new();
+            for child in children(unit, &entry)? {
+                if child.tag() != gimli::DW_TAG_subrange_type {
+                    continue;
+                }
+                let count = unsigned(child.attr_value(gimli::DW_AT_count)).or_else(|| {
+                    unsigned(child.attr_value(gimli::DW_AT_upper_bound)).map(|bound| bound + 1)
+                });
+                match count {
+                    Some(count) => bounds.push_str(&format!("[{count}]")),
+                    None => bounds.push_str("[]"),
+                }
+            }
+            if bounds.is_empty() {
+                bounds.push_str("[]");
+            }
+            format!("{}{bounds}", type_name(unit, inner, budget)?)
+        }
+        gimli::DW_TAG_subroutine_type => function_type(unit, &entry, "", budget)?,
+        tag => match attr_string(unit, &entry, gimli::DW_AT_name)? {
+            Some(name) => name,
+            None => match tag {
+                gimli::DW_TAG_structure_type => "struct <anonymous>".to_string(),
+                gimli::DW_TAG_class_type => "class <anonymous>".to_string(),
+                gimli::DW_TAG_union_type => "union <anonymous>".to_string(),
+                gimli::DW_TAG_enumeration_type => "enum <anonymous>".to_string(),
+                gimli::DW_TAG_unspecified_type => "void".to_string(),
+                _ => "?".to_string(),
+            },
+        },
+    };
+
+    Ok(name)
+}
+
+// The direct children of a DIE
+fn children<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    entry: &DebuggingInformationEntry<DwarfReader<'data>>,
+) -> Result<Vec<DebuggingInformationEntry<DwarfReader<'data>>>, gimli::Error> {
+    let mut children = Vec::new();
+    if !entry.has_children() {
+        return Ok(children);
+    }
+    let mut cursor = unit.entries_at_offset(entry.offset())?;
+    // Step onto the first child, or the null entry ending an empty list
+    cursor.next_entry()?;
+    cursor.next_entry()?;
+    let mut child = cursor.current().cloned();
+    while let Some(entry) = child {
+        children.push(entry);
+        child = cursor.next_sibling()?.cloned();
+    }
+
+    Ok(children)
+}
+
+// The DW_TAG_subroutine_type an attribute refers to, if it does
+fn subroutine<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    value: Option<AttributeValue<DwarfReader<'data>>>,
+) -> Result<Option<DebuggingInformationEntry<DwarfReader<'data>>>, gimli::Error> {
+    let Some(AttributeValue::UnitRef(offset)) = value else {
+        return Ok(None);
+    };
+    let entry = unit.entry(offset)?;
+    Ok((entry.tag() == gimli::DW_TAG_subroutine_type).then_some(entry))
+}
+
+// `int (char, ...)` for a subroutine type, `int (*)(char, ...)` when seen
+// through the `(*)` of a pointer
+fn function_type<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    entry: &DebuggingInformationEntry<DwarfReader<'data>>,
+    pointer: &str,
+    budget: &mut usize,
+) -> Result<String, gimli::Error> {
+    let result = type_name(unit, entry.attr_value(gimli::DW_AT_type), budget)?;
+    let mut parameters = Vec::new();
+    for child in children(unit, entry)? {
+        match child.tag() {
+            gimli::DW_TAG_formal_parameter => parameters.push(type_name(
+                unit,
+                child.attr_value(gimli::DW_AT_type),
+                budget,
+            )?),
+            gimli::DW_TAG_unspecified_parameters => parameters.push("...".to_string()),
+            _ => {}
+        }
+    }
+
+    Ok(format!("{result} {pointer}({})", parameters.join(", ")))
+}
+
+fn entry_pc<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    entry: &DebuggingInformationEntry<DwarfReader<'data>>,
+) -> Result<(Option<u64>, Option<u64>), gimli::Error> {
+    let low_pc = match entry.attr_value(gimli::DW_AT_low_pc) {
+        Some(value) => unit.attr_address(value)?,
+        None => None,
+    };
+    // DW_AT_high_pc is the size of the range unless it has an address form
+    let high_pc = match (low_pc, entry.attr_value(gimli::DW_AT_high_pc)) {
+        (Some(low_pc), Some(AttributeValue::Udata(size))) => Some(low_pc.wrapping_add(size)),
+        (_, Some(value)) => unit.attr_address(value)?,
+        (_, None) => None,
+    };
+
+    Ok((low_pc, high_pc))
+}
+
+fn contains<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    entry: &DebuggingInformationEntry<DwarfReader<'data>>,
+    address: u64,
+) -> Result<bool, gimli::Error> {
+    let mut ranges = unit.die_ranges(entry)?;
+    while let Some(range) = ranges.next()? {
+        if range.begin <= address && address < range.end {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+fn parse_entries<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    files: &[Option<String>],
+) -> Result<Vec<ElfDwarfEntry>, gimli::Error> {
+    let mut entries = Vec::new();
+    let mut cursor = unit.entries();
+    while let Some(entry) = cursor.next_dfs()? {
+        let (low_pc, high_pc) = entry_pc(unit, entry)?;
+        let type_name = match entry.attr_value(gimli::DW_AT_type) {
+            Some(value) => {
+                let mut budget = TYPE_BUDGET;
+                Some(type_name(unit, Some(value), &mut budget)?)
+            }
+            None => None,
+        };
+        let offset = entry
+            .offset()
+            .to_debug_info_offset(&unit.header)
+            .map_or(0, |offset| offset.0);
+
+        entries.push(ElfDwarfEntry {
+            offset: ElfDwarfOffset(offset),
+            depth: entry.depth().max(0) as usize,
+            tag: ElfDwarfTag(entry.tag().0),
+            name: ElfDwarfString(entry_name(unit, entry, 0)?),
+            type_name: ElfDwarfString(type_name),
+            byte_size: ElfDwarfNumber(unsigned(entry.attr_value(gimli::DW_AT_byte_size))),
+            low_pc: ElfDwarfPc(low_pc),
+            high_pc: ElfDwarfPc(high_pc),
+            decl_file: ElfDwarfString(file_name(files, entry.attr_value(gimli::DW_AT_decl_file))),
+            decl_line: ElfDwarfNumber(unsigned(entry.attr_value(gimli::DW_AT_decl_line))),
+        });
+    }
+
+    Ok(entries)
+}
+
+fn column(row: &gimli::LineRow) -> u64 {
+    match row.column() {
+        gimli::ColumnType::LeftEdge => 0,
+        gimli::ColumnType::Column(column) => column.get(),
+    }
+}
+
+fn parse_lines<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    files: &[Option<String>],
+) -> Result<Vec<ElfDwarfLine>, gimli::Error> {
+    let mut lines = Vec::new();
+    let Some(program) = unit.line_program.clone() else {
+        return Ok(lines);
+    };
+    let mut rows = program.rows();
+    while let Some((_, row)) = rows.next_row()? {
+        let flags = [
+            (row.is_stmt(), "is_stmt"),
+            (row.basic_block(), "basic_block"),
+            (row.prologue_end(), "prologue_end"),
+            (row.epilogue_begin(), "epilogue_begin"),
+            (row.end_sequence(), "end_sequence"),
+        ];
+        lines.push(ElfDwarfLine {
+            address: ElfDwarfAddress(row.address()),
+            file: file_name(files, Some(AttributeValue::FileIndex(row.file_index())))
+                .unwrap_or_default(),
+            line: row.line().map_or(0, |line| line.get()),
+            column: column(row),
+            flags: ElfDwarfLineFlags(
+                flags
+                    .into_iter()
+                    .filter_map(|(set, name)| set.then_some(name))
+                    .collect(),
+            ),
+        });
+    }
+
+    Ok(lines)
+}
+
+// Row of the line table covering `address`, as (file index, line, column)
+fn find_line<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    address: u64,
+) -> Result<Option<(u64, u64, u64)>, gimli::Error> {
+    let Some(program) = unit.line_program.clone() else {
+        return Ok(None);
+    };
+    let mut rows = program.rows();
+    let mut previous: Option<(u64, (u64, u64, u64))> = None;
+    while let Some((_, row)) = rows.next_row()? {
+        if let Some((start, location)) = previous
+            && start <= address
+            && address < row.address()
+        {
+            return Ok(Some(location));
+        }
+        let location = (
+            row.file_index(),
+            row.line().map_or(0, |line| line.get()),
+            column(row),
+        );
+        previous = (!row.end_sequence()).then_some((row.address(), location));
+    }
+
+    Ok(None)
+}
+
+// A function, or inlined call of one, that covers the address looked up
+struct Scope {
+    depth: isize,
+    name: Option<String>,
+    inlined: bool,
+    // Where an inlined call was made from
+    call: (Option<String>, u64, u64),
+}
+
+fn source_frames<'data>(
+    unit: UnitRef<'_, DwarfReader<'data>>,
+    files: &[Option<String>],
+    address: u64,
+) -> Result<Vec<ElfSourceFrame>, gimli::Error> {
+    let mut scopes: Vec<Scope> = Vec::new();
+    let mut cursor = unit.entries();
+    while let Some(entry) = cursor.next_dfs()? {
+        let inlined = entry.tag() == gimli::DW_TAG_inlined_subroutine;
+        if !(inlined || entry.tag() == gimli::DW_TAG_subprogram) || !contains(unit, entry, address)?
+        {
+            continue;
+        }
+        // Scopes that are not ancestors of this one end before it
+        scopes.retain(|scope| scope.depth < entry.depth());
+        scopes.push(Scope {
+            depth: entry.depth(),
+            name: entry_name(unit, entry, 0)?,
+            inlined,
+            call: (
+                file_name(files, entry.attr_value(gimli::DW_AT_call_file)),
+                unsigned(entry.attr_value(gimli::DW_AT_call_line)).unwrap_or_default(),
+                unsigned(entry.attr_value(gimli::DW_AT_call_column)).unwrap_or_default(),
+            ),
+        });
+    }
+
+    let (mut file, mut line, mut column) = match find_line(unit, address)? {
+        Some((index, line, column)) => (
+            file_name(files, Some(AttributeValue::FileIndex(index))),
+            line,
+            column,
+        ),
+        None => (None, 0, 0),
+    };
+    let mut frames = Vec::new();
+    for scope in scopes.into_iter().rev() {
+        frames.push(ElfSourceFrame {
+            function: ElfDwarfString(scope.name),
+            file: ElfDwarfString(file),
+            line,
+            column,
+            inlined: scope.inlined,
+        });
+        (file, line, column) = scope.call;
+    }
+    if frames.is_empty() {
+        frames.push(ElfSourceFrame {
+            function: ElfDwarfString(None),
+            file: ElfDwarfString(file),
+            line,
+            column,
+            inlined: false,
+        });
+    }
+
+    Ok(frames)
+}
+
+fn line_offset(unit: UnitRef<'_, DwarfReader<'_>>) -> usize {
+    unit.line_program
+        .as_ref()
+        .map_or(0, |program| program.header().offset().0)
+}
+
+// Reads every unit of .debug_info. Addresses are section offsets in
+// relocatable objects, whose debug relocations are applied first.
+pub fn parse_dwarf(
+    content: &[u8],
+    header: &ElfHeader,
+    section_header: &ElfSectionHeader,
+    relocation_tables: &ElfRelocationTables,
+) -> Result<ElfDwarf, ElfError> {
+    let sections = DebugSections::load(content, header, section_header, relocation_tables)?;
+    let dwarf = sections.dwarf();
+
+    let mut inner = Vec::new();
+    let mut next = 0;
+    let mut headers = dwarf.units();
+    while let Some(unit_header) = headers
+        .next()
+        .map_err(sections.error(".debug_info", next))?
+    {
+        let offset = unit_header.offset().0;
+        next = offset.saturating_add(unit_header.length_including_self());
+        let error = sections.error(".debug_info", offset);
+        let unit = dwarf.unit(unit_header).map_err(&error)?;
+        let unit = unit.unit_ref(&dwarf);
+        let line_error = sections.error(".debug_line", line_offset(unit));
+        let files = file_names(unit).map_err(&line_error)?;
+
+        let root = unit.entry(unit.header.root_offset()).map_err(&error)?;
+        let language = match root.attr_value(gimli::DW_AT_language) {
+            Some(AttributeValue::Language(language)) => Some(language.0),
+            _ => None,
+        };
+        inner.push(ElfDwarfUnit {
+            offset,
+            version: unit.header.version(),
+            address_size: unit.header.address_size(),
+            name: unit.name.map(to_string).unwrap_or_default(),
+            comp_dir: unit.comp_dir.map(to_string).unwrap_or_default(),
+            producer: attr_string(unit, &root, gimli::DW_AT_producer)
+                .map_err(&error)?
+                .unwrap_or_default(),
+            language: ElfDwarfLanguage(language),
+            entries: parse_entries(unit, &files).map_err(&error)?,
+            lines: parse_lines(unit, &files).map_err(&line_error)?,
+        });
+    }
+
+    Ok(ElfDwarf { inner })
+}
+
+// Maps an address to its function, file, line and column, with one frame
+// per inlined call. A single frame of unknowns when no unit covers it.
+pub fn parse_addr2line(
+    content: &[u8],
+    header: &ElfHeader,
+    section_header: &ElfSectionHeader,
+    relocation_tables: &ElfRelocationTables,
+    address: u64,
+) -> Result<ElfAddr2Line, ElfError> {
+    let sections = DebugSections::load(content, header, section_header, relocation_tables)?;
+    let dwarf = sections.dwarf();
+
+    let mut next = 0;
+    let mut headers = dwarf.units();
+    while let Some(unit_header) = headers
+        .next()
+        .map_err(sections.error(".debug_info", next))?
+    {
+        let offset = unit_header.offset().0;
+        next = offset.saturating_add(unit_header.length_including_self());
+        let error = sections.error(".debug_info", offset);
+        let unit = dwarf.unit(unit_header).map_err(&error)?;
+        let unit = unit.unit_ref(&dwarf);
+
+        let root = unit.entry(unit.header.root_offset()).map_err(&error)?;
+        if !contains(unit, &root, address).map_err(&error)? {
+            continue;
+        }
+        let files = file_names(unit).map_err(sections.error(".debug_line", line_offset(unit)))?;
+        return Ok(ElfAddr2Line {
+            address,
+            inner: source_frames(unit, &files, address).map_err(&error)?,
+        });
+    }
+
+    Ok(ElfAddr2Line {
+        address,
+        inner: vec![ElfSourceFrame::default()],
+    })
+}
@@ -0,0 +1,546 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfEndianness, ElfError, ElfPlatformType, ElfProgramHeader, ElfReader, ElfSectionHeader,
+    ElfSectionHeaderType, ElfSegmentType, flag_names, parse_string_at, serialize_named, slice_at,
+};
+
+// The dynamic table, an array of d_tag/d_val pairs terminated by DT_NULL
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDynamicTable {
+    // Offset of the table in the file image
+    pub offset: usize,
+    pub inner: Vec<ElfDynamicEntry>,
+}
+
+impl ElfDynamicTable {
+    pub fn inner(self) -> Vec<ElfDynamicEntry> {
+        self.inner
+    }
+
+    pub fn find(&self, tag: u64) -> Option<&ElfDynamicEntry> {
+        self.inner
+            .iter()
+            .find(|entry| entry.dynamic_tag.value() == tag)
+    }
+}
+
+impl std::fmt::Display for ElfDynamicTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfDynamicEntry {
+    pub dynamic_tag: ElfDynamicTag,
+    pub dynamic_value: ElfDynamicValue,
+    // Decoded d_val: the string for string table offsets, the flag names
+    // for DT_FLAGS and DT_FLAGS_1. Empty for addresses and sizes.
+    pub dynamic_meaning: String,
+}
+
+impl std::fmt::Display for ElfDynamicEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = format!(
+            "{}\n{}\n{}",
+            self.dynamic_tag, self.dynamic_value, self.dynamic_meaning
+        );
+        write!(f, "{}", txt)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDynamicValue(pub usize);
+
+impl std::fmt::Display for ElfDynamicValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default)]
+pub enum ElfDynamicTag {
+    #[default]
+    DtNull, // Marks the end of the table
+    DtNeeded,         // Name of a needed library
+    DtPltRelSz,       // Size of the PLT relocations
+    DtPltGot,         // Address of the PLT and/or GOT
+    DtHash,           // Address of the symbol hash table
+    DtStrTab,         // Address of the string table
+    DtSymTab,         // Address of the symbol table
+    DtRela,           // Address of the Rela relocations
+    DtRelaSz,         // Size of the Rela relocations
+    DtRelaEnt,        // Size of one Rela relocation
+    DtStrSz,          // Size of the string table
+    DtSymEnt,         // Size of one symbol table entry
+    DtInit,           // Address of the initialization function
+    DtFini,           // Address of the termination function
+    DtSoName,         // Name of the shared object
+    DtRPath,          // Library search path (deprecated)
+    DtSymbolic,       // Start symbol search within the object
+    DtRel,            // Address of the Rel relocations
+    DtRelSz,          // Size of the Rel relocations
+    DtRelEnt,         // Size of one Rel relocation
+    DtPltRel,         // Type of relocation used by the PLT
+    DtDebug,          // Used for debugging
+    DtTextRel,        // Relocations may modify a non writable segment
+    DtJmpRel,         // Address of the PLT relocations
+    DtBindNow,        // Process all relocations before transferring control
+    DtInitArray,      // Array of initialization functions
+    DtFiniArray,      // Array of termination functions
+    DtInitArraySz,    // Size of DT_INIT_ARRAY
+    DtFiniArraySz,    // Size of DT_FINI_ARRAY
+    DtRunPath,        // Library search path
+    DtFlags,          // Flags for the object
+    DtPreinitArray,   // Array of pre-initialization functions
+    DtPreinitArraySz, // Size of DT_PREINIT_ARRAY
+    DtSymTabShndx,    // Address of the SHT_SYMTAB_SHNDX section
+    DtRelrSz,         // Size of the Relr relocations
+    DtRelr,           // Address of the Relr relocations
+    DtRelrEnt,        // Size of one Relr relocation
+    DtGnuPrelinked,   // Prelinking timestamp
+    DtGnuConflictSz,  // Size of the conflict section
+    DtGnuLiblistSz,   // Size of the library list
+    DtChecksum,       //
+    DtPltPadSz,       //
+    DtMoveEnt,        //
+    DtMoveSz,         //
+    DtFeature1,       // Feature selection
+    DtPosFlag1,       // Flags for the following DT_* entry
+    DtSymInSz,        // Size of the syminfo table
+    DtSymInEnt,       // Size of one syminfo entry
+    DtGnuHash,        // Address of the GNU hash table
+    DtTlsDescPlt,     //
+    DtTlsDescGot,     //
+    DtGnuConflict,    // Address of the conflict section
+    DtGnuLiblist,     // Address of the library list
+    DtConfig,         // Configuration information
+    DtDepAudit,       // Dependency auditing
+    DtAudit,          // Object auditing
+    DtPltPad,         //
+    DtMoveTab,        //
+    DtSymInfo,        // Address of the syminfo table
+    DtVerSym,         // Address of the symbol version table
+    DtRelaCount,      // Number of R_*_RELATIVE Rela relocations
+    DtRelCount,       // Number of R_*_RELATIVE Rel relocations
+    DtFlags1,         // State flags, see DF_1_*
+    DtVerDef,         // Address of the version definitions
+    DtVerDefNum,      // Number of version definitions
+    DtVerNeed,        // Address of the needed versions
+    DtVerNeedNum,     // Number of needed versions
+    DtAuxiliary,      // Shared object to load before self
+    DtFilter,         // Shared object to get values from
+    Other(u64),
+}
+
+impl ElfDynamicTag {
+    pub const DT_NULL: u64 = 0;
+    pub const DT_NEEDED: u64 = 1;
+    pub const DT_STRTAB: u64 = 5;
+    pub const DT_STRSZ: u64 = 10;
+    pub const DT_SONAME: u64 = 14;
+    pub const DT_RPATH: u64 = 15;
+    pub const DT_DEBUG: u64 = 21;
+    pub const DT_BIND_NOW: u64 = 24;
+    pub const DT_RUNPATH: u64 = 29;
+    pub const DT_FLAGS: u64 = 30;
+    pub const DT_FLAGS_1: u64 = 0x6FFFFFFB;
+
+    pub fn value(&self) -> u64 {
+        match self {
+            ElfDynamicTag::DtNull => 0,
+            ElfDynamicTag::DtNeeded => 1,
+            ElfDynamicTag::DtPltRelSz => 2,
+            ElfDynamicTag::DtPltGot => 3,
+            ElfDynamicTag::DtHash => 4,
+            ElfDynamicTag::DtStrTab => 5,
+            ElfDynamicTag::DtSymTab => 6,
+            ElfDynamicTag::DtRela => 7,
+            ElfDynamicTag::DtRelaSz => 8,
+            ElfDynamicTag::DtRelaEnt => 9,
+            ElfDynamicTag::DtStrSz => 10,
+            ElfDynamicTag::DtSymEnt => 11,
+            ElfDynamicTag::DtInit => 12,
+            ElfDynamicTag::DtFini => 13,
+            ElfDynamicTag::DtSoName => 14,
+            ElfDynamicTag::DtRPath => 15,
+            ElfDynamicTag::DtSymbolic => 16,
+            ElfDynamicTag::DtRel => 17,
+            ElfDynamicTag::DtRelSz => 18,
+            ElfDynamicTag::DtRelEnt => 19,
+            ElfDynamicTag::DtPltRel => 20,
+            ElfDynamicTag::DtDebug => 21,
+            ElfDynamicTag::DtTextRel => 22,
+            ElfDynamicTag::DtJmpRel => 23,
+            ElfDynamicTag::DtBindNow => 24,
+            ElfDynamicTag::DtInitArray => 25,
+            ElfDynamicTag::DtFiniArray => 26,
+            ElfDynamicTag::DtInitArraySz => 27,
+            ElfDynamicTag::DtFiniArraySz => 28,
+            ElfDynamicTag::DtRunPath => 29,
+            ElfDynamicTag::DtFlags => 30,
+            ElfDynamicTag::DtPreinitArray => 32,
+            ElfDynamicTag::DtPreinitArraySz => 33,
+            ElfDynamicTag::DtSymTabShndx => 34,
+            ElfDynamicTag::DtRelrSz => 35,
+            ElfDynamicTag::DtRelr => 36,
+            ElfDynamicTag::DtRelrEnt => 37,
+            ElfDynamicTag::DtGnuPrelinked => 0x6FFFFDF5,
+            ElfDynamicTag::DtGnuConflictSz => 0x6FFFFDF6,
+            ElfDynamicTag::DtGnuLiblistSz => 0x6FFFFDF7,
+            ElfDynamicTag::DtChecksum => 0x6FFFFDF8,
+            ElfDynamicTag::DtPltPadSz => 0x6FFFFDF9,
+            ElfDynamicTag::DtMoveEnt => 0x6FFFFDFA,
+            ElfDynamicTag::DtMoveSz => 0x6FFFFDFB,
+            ElfDynamicTag::DtFeature1 => 0x6FFFFDFC,
+            ElfDynamicTag::DtPosFlag1 => 0x6FFFFDFD,
+            ElfDynamicTag::DtSymInSz => 0x6FFFFDFE,
+            ElfDynamicTag::DtSymInEnt => 0x6FFFFDFF,
+            ElfDynamicTag::DtGnuHash => 0x6FFFFEF5,
+            ElfDynamicTag::DtTlsDescPlt => 0x6FFFFEF6,
+            ElfDynamicTag::DtTlsDescGot => 0x6FFFFEF7,
+            ElfDynamicTag::DtGnuConflict => 0x6FFFFEF8,
+            ElfDynamicTag::DtGnuLiblist => 0x6FFFFEF9,
+            ElfDynamicTag::DtConfig => 0x6FFFFEFA,
+            ElfDynamicTag::DtDepAudit => 0x6FFFFEFB,
+            ElfDynamicTag::DtAudit => 0x6FFFFEFC,
+            ElfDynamicTag::DtPltPad => 0x6FFFFEFD,
+            ElfDynamicTag::DtMoveTab => 0x6FFFFEFE,
+            ElfDynamicTag::DtSymInfo => 0x6FFFFEFF,
+            ElfDynamicTag::DtVerSym => 0x6FFFFFF0,
+            ElfDynamicTag::DtRelaCount => 0x6FFFFFF9,
+            ElfDynamicTag::DtRelCount => 0x6FFFFFFA,
+            ElfDynamicTag::DtFlags1 => 0x6FFFFFFB,
+            ElfDynamicTag::DtVerDef => 0x6FFFFFFC,
+            ElfDynamicTag::DtVerDefNum => 0x6FFFFFFD,
+            ElfDynamicTag::DtVerNeed => 0x6FFFFFFE,
+            ElfDynamicTag::DtVerNeedNum => 0x6FFFFFFF,
+            ElfDynamicTag::DtAuxiliary => 0x7FFFFFFD,
+            ElfDynamicTag::DtFilter => 0x7FFFFFFF,
+            ElfDynamicTag::Other(value) => *value,
+        }
+    }
+
+    // Tags whose d_val is an offset into the DT_STRTAB string table
+    pub fn is_string(&self) -> bool {
+        matches!(
+            self,
+            ElfDynamicTag::DtNeeded
+                | ElfDynamicTag::DtSoName
+                | ElfDynamicTag::DtRPath
+                | ElfDynamicTag::DtRunPath
+                | ElfDynamicTag::DtConfig
+                | ElfDynamicTag::DtDepAudit
+                | ElfDynamicTag::DtAudit
+                | ElfDynamicTag::DtAuxiliary
+                | ElfDynamicTag::DtFilter
+        )
+    }
+}
+
+impl std::fmt::Display for ElfDynamicTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfDynamicTag::DtNull => "DT_NULL",
+            ElfDynamicTag::DtNeeded => "DT_NEEDED",
+            ElfDynamicTag::DtPltRelSz => "DT_PLTRELSZ",
+            ElfDynamicTag::DtPltGot => "DT_PLTGOT",
+            ElfDynamicTag::DtHash => "DT_HASH",
+            ElfDynamicTag::DtStrTab => "DT_STRTAB",
+            ElfDynamicTag::DtSymTab => "DT_SYMTAB",
+            ElfDynamicTag::DtRela => "DT_RELA",
+            ElfDynamicTag::DtRelaSz => "DT_RELASZ",
+            ElfDynamicTag::DtRelaEnt => "DT_RELAENT",
+            ElfDynamicTag::DtStrSz => "DT_STRSZ",
+            ElfDynamicTag::DtSymEnt => "DT_SYMENT",
+            ElfDynamicTag::DtInit => "DT_INIT",
+            ElfDynamicTag::DtFini => "DT_FINI",
+            ElfDynamicTag::DtSoName => "DT_SONAME",
+            ElfDynamicTag::DtRPath => "DT_RPATH",
+            ElfDynamicTag::DtSymbolic => "DT_SYMBOLIC",
+            ElfDynamicTag::DtRel => "DT_REL",
+            ElfDynamicTag::DtRelSz => "DT_RELSZ",
+            ElfDynamicTag::DtRelEnt => "DT_RELENT",
+            ElfDynamicTag::DtPltRel => "DT_PLTREL",
+            ElfDynamicTag::DtDebug => "DT_DEBUG",
+            ElfDynamicTag::DtTextRel => "DT_TEXTREL",
+            ElfDynamicTag::DtJmpRel => "DT_JMPREL",
+            ElfDynamicTag::DtBindNow => "DT_BIND_NOW",
+            ElfDynamicTag::DtInitArray => "DT_INIT_ARRAY",
+            ElfDynamicTag::DtFiniArray => "DT_FINI_ARRAY",
+            ElfDynamicTag::DtInitArraySz => "DT_INIT_ARRAYSZ",
+            ElfDynamicTag::DtFiniArraySz => "DT_FINI_ARRAYSZ",
+            ElfDynamicTag::DtRunPath => "DT_RUNPATH",
+            ElfDynamicTag::DtFlags => "DT_FLAGS",
+            ElfDynamicTag::DtPreinitArray => "DT_PREINIT_ARRAY",
+            ElfDynamicTag::DtPreinitArraySz => "DT_PREINIT_ARRAYSZ",
+            ElfDynamicTag::DtSymTabShndx => "DT_SYMTAB_SHNDX",
+            ElfDynamicTag::DtRelrSz => "DT_RELRSZ",
+            ElfDynamicTag::DtRelr => "DT_RELR",
+            ElfDynamicTag::DtRelrEnt => "DT_RELRENT",
+            ElfDynamicTag::DtGnuPrelinked => "DT_GNU_PRELINKED",
+            ElfDynamicTag::DtGnuConflictSz => "DT_GNU_CONFLICTSZ",
+            ElfDynamicTag::DtGnuLiblistSz => "DT_GNU_LIBLISTSZ",
+            ElfDynamicTag::DtChecksum => "DT_CHECKSUM",
+            ElfDynamicTag::DtPltPadSz => "DT_PLTPADSZ",
+            ElfDynamicTag::DtMoveEnt => "DT_MOVEENT",
+            ElfDynamicTag::DtMoveSz => "DT_MOVESZ",
+            ElfDynamicTag::DtFeature1 => "DT_FEATURE_1",
+            ElfDynamicTag::DtPosFlag1 => "DT_POSFLAG_1",
+            ElfDynamicTag::DtSymInSz => "DT_SYMINSZ",
+            ElfDynamicTag::DtSymInEnt => "DT_SYMINENT",
+            ElfDynamicTag::DtGnuHash => "DT_GNU_HASH",
+            ElfDynamicTag::DtTlsDescPlt => "DT_TLSDESC_PLT",
+            ElfDynamicTag::DtTlsDescGot => "DT_TLSDESC_GOT",
+            ElfDynamicTag::DtGnuConflict => "DT_GNU_CONFLICT",
+            ElfDynamicTag::DtGnuLiblist => "DT_GNU_LIBLIST",
+            ElfDynamicTag::DtConfig => "DT_CONFIG",
+            ElfDynamicTag::DtDepAudit => "DT_DEPAUDIT",
+            ElfDynamicTag::DtAudit => "DT_AUDIT",
+            ElfDynamicTag::DtPltPad => "DT_PLTPAD",
+            ElfDynamicTag::DtMoveTab => "DT_MOVETAB",
+            ElfDynamicTag::DtSymInfo => "DT_SYMINFO",
+            ElfDynamicTag::DtVerSym => "DT_VERSYM",
+            ElfDynamicTag::DtRelaCount => "DT_RELACOUNT",
+            ElfDynamicTag::DtRelCount => "DT_RELCOUNT",
+            ElfDynamicTag::DtFlags1 => "DT_FLAGS_1",
+            ElfDynamicTag::DtVerDef => "DT_VERDEF",
+            ElfDynamicTag::DtVerDefNum => "DT_VERDEFNUM",
+            ElfDynamicTag::DtVerNeed => "DT_VERNEED",
+            ElfDynamicTag::DtVerNeedNum => "DT_VERNEEDNUM",
+            ElfDynamicTag::DtAuxiliary => "DT_AUXILIARY",
+            ElfDynamicTag::DtFilter => "DT_FILTER",
+            ElfDynamicTag::Other(value) => return write!(f, "DT_<{:#x}>", value),
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl Serialize for ElfDynamicTag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, self.value(), self)
+    }
+}
+
+pub fn parse_dynamic_tag(d_tag: u64) -> ElfDynamicTag {
+    match d_tag {
+        0 => ElfDynamicTag::DtNull,
+        1 => ElfDynamicTag::DtNeeded,
+        2 => ElfDynamicTag::DtPltRelSz,
+        3 => ElfDynamicTag::DtPltGot,
+        4 => ElfDynamicTag::DtHash,
+        5 => ElfDynamicTag::DtStrTab,
+        6 => ElfDynamicTag::DtSymTab,
+        7 => ElfDynamicTag::DtRela,
+        8 => ElfDynamicTag::DtRelaSz,
+        9 => ElfDynamicTag::DtRelaEnt,
+        10 => ElfDynamicTag::DtStrSz,
+        11 => ElfDynamicTag::DtSymEnt,
+        12 => ElfDynamicTag::DtInit,
+        13 => ElfDynamicTag::DtFini,
+        14 => ElfDynamicTag::DtSoName,
+        15 => ElfDynamicTag::DtRPath,
+        16 => ElfDynamicTag::DtSymbolic,
+        17 => ElfDynamicTag::DtRel,
+        18 => ElfDynamicTag::DtRelSz,
+        19 => ElfDynamicTag::DtRelEnt,
+        20 => ElfDynamicTag::DtPltRel,
+        21 => ElfDynamicTag::DtDebug,
+        22 => ElfDynamicTag::DtTextRel,
+        23 => ElfDynamicTag::DtJmpRel,
+        24 => ElfDynamicTag::DtBindNow,
+        25 => ElfDynamicTag::DtInitArray,
+        26 => ElfDynamicTag::DtFiniArray,
+        27 => ElfDynamicTag::DtInitArraySz,
+        28 => ElfDynamicTag::DtFiniArraySz,
+        29 => ElfDynamicTag::DtRunPath,
+        30 => ElfDynamicTag::DtFlags,
+        32 => ElfDynamicTag::DtPreinitArray,
+        33 => ElfDynamicTag::DtPreinitArraySz,
+        34 => ElfDynamicTag::DtSymTabShndx,
+        35 => ElfDynamicTag::DtRelrSz,
+        36 => ElfDynamicTag::DtRelr,
+        37 => ElfDynamicTag::DtRelrEnt,
+        0x6FFFFDF5 => ElfDynamicTag::DtGnuPrelinked,
+        0x6FFFFDF6 => ElfDynamicTag::DtGnuConflictSz,
+        0x6FFFFDF7 => ElfDynamicTag::DtGnuLiblistSz,
+        0x6FFFFDF8 => ElfDynamicTag::DtChecksum,
+        0x6FFFFDF9 => ElfDynamicTag::DtPltPadSz,
+        0x6FFFFDFA => ElfDynamicTag::DtMoveEnt,
+        0x6FFFFDFB => ElfDynamicTag::DtMoveSz,
+        0x6FFFFDFC => ElfDynamicTag::DtFeature1,
+        0x6FFFFDFD => ElfDynamicTag::DtPosFlag1,
+        0x6FFFFDFE => ElfDynamicTag::DtSymInSz,
+        0x6FFFFDFF => ElfDynamicTag::DtSymInEnt,
+        0x6FFFFEF5 => ElfDynamicTag::DtGnuHash,
+        0x6FFFFEF6 => ElfDynamicTag::DtTlsDescPlt,
+        0x6FFFFEF7 => ElfDynamicTag::DtTlsDescGot,
+        0x6FFFFEF8 => ElfDynamicTag::DtGnuConflict,
+        0x6FFFFEF9 => ElfDynamicTag::DtGnuLiblist,
+        0x6FFFFEFA => ElfDynamicTag::DtConfig,
+        0x6FFFFEFB => ElfDynamicTag::DtDepAudit,
+        0x6FFFFEFC => ElfDynamicTag::DtAudit,
+        0x6FFFFEFD => ElfDynamicTag::DtPltPad,
+        0x6FFFFEFE => ElfDynamicTag::DtMoveTab,
+        0x6FFFFEFF => ElfDynamicTag::DtSymInfo,
+        0x6FFFFFF0 => ElfDynamicTag::DtVerSym,
+        0x6FFFFFF9 => ElfDynamicTag::DtRelaCount,
+        0x6FFFFFFA => ElfDynamicTag::DtRelCount,
+        0x6FFFFFFB => ElfDynamicTag::DtFlags1,
+        0x6FFFFFFC => ElfDynamicTag::DtVerDef,
+        0x6FFFFFFD => ElfDynamicTag::DtVerDefNum,
+        0x6FFFFFFE => ElfDynamicTag::DtVerNeed,
+        0x6FFFFFFF => ElfDynamicTag::DtVerNeedNum,
+        0x7FFFFFFD => ElfDynamicTag::DtAuxiliary,
+        0x7FFFFFFF => ElfDynamicTag::DtFilter,
+        other => ElfDynamicTag::Other(other),
+    }
+}
+
+const DYNAMIC_FLAGS: [(u64, &str); 5] = [
+    (0x1, "ORIGIN"),
+    (0x2, "SYMBOLIC"),
+    (0x4, "TEXTREL"),
+    (0x8, "BIND_NOW"),
+    (0x10, "STATIC_TLS"),
+];
+
+const DYNAMIC_FLAGS_1: [(u64, &str); 28] = [
+    (0x1, "NOW"),
+    (0x2, "GLOBAL"),
+    (0x4, "GROUP"),
+    (0x8, "NODELETE"),
+    (0x10, "LOADFLTR"),
+    (0x20, "INITFIRST"),
+    (0x40, "NOOPEN"),
+    (0x80, "ORIGIN"),
+    (0x100, "DIRECT"),
+    (0x200, "TRANS"),
+    (0x400, "INTERPOSE"),
+    (0x800, "NODEFLIB"),
+    (0x1000, "NODUMP"),
+    (0x2000, "CONFALT"),
+    (0x4000, "ENDFILTEE"),
+    (0x8000, "DISPRELDNE"),
+    (0x10000, "DISPRELPND"),
+    (0x20000, "NODIRECT"),
+    (0x40000, "IGNMULDEF"),
+    (0x80000, "NOKSYMS"),
+    (0x100000, "NOHDR"),
+    (0x200000, "EDITED"),
+    (0x400000, "NORELOC"),
+    (0x800000, "SYMINTPOSE"),
+    (0x1000000, "GLOBAUDIT"),
+    (0x2000000, "SINGLETON"),
+    (0x4000000, "STUB"),
+    (0x8000000, "PIE"),
+];
+
+// Prefers PT_DYNAMIC, which is what the dynamic loader uses, and falls back to
+// the SHT_DYNAMIC section for files without program headers.
+pub fn find_dynamic_table(
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+) -> Option<(usize, usize)> {
+    let segment = program_header
+        .inner
+        .iter()
+        .find(|entry| matches!(entry.segment_type, ElfSegmentType::PtDynamic))
+        .map(|entry| (entry.segment_offset.0, entry.segment_file_size.0));
+
+    segment.or_else(|| {
+        section_header
+            .inner
+            .iter()
+            .find(|entry| matches!(entry.section_header_type, ElfSectionHeaderType::ShtDynamic))
+            .map(|entry| (entry.section_offset.0, entry.section_size.0))
+    })
+}
+
+pub fn parse_dynamic_entry(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfDynamicEntry, ElfError> {
+    let d_tag = match platform {
+        ElfPlatformType::Bit32 => reader.read_u32("d_tag", endian)? as u64,
+        ElfPlatformType::Bit64 => reader.read_u64("d_tag", endian)?,
+    };
+    let d_val = reader.read_word("d_val", endian, platform)?;
+
+    Ok(ElfDynamicEntry {
+        dynamic_tag: parse_dynamic_tag(d_tag),
+        dynamic_value: ElfDynamicValue(d_val),
+        dynamic_meaning: String::new(),
+    })
+}
+
+pub fn parse_dynamic_table(
+    content: &[u8],
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfDynamicTable, ElfError> {
+    let Some((offset, size)) = find_dynamic_table(program_header, section_header) else {
+        return Ok(ElfDynamicTable::default());
+    };
+    let entry_size = match platform {
+        ElfPlatformType::Bit32 => 8,
+        ElfPlatformType::Bit64 => 16,
+    };
+    let table = slice_at(content, "dynamic table", offset, size)?;
+
+    let mut inner = Vec::new();
+    let mut reader = ElfReader::at(content, offset);
+    for _ in 0..table.len() / entry_size {
+        let entry = parse_dynamic_entry(&mut reader, endian, platform)?;
+        let is_null = matches!(entry.dynamic_tag, ElfDynamicTag::DtNull);
+        inner.push(entry);
+        if is_null {
+            break;
+        }
+    }
+    let mut dynamic = ElfDynamicTable { offset, inner };
+
+    // DT_STRTAB holds a virtual address, translate it through the PT_LOAD segments.
+    // Without program headers use the string table linked from SHT_DYNAMIC instead.
+    let string_table = dynamic
+        .find(ElfDynamicTag::DT_STRTAB)
+        .and_then(|entry| program_header.vaddr_to_offset(entry.dynamic_value.0))
+        .zip(
+            dynamic
+                .find(ElfDynamicTag::DT_STRSZ)
+                .map(|entry| entry.dynamic_value.0),
+        )
+        .or_else(|| {
+            section_header
+                .inner
+                .iter()
+                .find(|entry| matches!(entry.section_header_type, ElfSectionHeaderType::ShtDynamic))
+                .and_then(|entry| section_header.inner.get(entry.section_link.0 as usize))
+                .map(|entry| (entry.section_offset.0, entry.section_size.0))
+        });
+
+    for entry in dynamic.inner.iter_mut() {
+        let value = entry.dynamic_value.0;
+        entry.dynamic_meaning = match &entry.dynamic_tag {
+            tag if tag.is_string() => match string_table {
+                Some((table_offset, table_size)) => {
+                    parse_string_at(content, table_offset, table_size, value)
+                        .unwrap_or_else(|_| format!("<corrupt: {:#x}>", value))
+                }
+                None => String::new(),
+            },
+            ElfDynamicTag::DtFlags => flag_names(value as u64, &DYNAMIC_FLAGS, &[]).join(" "),
+            ElfDynamicTag::DtFlags1 => flag_names(value as u64, &DYNAMIC_FLAGS_1, &[]).join(" "),
+            ElfDynamicTag::DtPltRel => parse_dynamic_tag(value as u64).to_string(),
+            _ => String::new(),
+        };
+    }
+
+    Ok(dynamic)
+}
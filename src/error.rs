@@ -0,0 +1,119 @@
+#[derive(Debug)]
+pub enum ElfError {
+    // Reading the file from disk failed.
+    Io(std::io::Error),
+    // The file exists but has no content.
+    EmptyFile,
+    // The first four bytes are not 0x7F followed by ELF.
+    NotElf {
+        offset: usize,
+        actual: [u8; 4],
+    },
+    // EI_CLASS is neither 32- nor 64-bit.
+    UnsupportedClass {
+        offset: usize,
+        actual: u8,
+    },
+    // Reading a field would run past the end of the file.
+    Truncated {
+        field: &'static str,
+        offset: usize,
+        expected: usize,
+        actual: usize,
+    },
+    // An offset/size pair does not fit in the address space.
+    Overflow {
+        field: &'static str,
+        offset: usize,
+    },
+    // A field holds a value this parser does not know how to interpret.
+    InvalidValue {
+        field: &'static str,
+        offset: usize,
+        expected: &'static str,
+        actual: u64,
+    },
+    // No section has the requested name or index.
+    SectionNotFound(String),
+    // No symbol has the requested name.
+    SymbolNotFound(String),
+    // A .debug_* section does not parse as DWARF. `offset` is the file
+    // offset of the unit or line program holding the problem.
+    Dwarf {
+        section: &'static str,
+        offset: usize,
+        message: String,
+    },
+    // The compressed content of a section does not decompress. `offset` is
+    // the file offset of the compressed stream.
+    Decompression {
+        offset: usize,
+        message: String,
+    },
+}
+
+impl std::fmt::Display for ElfError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElfError::Io(err) => write!(f, "{err}"),
+            ElfError::EmptyFile => write!(f, "File is empty!"),
+            ElfError::NotElf { offset, actual } => write!(
+                f,
+                "Unsupported file type: expected magic 7F 45 4C 46 at offset {offset:#x}, found {actual:02X?}"
+            ),
+            ElfError::UnsupportedClass { offset, actual } => write!(
+                f,
+                "Unsupported platform type at offset {offset:#x}: expected 1 (32-bit) or 2 (64-bit), found {actual}"
+            ),
+            ElfError::Truncated {
+                field,
+                offset,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Truncated {field} at offset {offset:#x}: expected {expected} bytes, found {actual}"
+            ),
+            ElfError::Overflow { field, offset } => {
+                write!(f, "Overflow reading {field} at offset {offset:#x}")
+            }
+            ElfError::InvalidValue {
+                field,
+                offset,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Invalid {field} at offset {offset:#x}: expected {expected}, found {actual:#x}"
+            ),
+            ElfError::SectionNotFound(section) => {
+                write!(f, "No section named or numbered {section}")
+            }
+            ElfError::SymbolNotFound(symbol) => write!(f, "No symbol named {symbol}"),
+            ElfError::Dwarf {
+                section,
+                offset,
+                message,
+            } => write!(f, "Malformed {section} at offset {offset:#x}: {message}"),
+            ElfError::Decompression { offset, message } => write!(
+                f,
+                "Corrupt compressed data at offset {offset:#x}: {message}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ElfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ElfError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ElfError {
+    fn from(err: std::io::Error) -> Self {
+        ElfError::Io(err)
+    }
+}
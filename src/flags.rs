@@ -0,0 +1,23 @@
+// Names every set bit of a flags value found in `known`. Bits left over are
+// grouped by the first of `ranges` they fall in, as `<range>(0x..)`, so that
+// OS and processor specific bits survive decoding. Anything outside every
+// range follows as plain hex.
+pub fn flag_names(value: u64, known: &[(u64, &str)], ranges: &[(u64, &str)]) -> Vec<String> {
+    let mut names = known
+        .iter()
+        .filter(|(bit, _)| value & bit == *bit)
+        .map(|(_, name)| name.to_string())
+        .collect::<Vec<String>>();
+    let mut rest = known.iter().fold(value, |rest, (bit, _)| rest & !bit);
+    for (mask, range) in ranges {
+        if rest & mask != 0 {
+            names.push(format!("{range}({:#x})", rest & mask));
+            rest &= !mask;
+        }
+    }
+    if rest != 0 {
+        names.push(format!("{rest:#x}"));
+    }
+
+    names
+}
@@ -0,0 +1,1090 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{ElfError, ElfReader, serialize_named};
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfHeader {
+    // 0x7F followed by ELF(45 4c 46) in ASCII;
+    pub magic_number: ElfMagicNumber,
+    // This byte is set to either 1 or 2 to signify 32- or 64-bit format, respectively.
+    pub platform_type: ElfPlatformType,
+    // This byte is set to either 1 or 2 to signify little or big endianness, respectively.
+    // This affects interpretation of multi-byte fields starting with offset 0x10.
+    pub endianness: ElfEndianness,
+    // Set to 1 for the original and current version of ELF.
+    pub elf_header_version: ElfHeaderVersion,
+    // Identifies the target operating system ABI.
+    pub target_system_abi: ElfTargetSystemAbi,
+    // Further specifies the ABI version. Its interpretation depends on the target ABI.
+    // Linux kernel (after at least 2.6) has no definition of it,[6] so it is ignored for
+    // statically linked executables. In that case, offset and size of EI_PAD are 8.
+    pub target_abi_version: ElfTargetAbiVersion,
+    pub object_file_type: ElfObjectFileType,
+    // Specifies target instruction set architecture.
+    pub instruction_set: ElfInstructionSet,
+    // Set to 1 for the original version of ELF.
+    pub elf_version: ElfVersion,
+    // This is the memory address of the entry point from where the process starts executing.
+    pub entry_point: ElfEntryPoint,
+    // Points to the start of the program header table.
+    pub program_header_offset: ElfProgramHeaderOffset,
+    // Points to the start of the section header table
+    pub section_header_offset: ElfSectionHeaderOffset,
+    pub flags: ElfFlags,
+    // Contains the size of this header, normally 64 Bytes for 64-bit
+    // and 52 Bytes for 32-bit format.
+    pub header_size: ElfHeaderSize,
+    // Contains the size of a program header table entry.
+    pub program_header_entry_size: ElfProgramHeaderEntrySize,
+    // Contains the number of entries in the program header table.
+    pub program_header_entry_count: ElfProgramHeaderEntryCount,
+    // Contains the size of a section header table entry
+    pub section_header_entry_size: ElfSectionHeaderEntrySize,
+    // Contains the number of entries in the section header table.
+    pub section_header_entry_count: ElfSectionHeaderEntryCount,
+    // Contains index of the section header table entry that contains the section names.
+    pub section_header_sections_table_index: ElfSectionHeaderSectionsTableIndex,
+}
+
+impl std::fmt::Display for ElfHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.magic_number,
+            self.platform_type,
+            self.endianness,
+            self.elf_header_version,
+            self.target_system_abi,
+            self.target_abi_version,
+            self.object_file_type,
+            self.instruction_set,
+            self.elf_version,
+            self.entry_point,
+            self.program_header_offset,
+            self.section_header_offset,
+            self.flags,
+            self.header_size,
+            self.program_header_entry_size,
+            self.program_header_entry_count,
+            self.section_header_entry_size,
+            self.section_header_entry_count,
+            self.section_header_sections_table_index
+        );
+        write!(f, "{}", txt)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionHeaderSectionsTableIndex(pub u16);
+
+impl ElfSectionHeaderSectionsTableIndex {
+    pub const SHN_XINDEX: u16 = 0xFFFF;
+}
+
+impl std::fmt::Display for ElfSectionHeaderSectionsTableIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x?}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionHeaderEntrySize(pub u16);
+
+impl std::fmt::Display for ElfSectionHeaderEntrySize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x?}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionHeaderEntryCount(pub u16);
+
+impl std::fmt::Display for ElfSectionHeaderEntryCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x?}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfProgramHeaderEntryCount(pub u16);
+
+impl ElfProgramHeaderEntryCount {
+    // e_phnum does not hold the count, section header 0 does in sh_info
+    pub const PN_XNUM: u16 = 0xFFFF;
+
+    pub fn inner(&self) -> u16 {
+        self.0
+    }
+
+    pub fn is_extended(&self) -> bool {
+        self.0 == Self::PN_XNUM
+    }
+}
+
+impl std::fmt::Display for ElfProgramHeaderEntryCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_extended() {
+            true => write!(f, "{:x?} (PN_XNUM)", self.0),
+            false => write!(f, "{:x?}", self.0),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfProgramHeaderEntrySize(pub u16);
+
+impl std::fmt::Display for ElfProgramHeaderEntrySize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x?}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfHeaderSize(pub u16);
+
+impl std::fmt::Display for ElfHeaderSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x?}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfFlags(pub u32);
+
+impl std::fmt::Display for ElfFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x?}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionHeaderOffset(pub usize);
+
+impl std::fmt::Display for ElfSectionHeaderOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfProgramHeaderOffset(pub usize);
+
+impl std::fmt::Display for ElfProgramHeaderOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ElfEntryPoint(pub usize);
+
+impl std::fmt::Display for ElfEntryPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct ElfVersion(pub u32);
+
+impl std::fmt::Display for ElfVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub enum ElfInstructionSet {
+    AdvancedLogicCorpTinyJ,
+    AmdX86_64,
+    ArgonautRiscCore,
+    Arm,
+    Arm64bit,
+    AtTwe32100,
+    AxisCommunications32bit,
+    BerkeleyPacketFilter,
+    DensoNdr1,
+    DigitalAlpha,
+    DigitalEquipmentCorpPdp10,
+    DigitalEquipmentCorpPdp11,
+    DigitalVax,
+    Element14_64bitDSP,
+    FujitsuFr20,
+    FujitsuMma,
+    HewlettPackardPaRisc,
+    HitachiH8500,
+    HitachiH8S,
+    HitachiH8_300,
+    HitachiH8_300H,
+    Ia64,
+    IbmSpuSpc,
+    Ibmsystem370,
+    InfineonTechnologies32bit,
+    Intel80860,
+    Intel80960,
+    IntelMcu,
+    LoongArch,
+    LsiLogic16bitDsp,
+    Mips,
+    Mipsrs3000LittleEndian,
+    McstElbrusE2k,
+    Motorola68000M68k,
+    Motorola88000M88k,
+    MotorolaColdFire,
+    MotorolaM68hc12,
+    MotorolaMc68hc05,
+    MotorolaMc68hc08,
+    MotorolaMc68hc11,
+    MotorolaMc68hc16,
+    MotorolaRce,
+    MotorolaStarCore,
+    NecV800,
+    PowerPc,
+    PowerPc64bit,
+    Reserved(u16), // Reserved for future use
+    RiscV,
+    S390,
+    Sparc,
+    SiemensFx66,
+    SiemensPcp,
+    SiemensTriCore,
+    SiliconGraphicsSvx,
+    SonyDsp,
+    SonyNCpu,
+    SparcV9,
+    StanfordMipsX,
+    StmicroElectronicsSt100,
+    StmicroElectronicsSt19,
+    StmicroElectronicsSt7,
+    StmicroElectronicsSt9,
+    SuperH,
+    Tms320c6000Family,
+    ToyotaMe16,
+    TrwRh32,
+    #[default]
+    UnSpecified,
+    Wdc65c816,
+    X86,
+    ZilogZ80,
+    Other(u16), // Not known to this parser
+}
+
+impl std::fmt::Display for ElfInstructionSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfInstructionSet::UnSpecified => "No specific instruction set",
+            ElfInstructionSet::AtTwe32100 => "AT&T WE 32100",
+            ElfInstructionSet::Sparc => "SPARC",
+            ElfInstructionSet::X86 => "x86",
+            ElfInstructionSet::Motorola68000M68k => "Motorola 68000 (M68k)",
+            ElfInstructionSet::Motorola88000M88k => "Motorola 88000 (M88k)",
+            ElfInstructionSet::IntelMcu => "Intel MCU",
+            ElfInstructionSet::Intel80860 => "Intel 80860",
+            ElfInstructionSet::Mips => "MIPS",
+            ElfInstructionSet::Ibmsystem370 => "IBM System/370",
+            ElfInstructionSet::Mipsrs3000LittleEndian => "MIPS RS3000 Little-endian",
+            ElfInstructionSet::Reserved(value) => {
+                return write!(f, "Reserved for future use ({:#x})", value);
+            }
+            ElfInstructionSet::HewlettPackardPaRisc => "Hewlett-Packard PA-RISC",
+            ElfInstructionSet::Intel80960 => "Intel 80960",
+            ElfInstructionSet::PowerPc => "PowerPC",
+            ElfInstructionSet::PowerPc64bit => "PowerPC (64-bit)",
+            ElfInstructionSet::S390 => "S390, including S390x",
+            ElfInstructionSet::IbmSpuSpc => "IBM SPU/SPC",
+            ElfInstructionSet::NecV800 => "NEC V800",
+            ElfInstructionSet::FujitsuFr20 => "Fujitsu FR20",
+            ElfInstructionSet::TrwRh32 => "TRW RH-32",
+            ElfInstructionSet::MotorolaRce => "Motorola RCE",
+            ElfInstructionSet::Arm => "Arm (up to Armv7/AArch32)",
+            ElfInstructionSet::DigitalAlpha => "Digital Alpha",
+            ElfInstructionSet::SuperH => "SuperH",
+            ElfInstructionSet::SparcV9 => "SPARC Version 9",
+            ElfInstructionSet::SiemensTriCore => "Siemens TriCore embedded processor",
+            ElfInstructionSet::ArgonautRiscCore => "Argonaut RISC Core",
+            ElfInstructionSet::HitachiH8_300 => "Hitachi H8/300",
+            ElfInstructionSet::HitachiH8_300H => "Hitachi H8/300H",
+            ElfInstructionSet::HitachiH8S => "Hitachi H8S",
+            ElfInstructionSet::HitachiH8500 => "Hitachi H8/500",
+            ElfInstructionSet::Ia64 => "IA-64",
+            ElfInstructionSet::StanfordMipsX => "Stanford MIPS-X",
+            ElfInstructionSet::MotorolaColdFire => "Motorola ColdFire",
+            ElfInstructionSet::MotorolaM68hc12 => "Motorola M68HC12",
+            ElfInstructionSet::FujitsuMma => "Fujitsu MMA Multimedia Accelerator",
+            ElfInstructionSet::SiemensPcp => "Siemens PCP",
+            ElfInstructionSet::SonyNCpu => "Sony nCPU embedded RISC processor",
+            ElfInstructionSet::DensoNdr1 => "Denso NDR1 microprocessor",
+            ElfInstructionSet::MotorolaStarCore => "Motorola Star*Core processor",
+            ElfInstructionSet::ToyotaMe16 => "Toyota ME16 processor",
+            ElfInstructionSet::StmicroElectronicsSt100 => "STMicroelectronics ST100 processor",
+            ElfInstructionSet::AdvancedLogicCorpTinyJ => {
+                "Advanced Logic Corp. TinyJ embedded processor family"
+            }
+            ElfInstructionSet::AmdX86_64 => "AMD x86-64",
+            ElfInstructionSet::SonyDsp => "Sony DSP Processor",
+            ElfInstructionSet::DigitalEquipmentCorpPdp10 => "Digital Equipment Corp. PDP-10",
+            ElfInstructionSet::DigitalEquipmentCorpPdp11 => "Digital Equipment Corp. PDP-11",
+            ElfInstructionSet::SiemensFx66 => "Siemens FX66 microcontroller",
+            ElfInstructionSet::StmicroElectronicsSt9 => {
+                "STMicroelectronics ST9+ 8/16-bit microcontroller"
+            }
+            ElfInstructionSet::StmicroElectronicsSt7 => {
+                "STMicroelectronics ST7 8-bit microcontroller"
+            }
+            ElfInstructionSet::MotorolaMc68hc16 => "Motorola MC68HC16 Microcontroller",
+            ElfInstructionSet::MotorolaMc68hc11 => "Motorola MC68HC11 Microcontroller",
+            ElfInstructionSet::MotorolaMc68hc08 => "Motorola MC68HC08 Microcontroller",
+            ElfInstructionSet::MotorolaMc68hc05 => "Motorola MC68HC05 Microcontroller",
+            ElfInstructionSet::SiliconGraphicsSvx => "Silicon Graphics SVx",
+            ElfInstructionSet::StmicroElectronicsSt19 => {
+                "STMicroelectronics ST19 8-bit microcontroller"
+            }
+            ElfInstructionSet::DigitalVax => "Digital VAX",
+            ElfInstructionSet::AxisCommunications32bit => {
+                "Axis Communications 32-bit embedded processor"
+            }
+            ElfInstructionSet::InfineonTechnologies32bit => {
+                "Infineon Technologies 32-bit embedded processor"
+            }
+            ElfInstructionSet::Element14_64bitDSP => "Element 14 64-bit DSP Processor",
+            ElfInstructionSet::LsiLogic16bitDsp => "LSI Logic 16-bit DSP Processor",
+            ElfInstructionSet::Tms320c6000Family => "TMS320C6000 Family",
+            ElfInstructionSet::McstElbrusE2k => "MCST Elbrus e2k",
+            ElfInstructionSet::Arm64bit => "Arm 64-bits (Armv8/AArch64)",
+            ElfInstructionSet::ZilogZ80 => "Zilog Z80",
+            ElfInstructionSet::RiscV => "RISC-V",
+            ElfInstructionSet::BerkeleyPacketFilter => "Berkeley Packet Filter",
+            ElfInstructionSet::Wdc65c816 => "WDC 65C816",
+            ElfInstructionSet::LoongArch => "LoongArch",
+            ElfInstructionSet::Other(value) => return write!(f, "EM_<{:#x}>", value),
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfInstructionSet {
+    // Raw e_machine value
+    pub fn value(&self) -> u16 {
+        match self {
+            ElfInstructionSet::UnSpecified => 0x00,
+            ElfInstructionSet::AtTwe32100 => 0x01,
+            ElfInstructionSet::Sparc => 0x02,
+            ElfInstructionSet::X86 => 0x03,
+            ElfInstructionSet::Motorola68000M68k => 0x04,
+            ElfInstructionSet::Motorola88000M88k => 0x05,
+            ElfInstructionSet::IntelMcu => 0x06,
+            ElfInstructionSet::Intel80860 => 0x07,
+            ElfInstructionSet::Mips => 0x08,
+            ElfInstructionSet::Ibmsystem370 => 0x09,
+            ElfInstructionSet::Mipsrs3000LittleEndian => 0x0A,
+            ElfInstructionSet::HewlettPackardPaRisc => 0x0F,
+            ElfInstructionSet::Intel80960 => 0x13,
+            ElfInstructionSet::PowerPc => 0x14,
+            ElfInstructionSet::PowerPc64bit => 0x15,
+            ElfInstructionSet::S390 => 0x16,
+            ElfInstructionSet::IbmSpuSpc => 0x17,
+            ElfInstructionSet::NecV800 => 0x24,
+            ElfInstructionSet::FujitsuFr20 => 0x25,
+            ElfInstructionSet::TrwRh32 => 0x26,
+            ElfInstructionSet::MotorolaRce => 0x27,
+            ElfInstructionSet::Arm => 0x28,
+            ElfInstructionSet::DigitalAlpha => 0x29,
+            ElfInstructionSet::SuperH => 0x2A,
+            ElfInstructionSet::SparcV9 => 0x2B,
+            ElfInstructionSet::SiemensTriCore => 0x2C,
+            ElfInstructionSet::ArgonautRiscCore => 0x2D,
+            ElfInstructionSet::HitachiH8_300 => 0x2E,
+            ElfInstructionSet::HitachiH8_300H => 0x2F,
+            ElfInstructionSet::HitachiH8S => 0x30,
+            ElfInstructionSet::HitachiH8500 => 0x31,
+            ElfInstructionSet::Ia64 => 0x32,
+            ElfInstructionSet::StanfordMipsX => 0x33,
+            ElfInstructionSet::MotorolaColdFire => 0x34,
+            ElfInstructionSet::MotorolaM68hc12 => 0x35,
+            ElfInstructionSet::FujitsuMma => 0x36,
+            ElfInstructionSet::SiemensPcp => 0x37,
+            ElfInstructionSet::SonyNCpu => 0x38,
+            ElfInstructionSet::DensoNdr1 => 0x39,
+            ElfInstructionSet::MotorolaStarCore => 0x3A,
+            ElfInstructionSet::ToyotaMe16 => 0x3B,
+            ElfInstructionSet::StmicroElectronicsSt100 => 0x3C,
+            ElfInstructionSet::AdvancedLogicCorpTinyJ => 0x3D,
+            ElfInstructionSet::AmdX86_64 => 0x3E,
+            ElfInstructionSet::SonyDsp => 0x3F,
+            ElfInstructionSet::DigitalEquipmentCorpPdp10 => 0x40,
+            ElfInstructionSet::DigitalEquipmentCorpPdp11 => 0x41,
+            ElfInstructionSet::SiemensFx66 => 0x42,
+            ElfInstructionSet::StmicroElectronicsSt9 => 0x43,
+            ElfInstructionSet::StmicroElectronicsSt7 => 0x44,
+            ElfInstructionSet::MotorolaMc68hc16 => 0x45,
+            ElfInstructionSet::MotorolaMc68hc11 => 0x46,
+            ElfInstructionSet::MotorolaMc68hc08 => 0x47,
+            ElfInstructionSet::MotorolaMc68hc05 => 0x48,
+            ElfInstructionSet::SiliconGraphicsSvx => 0x49,
+            ElfInstructionSet::StmicroElectronicsSt19 => 0x4A,
+            ElfInstructionSet::DigitalVax => 0x4B,
+            ElfInstructionSet::AxisCommunications32bit => 0x4C,
+            ElfInstructionSet::InfineonTechnologies32bit => 0x4D,
+            ElfInstructionSet::Element14_64bitDSP => 0x4E,
+            ElfInstructionSet::LsiLogic16bitDsp => 0x4F,
+            ElfInstructionSet::Tms320c6000Family => 0x8C,
+            ElfInstructionSet::McstElbrusE2k => 0xAF,
+            ElfInstructionSet::Arm64bit => 0xB7,
+            ElfInstructionSet::ZilogZ80 => 0xDC,
+            ElfInstructionSet::RiscV => 0xF3,
+            ElfInstructionSet::BerkeleyPacketFilter => 0xF7,
+            ElfInstructionSet::Wdc65c816 => 0x101,
+            ElfInstructionSet::LoongArch => 0x102,
+            ElfInstructionSet::Reserved(value) | ElfInstructionSet::Other(value) => *value,
+        }
+    }
+}
+
+impl Serialize for ElfInstructionSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub enum ElfObjectFileType {
+    #[default]
+    EtNone, //Unknown.
+    EtRel,    //Relocatable file.
+    EtExec,   //Executable file.
+    EtDyn,    //Shared object.
+    EtCore,   //Core file.
+    EtLoos,   //Reserved inclusive range. Operating system specific.
+    EtHios,   //
+    EtLoproc, //Reserved inclusive range. Processor specific.
+    EtHiproc,
+    Other(u16), // Any other value, named after the range it falls in
+}
+
+impl std::fmt::Display for ElfObjectFileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfObjectFileType::EtNone => "ET_NONE",
+            ElfObjectFileType::EtRel => "ET_REL",
+            ElfObjectFileType::EtExec => "ET_EXEC",
+            ElfObjectFileType::EtDyn => "ET_DYN",
+            ElfObjectFileType::EtCore => "ET_CORE",
+            ElfObjectFileType::EtLoos => "ET_LOOS",
+            ElfObjectFileType::EtHios => "ET_HIOS",
+            ElfObjectFileType::EtLoproc => "ET_LOPROC",
+            ElfObjectFileType::EtHiproc => "ET_HIPROC",
+            ElfObjectFileType::Other(value) => {
+                return match value {
+                    0xFE00..=0xFEFF => write!(f, "ET_LOOS+{:#x}", value - 0xFE00),
+                    0xFF00..=0xFFFF => write!(f, "ET_LOPROC+{:#x}", value - 0xFF00),
+                    _ => write!(f, "ET_<{:#x}>", value),
+                };
+            }
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfObjectFileType {
+    // Raw e_type value
+    pub fn value(&self) -> u16 {
+        match self {
+            ElfObjectFileType::EtNone => 0x00,
+            ElfObjectFileType::EtRel => 0x01,
+            ElfObjectFileType::EtExec => 0x02,
+            ElfObjectFileType::EtDyn => 0x03,
+            ElfObjectFileType::EtCore => 0x04,
+            ElfObjectFileType::EtLoos => 0xFE00,
+            ElfObjectFileType::EtHios => 0xFEFF,
+            ElfObjectFileType::EtLoproc => 0xFF00,
+            ElfObjectFileType::EtHiproc => 0xFFFF,
+            ElfObjectFileType::Other(value) => *value,
+        }
+    }
+}
+
+impl Serialize for ElfObjectFileType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfReservedPadding(pub [u8; 7]);
+
+impl std::fmt::Display for ElfReservedPadding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfTargetAbiVersion(pub u8);
+
+impl std::fmt::Display for ElfTargetAbiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+#[derive(Default, Debug)]
+pub enum ElfTargetSystemAbi {
+    #[default]
+    SystemV,
+    Hpux,
+    NetBsd,
+    Linux,
+    GnuHurd,
+    Solaris,
+    AixMonterey,
+    Irix,
+    FreeBsd,
+    Tru64,
+    NovellModesto,
+    OpenBsd,
+    OpenVms,
+    NonStopKernel,
+    Aros,
+    FenixOs,
+    NuxiCloudAbi,
+    StratusTechnologiesOpenVos,
+    ArmEabi,
+    Arm,
+    Standalone,
+    Other(u8), // Not known to this parser
+}
+
+impl std::fmt::Display for ElfTargetSystemAbi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfTargetSystemAbi::SystemV => "System V",
+            ElfTargetSystemAbi::Hpux => "HP-UX",
+            ElfTargetSystemAbi::NetBsd => "NetBSD",
+            ElfTargetSystemAbi::Linux => "Linux",
+            ElfTargetSystemAbi::GnuHurd => "GNU Hurd",
+            ElfTargetSystemAbi::Solaris => "Solaris",
+            ElfTargetSystemAbi::AixMonterey => "AIX (Monterey)",
+            ElfTargetSystemAbi::Irix => "IRIX",
+            ElfTargetSystemAbi::FreeBsd => "FreeBSD",
+            ElfTargetSystemAbi::Tru64 => "Tru64",
+            ElfTargetSystemAbi::NovellModesto => "Novell Modesto",
+            ElfTargetSystemAbi::OpenBsd => "OpenBSD",
+            ElfTargetSystemAbi::OpenVms => "OpenVMS",
+            ElfTargetSystemAbi::NonStopKernel => "NonStop Kernel",
+            ElfTargetSystemAbi::Aros => "AROS",
+            ElfTargetSystemAbi::FenixOs => "FenixOS",
+            ElfTargetSystemAbi::NuxiCloudAbi => "Nuxi CloudABI",
+            ElfTargetSystemAbi::StratusTechnologiesOpenVos => "Stratus Technologies OpenVOS",
+            ElfTargetSystemAbi::ArmEabi => "ARM EABI",
+            ElfTargetSystemAbi::Arm => "ARM",
+            ElfTargetSystemAbi::Standalone => "Standalone (embedded) application",
+            ElfTargetSystemAbi::Other(value) => return write!(f, "ELFOSABI_<{:#x}>", value),
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfTargetSystemAbi {
+    // Raw EI_OSABI value
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfTargetSystemAbi::SystemV => 0x00,
+            ElfTargetSystemAbi::Hpux => 0x01,
+            ElfTargetSystemAbi::NetBsd => 0x02,
+            ElfTargetSystemAbi::Linux => 0x03,
+            ElfTargetSystemAbi::GnuHurd => 0x04,
+            ElfTargetSystemAbi::Solaris => 0x06,
+            ElfTargetSystemAbi::AixMonterey => 0x07,
+            ElfTargetSystemAbi::Irix => 0x08,
+            ElfTargetSystemAbi::FreeBsd => 0x09,
+            ElfTargetSystemAbi::Tru64 => 0x0A,
+            ElfTargetSystemAbi::NovellModesto => 0x0B,
+            ElfTargetSystemAbi::OpenBsd => 0x0C,
+            ElfTargetSystemAbi::OpenVms => 0x0D,
+            ElfTargetSystemAbi::NonStopKernel => 0x0E,
+            ElfTargetSystemAbi::Aros => 0x0F,
+            ElfTargetSystemAbi::FenixOs => 0x10,
+            ElfTargetSystemAbi::NuxiCloudAbi => 0x11,
+            ElfTargetSystemAbi::StratusTechnologiesOpenVos => 0x12,
+            ElfTargetSystemAbi::ArmEabi => 0x40,
+            ElfTargetSystemAbi::Arm => 0x61,
+            ElfTargetSystemAbi::Standalone => 0xFF,
+            ElfTargetSystemAbi::Other(value) => *value,
+        }
+    }
+}
+
+impl Serialize for ElfTargetSystemAbi {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfHeaderVersion(pub u8);
+
+impl std::fmt::Display for ElfHeaderVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub enum ElfPlatformType {
+    #[default]
+    Bit32,
+    Bit64,
+}
+
+impl std::fmt::Display for ElfPlatformType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfPlatformType::Bit32 => "32-bit",
+            ElfPlatformType::Bit64 => "64-bit",
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfPlatformType {
+    // Raw EI_CLASS value
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfPlatformType::Bit32 => 1,
+            ElfPlatformType::Bit64 => 2,
+        }
+    }
+}
+
+impl Serialize for ElfPlatformType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub enum ElfEndianness {
+    #[default]
+    Little,
+    Big,
+}
+
+impl std::fmt::Display for ElfEndianness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfEndianness::Little => "Little",
+            ElfEndianness::Big => "Big",
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl Serialize for ElfEndianness {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+impl ElfEndianness {
+    // Raw EI_DATA value
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfEndianness::Little => 1,
+            ElfEndianness::Big => 2,
+        }
+    }
+
+    pub fn u16_from(&self, bytes: &[u8]) -> u16 {
+        match self {
+            ElfEndianness::Little => u16::from_le_bytes([bytes[0], bytes[1]]),
+            ElfEndianness::Big => u16::from_be_bytes([bytes[0], bytes[1]]),
+        }
+    }
+
+    pub fn u32_from(&self, bytes: &[u8]) -> u32 {
+        match self {
+            ElfEndianness::Little => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            ElfEndianness::Big => u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        }
+    }
+
+    pub fn u64_from(&self, bytes: &[u8]) -> u64 {
+        match self {
+            ElfEndianness::Little => u64::from_le_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]),
+            ElfEndianness::Big => u64::from_be_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfMagicNumber(pub String);
+
+impl std::fmt::Display for ElfMagicNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub fn parse_section_header_sections_table_index(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionHeaderSectionsTableIndex, ElfError> {
+    let value = reader.read_u16("e_shstrndx", endian)?;
+
+    Ok(ElfSectionHeaderSectionsTableIndex(value))
+}
+
+pub fn parse_section_header_entry_count(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionHeaderEntryCount, ElfError> {
+    let value = reader.read_u16("e_shnum", endian)?;
+
+    Ok(ElfSectionHeaderEntryCount(value))
+}
+
+pub fn parse_section_header_entry_size(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionHeaderEntrySize, ElfError> {
+    let value = reader.read_u16("e_shentsize", endian)?;
+
+    Ok(ElfSectionHeaderEntrySize(value))
+}
+
+pub fn parse_program_header_entry_count(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfProgramHeaderEntryCount, ElfError> {
+    let value = reader.read_u16("e_phnum", endian)?;
+
+    Ok(ElfProgramHeaderEntryCount(value))
+}
+
+pub fn parse_program_header_entry_size(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfProgramHeaderEntrySize, ElfError> {
+    let value = reader.read_u16("e_phentsize", endian)?;
+
+    Ok(ElfProgramHeaderEntrySize(value))
+}
+
+pub fn parse_header_size(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfHeaderSize, ElfError> {
+    let value = reader.read_u16("e_ehsize", endian)?;
+
+    Ok(ElfHeaderSize(value))
+}
+
+pub fn parse_flags(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfFlags, ElfError> {
+    let flags = reader.read_u32("e_flags", endian)?;
+
+    Ok(ElfFlags(flags))
+}
+
+pub fn parse_section_header_offset(
+    reader: &mut ElfReader<'_>,
+    platform: &ElfPlatformType,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionHeaderOffset, ElfError> {
+    let value = reader.read_word("e_shoff", endian, platform)?;
+
+    Ok(ElfSectionHeaderOffset(value))
+}
+
+pub fn parse_program_header_offset(
+    reader: &mut ElfReader<'_>,
+    platform: &ElfPlatformType,
+    endian: &ElfEndianness,
+) -> Result<ElfProgramHeaderOffset, ElfError> {
+    let value = reader.read_word("e_phoff", endian, platform)?;
+
+    Ok(ElfProgramHeaderOffset(value))
+}
+
+pub fn parse_entry_point(
+    reader: &mut ElfReader<'_>,
+    platform: &ElfPlatformType,
+    endian: &ElfEndianness,
+) -> Result<ElfEntryPoint, ElfError> {
+    let value = reader.read_word("e_entry", endian, platform)?;
+
+    Ok(ElfEntryPoint(value))
+}
+
+pub fn parse_elf_version(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfVersion, ElfError> {
+    let e_version = reader.read_u32("e_version", endian)?;
+
+    Ok(ElfVersion(e_version))
+}
+
+pub fn parse_instruction_set(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfInstructionSet, ElfError> {
+    let set = reader.read_u16("e_machine", endian)?;
+
+    let set = match set {
+        0x00 => ElfInstructionSet::UnSpecified,
+        0x01 => ElfInstructionSet::AtTwe32100,
+        0x02 => ElfInstructionSet::Sparc,
+        0x03 => ElfInstructionSet::X86,
+        0x04 => ElfInstructionSet::Motorola68000M68k,
+        0x05 => ElfInstructionSet::Motorola88000M88k,
+        0x06 => ElfInstructionSet::IntelMcu,
+        0x07 => ElfInstructionSet::Intel80860,
+        0x08 => ElfInstructionSet::Mips,
+        0x09 => ElfInstructionSet::Ibmsystem370,
+        0x0A => ElfInstructionSet::Mipsrs3000LittleEndian,
+        reserved @ 0x0B..=0x0E => ElfInstructionSet::Reserved(reserved),
+        0x0F => ElfInstructionSet::HewlettPackardPaRisc,
+        0x13 => ElfInstructionSet::Intel80960,
+        0x14 => ElfInstructionSet::PowerPc,
+        0x15 => ElfInstructionSet::PowerPc64bit,
+        0x16 => ElfInstructionSet::S390,
+        0x17 => ElfInstructionSet::IbmSpuSpc,
+        reserved @ 0x18..=0x23 => ElfInstructionSet::Reserved(reserved),
+        0x24 => ElfInstructionSet::NecV800,
+        0x25 => ElfInstructionSet::FujitsuFr20,
+        0x26 => ElfInstructionSet::TrwRh32,
+        0x27 => ElfInstructionSet::MotorolaRce,
+        0x28 => ElfInstructionSet::Arm,
+        0x29 => ElfInstructionSet::DigitalAlpha,
+        0x2A => ElfInstructionSet::SuperH,
+        0x2B => ElfInstructionSet::SparcV9,
+        0x2C => ElfInstructionSet::SiemensTriCore,
+        0x2D => ElfInstructionSet::ArgonautRiscCore,
+        0x2E => ElfInstructionSet::HitachiH8_300,
+        0x2F => ElfInstructionSet::HitachiH8_300H,
+        0x30 => ElfInstructionSet::HitachiH8S,
+        0x31 => ElfInstructionSet::HitachiH8500,
+        0x32 => ElfInstructionSet::Ia64,
+        0x33 => ElfInstructionSet::StanfordMipsX,
+        0x34 => ElfInstructionSet::MotorolaColdFire,
+        0x35 => ElfInstructionSet::MotorolaM68hc12,
+        0x36 => ElfInstructionSet::FujitsuMma,
+        0x37 => ElfInstructionSet::SiemensPcp,
+        0x38 => ElfInstructionSet::SonyNCpu,
+        0x39 => ElfInstructionSet::DensoNdr1,
+        0x3A => ElfInstructionSet::MotorolaStarCore,
+        0x3B => ElfInstructionSet::ToyotaMe16,
+        0x3C => ElfInstructionSet::StmicroElectronicsSt100,
+        0x3D => ElfInstructionSet::AdvancedLogicCorpTinyJ,
+        0x3E => ElfInstructionSet::AmdX86_64,
+        0x3F => ElfInstructionSet::SonyDsp,
+        0x40 => ElfInstructionSet::DigitalEquipmentCorpPdp10,
+        0x41 => ElfInstructionSet::DigitalEquipmentCorpPdp11,
+        0x42 => ElfInstructionSet::SiemensFx66,
+        0x43 => ElfInstructionSet::StmicroElectronicsSt9,
+        0x44 => ElfInstructionSet::StmicroElectronicsSt7,
+        0x45 => ElfInstructionSet::MotorolaMc68hc16,
+        0x46 => ElfInstructionSet::MotorolaMc68hc11,
+        0x47 => ElfInstructionSet::MotorolaMc68hc08,
+        0x48 => ElfInstructionSet::MotorolaMc68hc05,
+        0x49 => ElfInstructionSet::SiliconGraphicsSvx,
+        0x4A => ElfInstructionSet::StmicroElectronicsSt19,
+        0x4B => ElfInstructionSet::DigitalVax,
+        0x4C => ElfInstructionSet::AxisCommunications32bit,
+        0x4D => ElfInstructionSet::InfineonTechnologies32bit,
+        0x4E => ElfInstructionSet::Element14_64bitDSP,
+        0x4F => ElfInstructionSet::LsiLogic16bitDsp,
+        0x8C => ElfInstructionSet::Tms320c6000Family,
+        0xAF => ElfInstructionSet::McstElbrusE2k,
+        0xB7 => ElfInstructionSet::Arm64bit,
+        0xDC => ElfInstructionSet::ZilogZ80,
+        0xF3 => ElfInstructionSet::RiscV,
+        0xF7 => ElfInstructionSet::BerkeleyPacketFilter,
+        0x101 => ElfInstructionSet::Wdc65c816,
+        0x102 => ElfInstructionSet::LoongArch,
+        other => ElfInstructionSet::Other(other),
+    };
+
+    Ok(set)
+}
+
+pub fn parse_object_file_type(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfObjectFileType, ElfError> {
+    let f_type = reader.read_u16("e_type", endian)?;
+
+    let f_type = match f_type {
+        0x00 => ElfObjectFileType::EtNone,
+        0x01 => ElfObjectFileType::EtRel,
+        0x02 => ElfObjectFileType::EtExec,
+        0x03 => ElfObjectFileType::EtDyn,
+        0x04 => ElfObjectFileType::EtCore,
+        0xFE00 => ElfObjectFileType::EtLoos,
+        0xFEFF => ElfObjectFileType::EtHios,
+        0xFF00 => ElfObjectFileType::EtLoproc,
+        0xFFFF => ElfObjectFileType::EtHiproc,
+        other => ElfObjectFileType::Other(other),
+    };
+
+    Ok(f_type)
+}
+
+pub fn parse_reserved_padding(reader: &mut ElfReader<'_>) -> Result<ElfReservedPadding, ElfError> {
+    let padding = reader.read_bytes::<7>("e_ident[EI_PAD]")?;
+
+    Ok(ElfReservedPadding(padding))
+}
+
+pub fn parse_target_abi_version(
+    reader: &mut ElfReader<'_>,
+) -> Result<ElfTargetAbiVersion, ElfError> {
+    let ver = reader.read_u8("e_ident[EI_ABIVERSION]")?;
+
+    Ok(ElfTargetAbiVersion(ver))
+}
+
+pub fn parse_target_system_abi(reader: &mut ElfReader<'_>) -> Result<ElfTargetSystemAbi, ElfError> {
+    let t_abi = match reader.read_u8("e_ident[EI_OSABI]")? {
+        0x00 => ElfTargetSystemAbi::SystemV,
+        0x01 => ElfTargetSystemAbi::Hpux,
+        0x02 => ElfTargetSystemAbi::NetBsd,
+        0x03 => ElfTargetSystemAbi::Linux,
+        0x04 => ElfTargetSystemAbi::GnuHurd,
+        0x06 => ElfTargetSystemAbi::Solaris,
+        0x07 => ElfTargetSystemAbi::AixMonterey,
+        0x08 => ElfTargetSystemAbi::Irix,
+        0x09 => ElfTargetSystemAbi::FreeBsd,
+        0x0A => ElfTargetSystemAbi::Tru64,
+        0x0B => ElfTargetSystemAbi::NovellModesto,
+        0x0C => ElfTargetSystemAbi::OpenBsd,
+        0x0D => ElfTargetSystemAbi::OpenVms,
+        0x0E => ElfTargetSystemAbi::NonStopKernel,
+        0x0F => ElfTargetSystemAbi::Aros,
+        0x10 => ElfTargetSystemAbi::FenixOs,
+        0x11 => ElfTargetSystemAbi::NuxiCloudAbi,
+        0x12 => ElfTargetSystemAbi::StratusTechnologiesOpenVos,
+        0x40 => ElfTargetSystemAbi::ArmEabi,
+        0x61 => ElfTargetSystemAbi::Arm,
+        0xFF => ElfTargetSystemAbi::Standalone,
+        other => ElfTargetSystemAbi::Other(other),
+    };
+
+    Ok(t_abi)
+}
+
+pub fn parse_elf_header_version(reader: &mut ElfReader<'_>) -> Result<ElfHeaderVersion, ElfError> {
+    let v = reader.read_u8("e_ident[EI_VERSION]")?;
+
+    Ok(ElfHeaderVersion(v))
+}
+
+pub fn parse_endianness(reader: &mut ElfReader<'_>) -> Result<ElfEndianness, ElfError> {
+    let offset = reader.pointer();
+    let end = match reader.read_u8("e_ident[EI_DATA]")? {
+        1u8 => ElfEndianness::Little,
+        2u8 => ElfEndianness::Big,
+        other => {
+            return Err(ElfError::InvalidValue {
+                field: "e_ident[EI_DATA]",
+                offset,
+                expected: "1 (little) or 2 (big)",
+                actual: other as u64,
+            });
+        }
+    };
+
+    Ok(end)
+}
+
+pub fn parse_platform_type(reader: &mut ElfReader<'_>) -> Result<ElfPlatformType, ElfError> {
+    let offset = reader.pointer();
+    let p_type = match reader.read_u8("e_ident[EI_CLASS]")? {
+        1u8 => ElfPlatformType::Bit32,
+        2u8 => ElfPlatformType::Bit64,
+        other => {
+            return Err(ElfError::UnsupportedClass {
+                offset,
+                actual: other,
+            });
+        }
+    };
+
+    Ok(p_type)
+}
+
+pub fn parse_magic_number(reader: &mut ElfReader<'_>) -> Result<ElfMagicNumber, ElfError> {
+    let offset = reader.pointer();
+    let magic_number = reader.read_bytes::<4>("e_ident[EI_MAG]")?;
+    let val_magic = [0x7f, 0x45, 0x4c, 0x46];
+
+    if magic_number != val_magic {
+        return Err(ElfError::NotElf {
+            offset,
+            actual: magic_number,
+        });
+    }
+
+    let magic_number = String::from_utf8_lossy(&magic_number).to_string();
+
+    Ok(ElfMagicNumber(magic_number))
+}
+
+pub fn parse_header(reader: &mut ElfReader<'_>) -> Result<ElfHeader, ElfError> {
+    let magic_number = parse_magic_number(reader)?;
+    let platform_type = parse_platform_type(reader)?;
+    let endianness = parse_endianness(reader)?;
+    let elf_header_version = parse_elf_header_version(reader)?;
+    let target_system_abi = parse_target_system_abi(reader)?;
+    let target_abi_version = parse_target_abi_version(reader)?;
+    let _reserved_padding = parse_reserved_padding(reader)?;
+    let object_file_type = parse_object_file_type(reader, &endianness)?;
+    let instruction_set = parse_instruction_set(reader, &endianness)?;
+    let elf_version = parse_elf_version(reader, &endianness)?;
+    let entry_point = parse_entry_point(reader, &platform_type, &endianness)?;
+    let program_header_offset = parse_program_header_offset(reader, &platform_type, &endianness)?;
+    let section_header_offset = parse_section_header_offset(reader, &platform_type, &endianness)?;
+    let flags = parse_flags(reader, &endianness)?;
+    let header_size = parse_header_size(reader, &endianness)?;
+    let program_header_entry_size = parse_program_header_entry_size(reader, &endianness)?;
+    let program_header_entry_count = parse_program_header_entry_count(reader, &endianness)?;
+    let section_header_entry_size = parse_section_header_entry_size(reader, &endianness)?;
+    let section_header_entry_count = parse_section_header_entry_count(reader, &endianness)?;
+    let section_header_sections_table_index =
+        parse_section_header_sections_table_index(reader, &endianness)?;
+
+    Ok(ElfHeader {
+        magic_number,
+        platform_type,
+        endianness,
+        elf_header_version,
+        target_system_abi,
+        target_abi_version,
+        object_file_type,
+        instruction_set,
+        elf_version,
+        entry_point,
+        program_header_offset,
+        section_header_offset,
+        flags,
+        header_size,
+        program_header_entry_size,
+        program_header_entry_count,
+        section_header_entry_size,
+        section_header_entry_count,
+        section_header_sections_table_index,
+    })
+}
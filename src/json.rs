@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use serde::{Serialize, Serializer, ser::SerializeStruct};
+
+use crate::{
+    ElfAddr2Line, ElfArchiveSymbol, ElfChecksec, ElfCore, ElfDiagnostic, ElfDisassembly,
+    ElfDwarfUnit, ElfDynamicTable, ElfHeader, ElfNoteTable, ElfProgramHeaderEntry,
+    ElfRelocationTable, ElfSectionDump, ElfSectionHeaderEntry, ElfSectionsDataEntry,
+    ElfSegmentMapping, ElfStringsEntry, ElfSummary, ElfSymbolTable,
+};
+
+// Version of the layout described in docs/json-schema.md. Bumped whenever a
+// field is removed, renamed or changes meaning; adding fields keeps it.
+pub const JSON_SCHEMA_VERSION: u32 = 2;
+
+// Top level object of the JSON output. Parts that were not asked for are
+// left out instead of being serialised empty.
+#[derive(Debug, Default, Serialize)]
+pub struct ElfJsonReport<'a> {
+    pub schema_version: u32,
+    pub file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<&'a ElfHeader>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program_headers: Option<&'a [ElfProgramHeaderEntry]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section_headers: Option<&'a [ElfSectionHeaderEntry]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sections_data: Option<&'a [ElfSectionsDataEntry]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section_dump: Option<&'a ElfSectionDump>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_tables: Option<&'a [ElfSymbolTable]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strings: Option<&'a [ElfStringsEntry]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disassembly: Option<&'a ElfDisassembly>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dwarf: Option<&'a [ElfDwarfUnit]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addr2line: Option<&'a ElfAddr2Line>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relocation_tables: Option<&'a [ElfRelocationTable]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dynamic: Option<&'a ElfDynamicTable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<&'a [ElfNoteTable]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<&'a ElfSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksec: Option<&'a ElfChecksec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_mapping: Option<&'a ElfSegmentMapping>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub core: Option<&'a ElfCore>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostics: Option<&'a [ElfDiagnostic]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_index: Option<&'a [ElfArchiveSymbol]>,
+    // One report per member when the file is a static archive
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<ElfJsonReport<'a>>>,
+}
+
+impl ElfJsonReport<'_> {
+    pub fn new(file: &Path) -> Self {
+        ElfJsonReport {
+            schema_version: JSON_SCHEMA_VERSION,
+            file: file.display().to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+// Enumerations are written as `{ "value": <raw>, "name": <Display> }`.
+pub(crate) fn serialize_named<S: Serializer>(
+    serializer: S,
+    value: u64,
+    name: &dyn std::fmt::Display,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("Named", 2)?;
+    state.serialize_field("value", &value)?;
+    state.serialize_field("name", &name.to_string())?;
+    state.end()
+}
+
+// Bitmasks are written as `{ "value": <raw>, "names": [<flag>, ...] }`.
+pub(crate) fn serialize_flags<S: Serializer>(
+    serializer: S,
+    value: u64,
+    names: Vec<String>,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("Flags", 2)?;
+    state.serialize_field("value", &value)?;
+    state.serialize_field("names", &names)?;
+    state.end()
+}
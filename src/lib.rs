@@ -0,0 +1,299 @@
+// An ELF executable file format parser
+// References:
+//     https://en.wikipedia.org/wiki/Executable_and_Linkable_Format
+//     https://wiki.osdev.org/ELF
+
+use std::{fs::File, io::Read, path::Path};
+
+use tabled::Tabled;
+
+mod archive;
+mod checksec;
+mod compress;
+mod coredump;
+mod disasm;
+mod dump;
+mod dwarf;
+mod dynamic;
+mod error;
+mod flags;
+mod header;
+mod json;
+mod mapping;
+mod note;
+mod program;
+mod reader;
+mod relocation;
+mod section;
+mod strings;
+mod summary;
+mod symbol;
+mod validate;
+
+pub use archive::*;
+pub use checksec::*;
+pub use compress::*;
+pub use coredump::*;
+pub use disasm::*;
+pub use dump::*;
+pub use dwarf::*;
+pub use dynamic::*;
+pub use error::*;
+pub use flags::*;
+pub use header::*;
+pub use json::*;
+pub use mapping::*;
+pub use note::*;
+pub use program::*;
+pub use reader::*;
+pub use relocation::*;
+pub use section::*;
+pub use strings::*;
+pub use summary::*;
+pub use symbol::*;
+pub use validate::*;
+
+// A parsed ELF image. Borrows the raw bytes so that callers can go back
+// to the file content for anything the headers point at.
+#[derive(Debug, Default)]
+pub struct ElfFile<'data> {
+    pub content: &'data [u8],
+    pub header: ElfHeader,
+    pub program_header: ElfProgramHeader,
+    pub section_header: ElfSectionHeader,
+}
+
+impl<'data> ElfFile<'data> {
+    pub fn parse(content: &'data [u8]) -> Result<Self, ElfError> {
+        let mut reader = ElfReader::new(content);
+        let header = parse_header(&mut reader)?;
+
+        reader.seek(header.program_header_offset.0);
+        let program_header = parse_program_header(
+            &mut reader,
+            parse_program_header_count(content, &header)?,
+            &header.program_header_entry_size,
+            &header.endianness,
+            &header.platform_type,
+        )?;
+
+        reader.seek(header.section_header_offset.0);
+        let section_header = parse_section_header(
+            &mut reader,
+            &header.section_header_entry_count,
+            &header.section_header_entry_size,
+            &header.section_header_sections_table_index,
+            &header.endianness,
+            &header.platform_type,
+        )?;
+
+        Ok(ElfFile {
+            content,
+            header,
+            program_header,
+            section_header,
+        })
+    }
+
+    // The program header count read from the sh_info of section 0 when e_phnum
+    // is PN_XNUM, `None` when e_phnum holds the count itself.
+    pub fn extended_program_header_count(&self) -> Option<usize> {
+        self.header
+            .program_header_entry_count
+            .is_extended()
+            .then_some(self.program_header.inner.len())
+    }
+
+    pub fn sections_data(&self) -> Result<ElfSectionsData, ElfError> {
+        parse_sections_data(self.content, &self.section_header)
+    }
+
+    pub fn section_dump(&self, selector: &str) -> Result<ElfSectionDump, ElfError> {
+        parse_section_dump(
+            self.content,
+            &self.section_header,
+            selector,
+            &self.header.endianness,
+        )
+    }
+
+    pub fn strings(
+        &self,
+        min_length: usize,
+        encodings: &[ElfStringEncoding],
+    ) -> Result<ElfStrings, ElfError> {
+        parse_strings(
+            self.content,
+            &self.program_header,
+            &self.section_header,
+            min_length,
+            encodings,
+        )
+    }
+
+    pub fn disassemble(&self, selector: &str) -> Result<ElfDisassembly, ElfError> {
+        parse_disassembly(
+            self.content,
+            &self.header,
+            &self.section_header,
+            &self.symbol_tables()?,
+            selector,
+        )
+    }
+
+    pub fn disassemble_symbol(&self, name: &str) -> Result<ElfDisassembly, ElfError> {
+        parse_symbol_disassembly(
+            self.content,
+            &self.header,
+            &self.section_header,
+            &self.symbol_tables()?,
+            name,
+        )
+    }
+
+    pub fn dwarf(&self) -> Result<ElfDwarf, ElfError> {
+        parse_dwarf(
+            self.content,
+            &self.header,
+            &self.section_header,
+            &self.relocation_tables()?,
+        )
+    }
+
+    pub fn addr2line(&self, address: u64) -> Result<ElfAddr2Line, ElfError> {
+        parse_addr2line(
+            self.content,
+            &self.header,
+            &self.section_header,
+            &self.relocation_tables()?,
+            address,
+        )
+    }
+
+    pub fn symbol_tables(&self) -> Result<ElfSymbolTables, ElfError> {
+        parse_symbol_tables(
+            self.content,
+            &self.section_header,
+            &self.header.endianness,
+            &self.header.platform_type,
+        )
+    }
+
+    pub fn relocation_tables(&self) -> Result<ElfRelocationTables, ElfError> {
+        parse_relocation_tables(
+            self.content,
+            &self.section_header,
+            &self.header.instruction_set,
+            &self.header.endianness,
+            &self.header.platform_type,
+        )
+    }
+
+    pub fn dynamic_table(&self) -> Result<ElfDynamicTable, ElfError> {
+        parse_dynamic_table(
+            self.content,
+            &self.program_header,
+            &self.section_header,
+            &self.header.endianness,
+            &self.header.platform_type,
+        )
+    }
+
+    pub fn note_tables(&self) -> Result<ElfNoteTables, ElfError> {
+        parse_note_tables(
+            self.content,
+            &self.program_header,
+            &self.section_header,
+            &self.header.instruction_set,
+            &self.header.endianness,
+            &self.header.platform_type,
+        )
+    }
+
+    pub fn core(&self) -> Result<ElfCore, ElfError> {
+        parse_core(
+            self.content,
+            &self.header,
+            &self.program_header,
+            &self.section_header,
+        )
+    }
+
+    pub fn summary(&self) -> Result<ElfSummary, ElfError> {
+        parse_summary(
+            self.content,
+            &self.header,
+            &self.program_header,
+            &self.section_header,
+        )
+    }
+
+    pub fn checksec(&self) -> Result<ElfChecksec, ElfError> {
+        parse_checksec(
+            self.content,
+            &self.header,
+            &self.program_header,
+            &self.section_header,
+        )
+    }
+
+    pub fn segment_mapping(&self) -> ElfSegmentMapping {
+        parse_segment_mapping(&self.program_header, &self.section_header)
+    }
+
+    pub fn validate(&self) -> ElfDiagnostics {
+        validate(self.content)
+    }
+
+    pub fn section_by_name(&self, name: &str) -> Option<&ElfSectionHeaderEntry> {
+        self.section_header
+            .inner
+            .iter()
+            .find(|entry| entry.section_name.0 == name)
+    }
+}
+
+pub fn read_file(filepath: &Path) -> Result<Vec<u8>, ElfError> {
+    let mut file = File::options().read(true).open(filepath)?;
+    let mut buf = Vec::new();
+    let read = file.read_to_end(&mut buf)?;
+    if read == 0 {
+        return Err(ElfError::EmptyFile);
+    }
+
+    buf.shrink_to_fit();
+    Ok(buf)
+}
+
+#[derive(Debug, Default, Tabled)]
+pub struct ElfBinary {
+    pub header: ElfHeader,
+    pub program_header: ElfProgramHeader,
+    pub section_header: ElfSectionHeader,
+    pub sections_data: ElfSectionsData,
+    pub section_dump: ElfSectionDump,
+    pub strings: ElfStrings,
+    pub disassembly: ElfDisassembly,
+    pub dwarf: ElfDwarf,
+    pub addr2line: ElfAddr2Line,
+    pub symbol_tables: ElfSymbolTables,
+    pub relocation_tables: ElfRelocationTables,
+    pub dynamic_table: ElfDynamicTable,
+    pub note_tables: ElfNoteTables,
+    pub summary: ElfSummary,
+    pub checksec: ElfChecksec,
+    pub segment_mapping: ElfSegmentMapping,
+    pub diagnostics: ElfDiagnostics,
+    pub archive_index: ElfArchiveSymbols,
+    pub core: ElfCore,
+}
+
+impl std::fmt::Display for ElfBinary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\n{}\n{}",
+            self.header, self.program_header, self.section_header
+        )
+    }
+}
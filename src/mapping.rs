@@ -0,0 +1,188 @@
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    ElfProgramHeader, ElfProgramHeaderEntry, ElfSectionFlags, ElfSectionHeader,
+    ElfSectionHeaderEntry, ElfSectionHeaderType, ElfSegmentMemorySize, ElfSegmentType,
+    ElfSegmentVAddr,
+};
+
+// Which sections land in which segment, as in the "Section to Segment mapping"
+// of `readelf -l`
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSegmentMapping {
+    pub inner: Vec<ElfSegmentSections>,
+    // Sections, other than the null section 0, that no segment contains
+    pub unmapped: ElfSegmentSectionNames,
+}
+
+impl ElfSegmentMapping {
+    pub fn inner(self) -> Vec<ElfSegmentSections> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfSegmentMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Tabled, Serialize)]
+pub struct ElfSegmentSections {
+    // Index of the segment in the program header table
+    pub segment_index: usize,
+    pub segment_type: ElfSegmentType,
+    pub segment_vaddr: ElfSegmentVAddr,
+    pub segment_memory_size: ElfSegmentMemorySize,
+    pub sections: ElfSegmentSectionNames,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSegmentSectionNames(pub Vec<String>);
+
+impl std::fmt::Display for ElfSegmentSectionNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join(" "))
+    }
+}
+
+// `start..start + size` lies within `segment_start..segment_start + segment_size`.
+// Like binutils, an empty section may sit at the very end of an empty segment only.
+fn range_in_segment(start: usize, size: usize, segment_start: usize, segment_size: usize) -> bool {
+    let Some(relative) = start.checked_sub(segment_start) else {
+        return false;
+    };
+
+    relative <= segment_size.wrapping_sub(1)
+        && relative
+            .checked_add(size)
+            .is_some_and(|end| end <= segment_size)
+}
+
+// The rules of binutils' ELF_SECTION_IN_SEGMENT_STRICT, so that the mapping
+// matches what `readelf -l` prints.
+pub fn section_in_segment(
+    section: &ElfSectionHeaderEntry,
+    segment: &ElfProgramHeaderEntry,
+) -> bool {
+    let tls = section.section_flags.contains(ElfSectionFlags::SHF_TLS);
+    let alloc = section.section_flags.contains(ElfSectionFlags::SHF_ALLOC);
+    let nobits = matches!(section.section_header_type, ElfSectionHeaderType::ShtNobits);
+    let segment_type = &segment.segment_type;
+
+    // .tbss takes no room in the image outside of PT_TLS
+    if tls && nobits && !matches!(segment_type, ElfSegmentType::PtTls) {
+        return false;
+    }
+    let type_matches = match tls {
+        true => matches!(
+            segment_type,
+            ElfSegmentType::PtTls | ElfSegmentType::PtGnuRelro | ElfSegmentType::PtLoad
+        ),
+        false => !matches!(segment_type, ElfSegmentType::PtTls | ElfSegmentType::PtPhdr),
+    };
+    if !type_matches {
+        return false;
+    }
+    // Non allocated sections are never part of the memory image
+    if !alloc
+        && matches!(
+            segment_type,
+            ElfSegmentType::PtLoad
+                | ElfSegmentType::PtDynamic
+                | ElfSegmentType::PtGnuEhFrame
+                | ElfSegmentType::PtGnuStack
+                | ElfSegmentType::PtGnuRelro
+                | ElfSegmentType::PtGnuSframe
+        )
+    {
+        return false;
+    }
+
+    let offset = section.section_offset.0;
+    let addr = section.section_addr.0;
+    let size = section.section_size.0;
+    if !nobits
+        && !range_in_segment(
+            offset,
+            size,
+            segment.segment_offset.0,
+            segment.segment_file_size.0,
+        )
+    {
+        return false;
+    }
+    if alloc
+        && !range_in_segment(
+            addr,
+            size,
+            segment.segment_vaddr.0,
+            segment.segment_memory_size.0,
+        )
+    {
+        return false;
+    }
+
+    // An empty section right at the end of PT_DYNAMIC is not part of it
+    if matches!(segment_type, ElfSegmentType::PtDynamic)
+        && size == 0
+        && segment.segment_memory_size.0 != 0
+    {
+        let in_file = nobits
+            || (offset > segment.segment_offset.0
+                && offset - segment.segment_offset.0 < segment.segment_file_size.0);
+        let in_memory = !alloc
+            || (addr > segment.segment_vaddr.0
+                && addr - segment.segment_vaddr.0 < segment.segment_memory_size.0);
+        return in_file && in_memory;
+    }
+
+    true
+}
+
+pub fn parse_segment_mapping(
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+) -> ElfSegmentMapping {
+    // Section 0 is the null section and never mapped
+    let sections = section_header.inner.get(1..).unwrap_or_default();
+    let mut mapped = vec![false; sections.len()];
+
+    let inner = program_header
+        .inner
+        .iter()
+        .enumerate()
+        .map(|(segment_index, segment)| {
+            let names = sections
+                .iter()
+                .zip(mapped.iter_mut())
+                .filter(|(section, _)| section_in_segment(section, segment))
+                .map(|(section, mapped)| {
+                    *mapped = true;
+                    section.section_name.inner()
+                })
+                .collect();
+
+            ElfSegmentSections {
+                segment_index,
+                segment_type: segment.segment_type.clone(),
+                segment_vaddr: ElfSegmentVAddr(segment.segment_vaddr.0),
+                segment_memory_size: ElfSegmentMemorySize(segment.segment_memory_size.0),
+                sections: ElfSegmentSectionNames(names),
+            }
+        })
+        .collect();
+
+    let unmapped = sections
+        .iter()
+        .zip(mapped)
+        .filter(|(_, mapped)| !mapped)
+        .map(|(section, _)| section.section_name.inner())
+        .collect();
+
+    ElfSegmentMapping {
+        inner,
+        unmapped: ElfSegmentSectionNames(unmapped),
+    }
+}
@@ -0,0 +1,525 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfEndianness, ElfError, ElfInstructionSet, ElfPlatformType, ElfProgramHeader, ElfReader,
+    ElfSectionHeader, ElfSectionHeaderType, ElfSegmentType, flag_names, serialize_named, slice_at,
+};
+
+// All the note sections (SHT_NOTE) found in a file, or its PT_NOTE segments
+// when the file has no section headers.
+#[derive(Debug, Default, Serialize)]
+pub struct ElfNoteTables {
+    pub inner: Vec<ElfNoteTable>,
+}
+
+impl ElfNoteTables {
+    pub fn inner(self) -> Vec<ElfNoteTable> {
+        self.inner
+    }
+
+    // The NT_GNU_BUILD_ID descriptor as a hex string
+    pub fn gnu_build_id(&self) -> Option<String> {
+        self.inner
+            .iter()
+            .flat_map(|table| table.inner.iter())
+            .find(|note| note.note_owner == "GNU" && note.note_type.value == 3)
+            .map(|note| hex_bytes(&note.note_data, ""))
+    }
+
+    // The 32-bit pr_data of the first GNU property of type `pr_type`, e.g. the
+    // feature bits of GNU_PROPERTY_X86_FEATURE_1_AND
+    pub fn gnu_property(
+        &self,
+        pr_type: u32,
+        endian: &ElfEndianness,
+        platform: &ElfPlatformType,
+    ) -> Option<u32> {
+        self.inner
+            .iter()
+            .flat_map(|table| table.inner.iter())
+            .filter(|note| note.note_owner == "GNU" && note.note_type.value == 5)
+            .filter_map(|note| split_gnu_properties(&note.note_data, endian, platform).ok())
+            .flatten()
+            .find(|(property, _)| *property == pr_type)
+            .and_then(|(_, data)| ElfReader::new(data).read_u32("pr_data", endian).ok())
+    }
+}
+
+impl std::fmt::Display for ElfNoteTables {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfNoteTable {
+    // Name of the note section, e.g. `.note.gnu.build-id`.
+    // Empty for notes read from a PT_NOTE segment.
+    pub section_name: String,
+    // Offset of the notes in the file image
+    pub offset: usize,
+    pub size: usize,
+    pub inner: Vec<ElfNote>,
+}
+
+impl std::fmt::Display for ElfNoteTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{:?}", self.section_name, self.inner)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfNote {
+    // Name of the note's originator, e.g. GNU or FreeBSD
+    pub note_owner: String,
+    // Size in bytes of the descriptor
+    pub note_data_size: ElfNoteDataSize,
+    pub note_type: ElfNoteType,
+    // Decoded descriptor for the notes this parser knows, hex bytes otherwise
+    pub note_description: String,
+    // The raw descriptor
+    #[tabled(skip)]
+    #[serde(skip)]
+    pub note_data: Vec<u8>,
+    // File offset of the descriptor
+    #[tabled(skip)]
+    #[serde(skip)]
+    pub note_data_offset: usize,
+}
+
+impl std::fmt::Display for ElfNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = format!(
+            "{}\n{}\n{}\n{}",
+            self.note_owner, self.note_data_size, self.note_type, self.note_description
+        );
+        write!(f, "{}", txt)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfNoteDataSize(pub usize);
+
+impl std::fmt::Display for ElfNoteDataSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+// Note types are only meaningful together with the owner, so the raw value
+// is kept next to the name resolved for it.
+#[derive(Debug, Default)]
+pub struct ElfNoteType {
+    pub value: u32,
+    pub name: Option<&'static str>,
+}
+
+impl std::fmt::Display for ElfNoteType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "NT_<{:#x}>", self.value),
+        }
+    }
+}
+
+impl Serialize for ElfNoteType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, self.value as u64, self)
+    }
+}
+
+pub fn note_type_name(owner: &str, n_type: u32) -> Option<&'static str> {
+    let name = match (owner, n_type) {
+        ("GNU", 1) => "NT_GNU_ABI_TAG",
+        ("GNU", 2) => "NT_GNU_HWCAP",
+        ("GNU", 3) => "NT_GNU_BUILD_ID",
+        ("GNU", 4) => "NT_GNU_GOLD_VERSION",
+        ("GNU", 5) => "NT_GNU_PROPERTY_TYPE_0",
+        ("FDO", 0xCAFE1A7E) => "NT_FDO_PACKAGING_METADATA",
+        ("FDO", 0x407C0C0A) => "NT_FDO_DLOPEN_METADATA",
+        ("Go", 4) => "NT_GO_BUILD_ID",
+        ("FreeBSD", 1) => "NT_FREEBSD_ABI_TAG",
+        ("FreeBSD", 2) => "NT_FREEBSD_NOINIT_TAG",
+        ("FreeBSD", 3) => "NT_FREEBSD_ARCH_TAG",
+        ("FreeBSD", 4) => "NT_FREEBSD_FEATURE_CTL",
+        ("CORE", 1) => "NT_PRSTATUS",
+        ("CORE", 2) => "NT_FPREGSET",
+        ("CORE", 3) => "NT_PRPSINFO",
+        ("CORE", 4) => "NT_TASKSTRUCT",
+        ("CORE", 6) => "NT_AUXV",
+        ("CORE", 0x53494749) => "NT_SIGINFO",
+        ("CORE", 0x46494C45) => "NT_FILE",
+        ("LINUX", 0x46E62B7F) => "NT_PRXFPREG",
+        ("LINUX", 0x200) => "NT_386_TLS",
+        ("LINUX", 0x201) => "NT_386_IOPERM",
+        ("LINUX", 0x202) => "NT_X86_XSTATE",
+        ("LINUX", 0x204) => "NT_X86_SHSTK",
+        ("LINUX", 0x205) => "NT_X86_XSAVE_LAYOUT",
+        ("LINUX", 0x400) => "NT_ARM_VFP",
+        ("LINUX", 0x401) => "NT_ARM_TLS",
+        ("LINUX", 0x402) => "NT_ARM_HW_BREAK",
+        ("LINUX", 0x403) => "NT_ARM_HW_WATCH",
+        ("LINUX", 0x404) => "NT_ARM_SYSTEM_CALL",
+        ("LINUX", 0x405) => "NT_ARM_SVE",
+        ("LINUX", 0x406) => "NT_ARM_PAC_MASK",
+        ("LINUX", 0x409) => "NT_ARM_TAGGED_ADDR_CTRL",
+        ("LINUX", 0x900) => "NT_RISCV_CSR",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+const GNU_ABI_TAG_OS: [&str; 7] = [
+    "Linux", "Hurd", "Solaris", "FreeBSD", "NetBSD", "Syllable", "NaCl",
+];
+
+const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+const GNU_PROPERTY_1_NEEDED: u32 = 0xB0008000;
+const GNU_PROPERTY_LOPROC: u32 = 0xC0000000;
+const GNU_PROPERTY_HIPROC: u32 = 0xDFFFFFFF;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xC0000000;
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xC0000002;
+const GNU_PROPERTY_X86_FEATURE_2_NEEDED: u32 = 0xC0008001;
+const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xC0008002;
+const GNU_PROPERTY_X86_FEATURE_2_USED: u32 = 0xC0010001;
+const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xC0010002;
+
+const GNU_PROPERTY_1_NEEDED_BITS: [(u64, &str); 1] = [(0x1, "indirect external access")];
+
+const AARCH64_FEATURE_1_BITS: [(u64, &str); 3] = [(0x1, "BTI"), (0x2, "PAC"), (0x4, "GCS")];
+
+const X86_FEATURE_1_BITS: [(u64, &str); 4] = [
+    (0x1, "IBT"),
+    (0x2, "SHSTK"),
+    (0x4, "LAM_U48"),
+    (0x8, "LAM_U57"),
+];
+
+const X86_FEATURE_2_BITS: [(u64, &str); 12] = [
+    (0x1, "x86"),
+    (0x2, "x87"),
+    (0x4, "MMX"),
+    (0x8, "XMM"),
+    (0x10, "YMM"),
+    (0x20, "ZMM"),
+    (0x40, "FXSR"),
+    (0x80, "XSAVE"),
+    (0x100, "XSAVEOPT"),
+    (0x200, "XSAVEC"),
+    (0x400, "TMM"),
+    (0x800, "MASK"),
+];
+
+const X86_ISA_1_BITS: [(u64, &str); 4] = [
+    (0x1, "x86-64-baseline"),
+    (0x2, "x86-64-v2"),
+    (0x4, "x86-64-v3"),
+    (0x8, "x86-64-v4"),
+];
+
+const FREEBSD_FEATURE_CTL_BITS: [(u64, &str); 6] = [
+    (0x1, "ASLR_DISABLE"),
+    (0x2, "PROTMAX_DISABLE"),
+    (0x4, "STKGAP_DISABLE"),
+    (0x8, "WXNEEDED"),
+    (0x10, "LA48"),
+    (0x20, "ASG_DISABLE"),
+];
+
+// Names the bits set in `value` the way readelf lists them, see `flag_names`.
+fn bit_names(value: u32, names: &[(u64, &str)]) -> String {
+    if value == 0 {
+        return "<None>".to_string();
+    }
+    flag_names(value as u64, names, &[]).join(", ")
+}
+
+// Reads a NUL terminated (or descriptor sized) string out of a descriptor.
+fn desc_string(desc: &[u8]) -> String {
+    let end = desc
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(desc.len());
+    String::from_utf8_lossy(&desc[..end]).into_owned()
+}
+
+fn hex_bytes(desc: &[u8], separator: &str) -> String {
+    desc.iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+// Splits the pr_type/pr_datasz/pr_data array of a NT_GNU_PROPERTY_TYPE_0 note.
+// Each property is padded to 8 bytes in 64-bit files and to 4 in 32-bit ones.
+pub fn split_gnu_properties<'data>(
+    desc: &'data [u8],
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<Vec<(u32, &'data [u8])>, ElfError> {
+    let align = match platform {
+        ElfPlatformType::Bit32 => 4,
+        ElfPlatformType::Bit64 => 8,
+    };
+
+    let mut properties = Vec::new();
+    let mut reader = ElfReader::new(desc);
+    while desc.len() - reader.pointer() >= 8 {
+        let pr_type = reader.read_u32("pr_type", endian)?;
+        let pr_datasz = reader.read_u32("pr_datasz", endian)? as usize;
+        properties.push((pr_type, reader.read_slice("pr_data", pr_datasz)?));
+
+        let padding = (align - pr_datasz % align) % align;
+        if desc.len() - reader.pointer() < padding {
+            break;
+        }
+        reader.skip("pr_padding", padding)?;
+    }
+
+    Ok(properties)
+}
+
+fn parse_gnu_properties(
+    desc: &[u8],
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<String, ElfError> {
+    let is_x86 = matches!(
+        machine,
+        ElfInstructionSet::X86 | ElfInstructionSet::AmdX86_64
+    );
+    let is_aarch64 = matches!(machine, ElfInstructionSet::Arm64bit);
+
+    let mut properties = Vec::new();
+    for (pr_type, data) in split_gnu_properties(desc, endian, platform)? {
+        let mut data_reader = ElfReader::new(data);
+        let property = match pr_type {
+            GNU_PROPERTY_STACK_SIZE => format!(
+                "stack size: {:#x}",
+                data_reader.read_word("pr_data", endian, platform)?
+            ),
+            GNU_PROPERTY_NO_COPY_ON_PROTECTED => "no copy on protected".to_string(),
+            GNU_PROPERTY_1_NEEDED => format!(
+                "1_needed: {}",
+                bit_names(
+                    data_reader.read_u32("pr_data", endian)?,
+                    &GNU_PROPERTY_1_NEEDED_BITS
+                )
+            ),
+            GNU_PROPERTY_AARCH64_FEATURE_1_AND if is_aarch64 => format!(
+                "AArch64 feature: {}",
+                bit_names(
+                    data_reader.read_u32("pr_data", endian)?,
+                    &AARCH64_FEATURE_1_BITS
+                )
+            ),
+            GNU_PROPERTY_X86_FEATURE_1_AND if is_x86 => format!(
+                "x86 feature: {}",
+                bit_names(
+                    data_reader.read_u32("pr_data", endian)?,
+                    &X86_FEATURE_1_BITS
+                )
+            ),
+            GNU_PROPERTY_X86_FEATURE_2_NEEDED if is_x86 => format!(
+                "x86 feature needed: {}",
+                bit_names(
+                    data_reader.read_u32("pr_data", endian)?,
+                    &X86_FEATURE_2_BITS
+                )
+            ),
+            GNU_PROPERTY_X86_FEATURE_2_USED if is_x86 => format!(
+                "x86 feature used: {}",
+                bit_names(
+                    data_reader.read_u32("pr_data", endian)?,
+                    &X86_FEATURE_2_BITS
+                )
+            ),
+            GNU_PROPERTY_X86_ISA_1_NEEDED if is_x86 => format!(
+                "x86 ISA needed: {}",
+                bit_names(data_reader.read_u32("pr_data", endian)?, &X86_ISA_1_BITS)
+            ),
+            GNU_PROPERTY_X86_ISA_1_USED if is_x86 => format!(
+                "x86 ISA used: {}",
+                bit_names(data_reader.read_u32("pr_data", endian)?, &X86_ISA_1_BITS)
+            ),
+            GNU_PROPERTY_LOPROC..=GNU_PROPERTY_HIPROC => format!(
+                "<processor specific type {:#x} data: {}>",
+                pr_type,
+                hex_bytes(data, " ")
+            ),
+            _ => format!(
+                "<unknown type {:#x} data: {}>",
+                pr_type,
+                hex_bytes(data, " ")
+            ),
+        };
+        properties.push(property);
+    }
+
+    Ok(format!("Properties: {}", properties.join(", ")))
+}
+
+pub fn parse_note_description(
+    owner: &str,
+    n_type: u32,
+    desc: &[u8],
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<String, ElfError> {
+    let mut reader = ElfReader::new(desc);
+    let description = match (owner, n_type) {
+        ("GNU", 1) => {
+            let os = reader.read_u32("abi_tag_os", endian)?;
+            let major = reader.read_u32("abi_tag_major", endian)?;
+            let minor = reader.read_u32("abi_tag_minor", endian)?;
+            let subminor = reader.read_u32("abi_tag_subminor", endian)?;
+            let os = match GNU_ABI_TAG_OS.get(os as usize) {
+                Some(name) => name.to_string(),
+                None => format!("<unknown: {:#x}>", os),
+            };
+            format!("OS: {}, ABI: {}.{}.{}", os, major, minor, subminor)
+        }
+        ("GNU", 3) => format!("Build ID: {}", hex_bytes(desc, "")),
+        ("GNU", 4) => format!("Version: {}", desc_string(desc)),
+        ("GNU", 5) => parse_gnu_properties(desc, machine, endian, platform)?,
+        ("FDO", 0xCAFE1A7E) => format!("Packaging Metadata: {}", desc_string(desc)),
+        ("FDO", 0x407C0C0A) => format!("Dlopen Metadata: {}", desc_string(desc)),
+        ("Go", 4) => format!("Go build ID: {}", desc_string(desc)),
+        ("FreeBSD", 1) => format!("Version: {}", reader.read_u32("abi_tag", endian)?),
+        ("FreeBSD", 2) => String::new(),
+        ("FreeBSD", 3) => format!("Arch: {}", desc_string(desc)),
+        ("FreeBSD", 4) => format!(
+            "Features: {}",
+            bit_names(
+                reader.read_u32("feature_ctl", endian)?,
+                &FREEBSD_FEATURE_CTL_BITS
+            )
+        ),
+        _ => format!("description data: {}", hex_bytes(desc, " ")),
+    };
+
+    Ok(description)
+}
+
+fn align_up(value: usize, align: usize, offset: usize) -> Result<usize, ElfError> {
+    value
+        .checked_add(align - 1)
+        .map(|value| value & !(align - 1))
+        .ok_or(ElfError::Overflow {
+            field: "note padding",
+            offset,
+        })
+}
+
+// Walks the namesz/descsz/type records of a note section or segment. The name
+// and the descriptor are each padded to `align`, which is 8 for notes laid out
+// with 8 byte alignment (e.g. .note.gnu.property on 64-bit) and 4 otherwise.
+pub fn parse_note_table(
+    content: &[u8],
+    offset: usize,
+    size: usize,
+    align: usize,
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<Vec<ElfNote>, ElfError> {
+    let align = match align {
+        8 => 8,
+        _ => 4,
+    };
+    let table = slice_at(content, "note table", offset, size)?;
+    let end = offset + table.len();
+
+    // Reads are bounded by the end of the table, not the end of the file
+    let mut reader = ElfReader::at(&content[..end], offset);
+    let mut inner = Vec::new();
+    while end - reader.pointer() >= 12 {
+        let n_namesz = reader.read_u32("n_namesz", endian)? as usize;
+        let n_descsz = reader.read_u32("n_descsz", endian)? as usize;
+        let n_type = reader.read_u32("n_type", endian)?;
+        let name = reader.read_slice("n_name", n_namesz)?;
+        let desc_offset = offset + align_up(reader.pointer() - offset, align, reader.pointer())?;
+        reader.seek(desc_offset.min(end));
+        let note_data_offset = reader.pointer();
+        let desc = reader.read_slice("n_desc", n_descsz)?;
+        let next = offset + align_up(reader.pointer() - offset, align, reader.pointer())?;
+        reader.seek(next.min(end));
+
+        let owner = desc_string(name);
+        let description = parse_note_description(&owner, n_type, desc, machine, endian, platform)
+            .unwrap_or_else(|_| format!("<corrupt: {}>", hex_bytes(desc, " ")));
+        inner.push(ElfNote {
+            note_type: ElfNoteType {
+                value: n_type,
+                name: note_type_name(&owner, n_type),
+            },
+            note_owner: owner,
+            note_data_size: ElfNoteDataSize(n_descsz),
+            note_description: description,
+            note_data: desc.to_vec(),
+            note_data_offset,
+        });
+    }
+
+    Ok(inner)
+}
+
+// Uses the SHT_NOTE sections when there are section headers, like readelf does,
+// and falls back to the PT_NOTE segments otherwise.
+pub fn parse_note_tables(
+    content: &[u8],
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfNoteTables, ElfError> {
+    let sources = match section_header.inner.is_empty() {
+        false => section_header
+            .inner
+            .iter()
+            .filter(|entry| matches!(entry.section_header_type, ElfSectionHeaderType::ShtNote))
+            .map(|entry| {
+                (
+                    entry.section_name.0.clone(),
+                    entry.section_offset.0,
+                    entry.section_size.0,
+                    entry.section_addr_allign.0,
+                )
+            })
+            .collect::<Vec<_>>(),
+        true => program_header
+            .inner
+            .iter()
+            .filter(|entry| matches!(entry.segment_type, ElfSegmentType::PtNote))
+            .map(|entry| {
+                (
+                    String::new(),
+                    entry.segment_offset.0,
+                    entry.segment_file_size.0,
+                    entry.segment_allignment.0,
+                )
+            })
+            .collect::<Vec<_>>(),
+    };
+
+    let inner = sources
+        .into_iter()
+        .map(|(section_name, offset, size, align)| {
+            Ok(ElfNoteTable {
+                inner: parse_note_table(content, offset, size, align, machine, endian, platform)?,
+                section_name,
+                offset,
+                size,
+            })
+        })
+        .collect::<Result<Vec<ElfNoteTable>, ElfError>>()?;
+
+    Ok(ElfNoteTables { inner })
+}
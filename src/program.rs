@@ -0,0 +1,471 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfEndianness, ElfError, ElfHeader, ElfPlatformType, ElfProgramHeaderEntryCount,
+    ElfProgramHeaderEntrySize, ElfReader, check_entry_size, entry_offset, flag_names,
+    parse_section_header_entry, serialize_flags, serialize_named,
+};
+
+// This is an array of N (given in the `ElfHeader`) entries
+#[derive(Debug, Default)]
+pub struct ElfProgramHeader {
+    pub inner: Vec<ElfProgramHeaderEntry>,
+    // Bytes skipped after each entry when e_phentsize is larger than Elf*_Phdr
+    pub entry_padding: usize,
+}
+
+impl ElfProgramHeader {
+    pub fn inner(self) -> Vec<ElfProgramHeaderEntry> {
+        self.inner
+    }
+
+    // Translates a virtual address into a file offset through the PT_LOAD segment
+    // containing it. Addresses in the zero filled tail of a segment have no offset.
+    pub fn vaddr_to_offset(&self, vaddr: usize) -> Option<usize> {
+        self.inner
+            .iter()
+            .filter(|entry| matches!(entry.segment_type, ElfSegmentType::PtLoad))
+            .find(|entry| {
+                let start = entry.segment_vaddr.0;
+                vaddr >= start && vaddr - start < entry.segment_file_size.0
+            })
+            .and_then(|entry| {
+                entry
+                    .segment_offset
+                    .0
+                    .checked_add(vaddr - entry.segment_vaddr.0)
+            })
+    }
+}
+
+impl std::fmt::Display for ElfProgramHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Tabled, Serialize)]
+pub struct ElfProgramHeaderEntry {
+    pub segment_type: ElfSegmentType,
+    pub segment_flags: ElfSegmentFlags,
+    // Offset of the segment in the file image.
+    pub segment_offset: ElfSegmentOffset,
+    // Virtual address of the segment in memory.
+    pub segment_vaddr: ElfSegmentVAddr,
+    // On systems where physical address is relevant, reserved for segment's physical address.
+    pub segment_paddr: ElfSegmentPAddr,
+    pub segment_file_size: ElfSegmentFileSize,
+    // Size in bytes of the segment in memory. May be 0.
+    pub segment_memory_size: ElfSegmentMemorySize,
+    // 0 and 1 specify no alignment. Otherwise should be a positive, integral power of 2,
+    // with p_vaddr equating p_offset modulus p_align.
+    pub segment_allignment: ElfSegmentAllignment,
+}
+
+impl std::fmt::Display for ElfProgramHeaderEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.segment_type,
+            self.segment_flags,
+            self.segment_offset,
+            self.segment_vaddr,
+            self.segment_paddr,
+            self.segment_file_size,
+            self.segment_memory_size,
+            self.segment_allignment
+        );
+        write!(f, "{}", txt)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElfSegmentAllignment(pub usize);
+
+impl std::fmt::Display for ElfSegmentAllignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElfSegmentMemorySize(pub usize);
+
+impl std::fmt::Display for ElfSegmentMemorySize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElfSegmentFileSize(pub usize);
+
+impl std::fmt::Display for ElfSegmentFileSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElfSegmentPAddr(pub usize);
+
+impl std::fmt::Display for ElfSegmentPAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElfSegmentVAddr(pub usize);
+
+impl std::fmt::Display for ElfSegmentVAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElfSegmentOffset(pub usize);
+
+impl std::fmt::Display for ElfSegmentOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+// Raw p_flags, see `flag_names`
+#[derive(Debug, Default)]
+pub struct ElfSegmentFlags(pub u32);
+
+impl ElfSegmentFlags {
+    pub const PF_X: u32 = 0x1; // Executable segment
+    pub const PF_W: u32 = 0x2; // Writeable segment
+    pub const PF_R: u32 = 0x4; // readable segment
+    pub const PF_MASKOS: u32 = 0x0FF00000; // OS-specific
+    pub const PF_MASKPROC: u32 = 0xF0000000; // Processor-specific
+
+    pub fn contains(&self, flag: u32) -> bool {
+        self.0 & flag == flag
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        flag_names(
+            u64::from(self.0),
+            &[
+                (u64::from(Self::PF_R), "PF_R"),
+                (u64::from(Self::PF_W), "PF_W"),
+                (u64::from(Self::PF_X), "PF_X"),
+            ],
+            &[
+                (u64::from(Self::PF_MASKOS), "PF_MASKOS"),
+                (u64::from(Self::PF_MASKPROC), "PF_MASKPROC"),
+                (u64::MAX, "PF_UNKNOWN"),
+            ],
+        )
+    }
+}
+
+impl Serialize for ElfSegmentFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_flags(serializer, u64::from(self.0), self.names())
+    }
+}
+
+// Rendered the way readelf does, one column per permission: `R E`, `RW `.
+// Any other bits follow as hex.
+impl std::fmt::Display for ElfSegmentFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = [(Self::PF_R, 'R'), (Self::PF_W, 'W'), (Self::PF_X, 'E')]
+            .iter()
+            .map(|(bit, key)| if self.contains(*bit) { *key } else { ' ' })
+            .collect::<String>();
+        let other = self.0 & !(Self::PF_R | Self::PF_W | Self::PF_X);
+        match other {
+            0 => write!(f, "{}", txt),
+            other => write!(f, "{} {:#x}", txt, other),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ElfSegmentType {
+    PtNull,        //Program header table entry unused.
+    PtLoad,        //Loadable segment.
+    PtDynamic,     //Dynamic linking information.
+    PtInterp,      //Interpreter information.
+    PtNote,        //Auxiliary information.
+    PtShlib,       //Reserved.
+    PtPhdr,        //Segment containing program header table itself.
+    PtTls,         //Thread-Local Storage template.
+    PtLoos,        //Reserved inclusive range. Operating system specific.
+    PtHios,        //
+    PtLoproc,      //Reserved inclusive range. Processor specific.
+    PtHiproc,      //
+    PtGnuEhFrame,  //Location of .eh_frame_hdr (GNU).
+    PtGnuStack,    //Stack executability (GNU).
+    PtGnuRelro,    //Read-only after relocation (GNU).
+    PtGnuProperty, //Location of .note.gnu.property (GNU).
+    PtGnuSframe,   //Location of .sframe (GNU).
+    Other(u32),    //Any other value, named after the range it falls in.
+}
+
+impl std::fmt::Display for ElfSegmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfSegmentType::PtNull => "PT_NULL",
+            ElfSegmentType::PtLoad => "PT_LOAD",
+            ElfSegmentType::PtDynamic => "PT_DYNAMIC",
+            ElfSegmentType::PtInterp => "PT_INTERP",
+            ElfSegmentType::PtNote => "PT_NOTE",
+            ElfSegmentType::PtShlib => "PT_SHLIB",
+            ElfSegmentType::PtPhdr => "PT_PHDR",
+            ElfSegmentType::PtTls => "PT_TLS",
+            ElfSegmentType::PtLoos => "PT_LOOS",
+            ElfSegmentType::PtHios => "PT_HIOS",
+            ElfSegmentType::PtLoproc => "PT_LOPROC",
+            ElfSegmentType::PtHiproc => "PT_HIPROC",
+            ElfSegmentType::PtGnuEhFrame => "PT_GNU_EH_FRAME",
+            ElfSegmentType::PtGnuStack => "PT_GNU_STACK",
+            ElfSegmentType::PtGnuRelro => "PT_GNU_RELRO",
+            ElfSegmentType::PtGnuProperty => "PT_GNU_PROPERTY",
+            ElfSegmentType::PtGnuSframe => "PT_GNU_SFRAME",
+            ElfSegmentType::Other(value) => {
+                return match value {
+                    0x60000000..=0x6FFFFFFF => write!(f, "PT_LOOS+{:#x}", value - 0x60000000),
+                    0x70000000..=0x7FFFFFFF => write!(f, "PT_LOPROC+{:#x}", value - 0x70000000),
+                    _ => write!(f, "PT_<{:#x}>", value),
+                };
+            }
+        };
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfSegmentType {
+    // Raw p_type value
+    pub fn value(&self) -> u32 {
+        match self {
+            ElfSegmentType::PtNull => 0x00000000,
+            ElfSegmentType::PtLoad => 0x00000001,
+            ElfSegmentType::PtDynamic => 0x00000002,
+            ElfSegmentType::PtInterp => 0x00000003,
+            ElfSegmentType::PtNote => 0x00000004,
+            ElfSegmentType::PtShlib => 0x00000005,
+            ElfSegmentType::PtPhdr => 0x00000006,
+            ElfSegmentType::PtTls => 0x00000007,
+            ElfSegmentType::PtLoos => 0x60000000,
+            ElfSegmentType::PtHios => 0x6FFFFFFF,
+            ElfSegmentType::PtLoproc => 0x70000000,
+            ElfSegmentType::PtHiproc => 0x7FFFFFFF,
+            ElfSegmentType::PtGnuEhFrame => 0x6474E550,
+            ElfSegmentType::PtGnuStack => 0x6474E551,
+            ElfSegmentType::PtGnuRelro => 0x6474E552,
+            ElfSegmentType::PtGnuProperty => 0x6474E553,
+            ElfSegmentType::PtGnuSframe => 0x6474E554,
+            ElfSegmentType::Other(value) => *value,
+        }
+    }
+}
+
+impl Serialize for ElfSegmentType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+pub fn parse_segment_allignment(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSegmentAllignment, ElfError> {
+    let value = reader.read_word("p_align", endian, platform)?;
+
+    Ok(ElfSegmentAllignment(value))
+}
+
+pub fn parse_segment_memory_size(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSegmentMemorySize, ElfError> {
+    let value = reader.read_word("p_memsz", endian, platform)?;
+
+    Ok(ElfSegmentMemorySize(value))
+}
+
+pub fn parse_segment_file_size(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSegmentFileSize, ElfError> {
+    let value = reader.read_word("p_filesz", endian, platform)?;
+
+    Ok(ElfSegmentFileSize(value))
+}
+
+pub fn parse_segment_paddr(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSegmentPAddr, ElfError> {
+    let value = reader.read_word("p_paddr", endian, platform)?;
+
+    Ok(ElfSegmentPAddr(value))
+}
+
+pub fn parse_segment_vaddr(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSegmentVAddr, ElfError> {
+    let value = reader.read_word("p_vaddr", endian, platform)?;
+
+    Ok(ElfSegmentVAddr(value))
+}
+
+pub fn parse_segment_offset(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSegmentOffset, ElfError> {
+    let value = reader.read_word("p_offset", endian, platform)?;
+
+    Ok(ElfSegmentOffset(value))
+}
+
+pub fn parse_segment_flags(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSegmentFlags, ElfError> {
+    let flags = reader.read_u32("p_flags", endian)?;
+
+    Ok(ElfSegmentFlags(flags))
+}
+
+pub fn parse_segment_type(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSegmentType, ElfError> {
+    let p_type = reader.read_u32("p_type", endian)?;
+    let p_type = match p_type {
+        0x00000000 => ElfSegmentType::PtNull,
+        0x00000001 => ElfSegmentType::PtLoad,
+        0x00000002 => ElfSegmentType::PtDynamic,
+        0x00000003 => ElfSegmentType::PtInterp,
+        0x00000004 => ElfSegmentType::PtNote,
+        0x00000005 => ElfSegmentType::PtShlib,
+        0x00000006 => ElfSegmentType::PtPhdr,
+        0x00000007 => ElfSegmentType::PtTls,
+        0x60000000 => ElfSegmentType::PtLoos,
+        0x6FFFFFFF => ElfSegmentType::PtHios,
+        0x70000000 => ElfSegmentType::PtLoproc,
+        0x7FFFFFFF => ElfSegmentType::PtHiproc,
+        0x6474E550 => ElfSegmentType::PtGnuEhFrame,
+        0x6474E551 => ElfSegmentType::PtGnuStack,
+        0x6474E552 => ElfSegmentType::PtGnuRelro,
+        0x6474E553 => ElfSegmentType::PtGnuProperty,
+        0x6474E554 => ElfSegmentType::PtGnuSframe,
+        other => ElfSegmentType::Other(other),
+    };
+
+    Ok(p_type)
+}
+
+pub fn parse_program_header_entry(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfProgramHeaderEntry, ElfError> {
+    let segment_type = parse_segment_type(reader, endian)?;
+    let mut segment_flags = ElfSegmentFlags::default();
+
+    if let ElfPlatformType::Bit64 = platform {
+        segment_flags = parse_segment_flags(reader, endian)?;
+    }
+    let segment_offset = parse_segment_offset(reader, endian, platform)?;
+    let segment_vaddr = parse_segment_vaddr(reader, endian, platform)?;
+    let segment_paddr = parse_segment_paddr(reader, endian, platform)?;
+    let segment_file_size = parse_segment_file_size(reader, endian, platform)?;
+    let segment_memory_size = parse_segment_memory_size(reader, endian, platform)?;
+
+    // These flags appear in a diffent offset depending on the target platform type
+    // for allignment reasons
+    if let ElfPlatformType::Bit32 = platform {
+        segment_flags = parse_segment_flags(reader, endian)?;
+    }
+    let segment_allignment = parse_segment_allignment(reader, endian, platform)?;
+
+    Ok(ElfProgramHeaderEntry {
+        segment_type,
+        segment_flags,
+        segment_offset,
+        segment_vaddr,
+        segment_paddr,
+        segment_file_size,
+        segment_memory_size,
+        segment_allignment,
+    })
+}
+
+// The number of program headers. When e_phnum is PN_XNUM the real count did not
+// fit in 16 bits and is stored in the sh_info of section header 0.
+pub fn parse_program_header_count(content: &[u8], header: &ElfHeader) -> Result<usize, ElfError> {
+    if !header.program_header_entry_count.is_extended() {
+        return Ok(header.program_header_entry_count.inner() as usize);
+    }
+
+    let offset = header.section_header_offset.0;
+    if offset == 0 {
+        return Err(ElfError::InvalidValue {
+            field: "e_phnum",
+            offset: match header.platform_type {
+                ElfPlatformType::Bit32 => 0x2C,
+                ElfPlatformType::Bit64 => 0x38,
+            },
+            expected: "a section header 0 holding the PN_XNUM count",
+            actual: ElfProgramHeaderEntryCount::PN_XNUM as u64,
+        });
+    }
+    let mut reader = ElfReader::at(content, offset);
+    let first = parse_section_header_entry(&mut reader, &header.endianness, &header.platform_type)?;
+
+    Ok(first.section_info.0 as usize)
+}
+
+pub fn parse_program_header(
+    reader: &mut ElfReader<'_>,
+    entry_count: usize,
+    entry_size: &ElfProgramHeaderEntrySize,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfProgramHeader, ElfError> {
+    if entry_count == 0 {
+        return Ok(ElfProgramHeader::default());
+    }
+
+    // Elf32_Phdr and Elf64_Phdr, and where e_phentsize sits in the file header
+    let (minimum_entry_size, field_offset) = match platform {
+        ElfPlatformType::Bit32 => (32, 0x2A),
+        ElfPlatformType::Bit64 => (56, 0x36),
+    };
+    let entry_size = check_entry_size(
+        "e_phentsize",
+        field_offset,
+        entry_size.0,
+        minimum_entry_size,
+    )?;
+
+    let start = reader.pointer();
+    let mut inner = Vec::with_capacity(reader.capacity_for(entry_count, entry_size));
+    for index in 0..entry_count {
+        reader.seek(entry_offset("program header", start, index, entry_size)?);
+        let entry = parse_program_header_entry(reader, endian, platform)?;
+        inner.push(entry);
+    }
+
+    Ok(ElfProgramHeader {
+        inner,
+        entry_padding: entry_size - minimum_entry_size,
+    })
+}
@@ -0,0 +1,168 @@
+use crate::{ElfEndianness, ElfError, ElfPlatformType};
+
+// A cursor over the raw file content. Every read is bounds checked so that
+// truncated or hostile files surface as an `ElfError` instead of a panic.
+#[derive(Debug, Clone)]
+pub struct ElfReader<'data> {
+    content: &'data [u8],
+    pointer: usize,
+}
+
+impl<'data> ElfReader<'data> {
+    pub fn new(content: &'data [u8]) -> Self {
+        ElfReader {
+            content,
+            pointer: 0,
+        }
+    }
+
+    pub fn at(content: &'data [u8], offset: usize) -> Self {
+        ElfReader {
+            content,
+            pointer: offset,
+        }
+    }
+
+    pub fn content(&self) -> &'data [u8] {
+        self.content
+    }
+
+    pub fn pointer(&self) -> usize {
+        self.pointer
+    }
+
+    // Capacity to reserve for `count` entries of `entry_size` bytes read from
+    // here on. Counts come from the file, so a hostile one must not drive the
+    // allocation past what the remaining content can hold.
+    pub fn capacity_for(&self, count: usize, entry_size: usize) -> usize {
+        let remaining = self.content.len().saturating_sub(self.pointer);
+        count.min(remaining / entry_size.max(1))
+    }
+
+    pub fn seek(&mut self, offset: usize) {
+        self.pointer = offset;
+    }
+
+    pub fn skip(&mut self, field: &'static str, count: usize) -> Result<(), ElfError> {
+        self.read_slice(field, count)?;
+        Ok(())
+    }
+
+    pub fn read_slice(&mut self, field: &'static str, len: usize) -> Result<&'data [u8], ElfError> {
+        let bytes = slice_at(self.content, field, self.pointer, len)?;
+        self.pointer += len;
+        Ok(bytes)
+    }
+
+    pub fn read_bytes<const N: usize>(&mut self, field: &'static str) -> Result<[u8; N], ElfError> {
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(self.read_slice(field, N)?);
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self, field: &'static str) -> Result<u8, ElfError> {
+        let [byte] = self.read_bytes::<1>(field)?;
+        Ok(byte)
+    }
+
+    pub fn read_u16(
+        &mut self,
+        field: &'static str,
+        endian: &ElfEndianness,
+    ) -> Result<u16, ElfError> {
+        let bytes = self.read_bytes::<2>(field)?;
+        Ok(endian.u16_from(&bytes))
+    }
+
+    pub fn read_u32(
+        &mut self,
+        field: &'static str,
+        endian: &ElfEndianness,
+    ) -> Result<u32, ElfError> {
+        let bytes = self.read_bytes::<4>(field)?;
+        Ok(endian.u32_from(&bytes))
+    }
+
+    pub fn read_u64(
+        &mut self,
+        field: &'static str,
+        endian: &ElfEndianness,
+    ) -> Result<u64, ElfError> {
+        let bytes = self.read_bytes::<8>(field)?;
+        Ok(endian.u64_from(&bytes))
+    }
+
+    // Reads a 4 byte word for 32-bit files and an 8 byte word for 64-bit files.
+    pub fn read_word(
+        &mut self,
+        field: &'static str,
+        endian: &ElfEndianness,
+        platform: &ElfPlatformType,
+    ) -> Result<usize, ElfError> {
+        let offset = self.pointer;
+        let word = match platform {
+            ElfPlatformType::Bit32 => self.read_u32(field, endian)? as u64,
+            ElfPlatformType::Bit64 => self.read_u64(field, endian)?,
+        };
+
+        usize::try_from(word).map_err(|_| ElfError::Overflow { field, offset })
+    }
+}
+
+// Borrows `size` bytes starting at `offset`, failing instead of panicking when the
+// range overflows or runs past the end of `content`.
+pub fn slice_at<'data>(
+    content: &'data [u8],
+    field: &'static str,
+    offset: usize,
+    size: usize,
+) -> Result<&'data [u8], ElfError> {
+    let end = offset
+        .checked_add(size)
+        .ok_or(ElfError::Overflow { field, offset })?;
+
+    content.get(offset..end).ok_or(ElfError::Truncated {
+        field,
+        offset,
+        expected: size,
+        actual: content.len().saturating_sub(offset),
+    })
+}
+
+// Validates a declared table entry size (e_phentsize, e_shentsize) against the
+// size of the structure for the file class. Larger entries are padded, walkers
+// step by the declared size and skip the tail. Returns the declared size.
+pub(crate) fn check_entry_size(
+    field: &'static str,
+    offset: usize,
+    declared: u16,
+    expected: usize,
+) -> Result<usize, ElfError> {
+    let declared = declared as usize;
+    if declared < expected {
+        return Err(ElfError::InvalidValue {
+            field,
+            offset,
+            expected: "at least the entry size of the file class",
+            actual: declared as u64,
+        });
+    }
+
+    Ok(declared)
+}
+
+// Offset of entry `index` in a table starting at `start`
+pub(crate) fn entry_offset(
+    field: &'static str,
+    start: usize,
+    index: usize,
+    entry_size: usize,
+) -> Result<usize, ElfError> {
+    index
+        .checked_mul(entry_size)
+        .and_then(|offset| offset.checked_add(start))
+        .ok_or(ElfError::Overflow {
+            field,
+            offset: start,
+        })
+}
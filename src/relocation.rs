@@ -0,0 +1,630 @@
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    ElfEndianness, ElfError, ElfInstructionSet, ElfPlatformType, ElfReader, ElfSectionHeader,
+    ElfSectionHeaderEntry, ElfSectionHeaderType, ElfSymbolName, ElfSymbolType, ElfSymbolValue,
+    parse_symbol_table, slice_at,
+};
+
+// All the relocation sections (SHT_REL and SHT_RELA) found in a file
+#[derive(Debug, Default, Serialize)]
+pub struct ElfRelocationTables {
+    pub inner: Vec<ElfRelocationTable>,
+}
+
+impl ElfRelocationTables {
+    pub fn inner(self) -> Vec<ElfRelocationTable> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfRelocationTables {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfRelocationTable {
+    // Name of the section holding the relocations, e.g. `.rela.text`
+    pub section_name: String,
+    // Offset of the relocation section in the file image
+    pub section_offset: usize,
+    // Section the relocations are applied to, taken from `section_info`.
+    // Empty for dynamic relocations, which apply to the whole image.
+    pub applies_to: String,
+    // Symbol table the entries refer to, taken from `section_link`.
+    pub symbol_table: String,
+    pub inner: Vec<ElfRelocation>,
+}
+
+impl std::fmt::Display for ElfRelocationTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{:?}", self.section_name, self.inner)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfRelocation {
+    // Location to apply the relocation to. A section offset for relocatable
+    // files and a virtual address for executables and shared objects.
+    pub relocation_offset: ElfRelocationOffset,
+    // Raw r_info, holding both the symbol index and the relocation type.
+    pub relocation_info: ElfRelocationInfo,
+    pub relocation_type: ElfRelocationType,
+    pub symbol_index: usize,
+    pub symbol_value: ElfSymbolValue,
+    pub symbol_name: ElfSymbolName,
+    // Only present in SHT_RELA entries.
+    pub relocation_addend: ElfRelocationAddend,
+}
+
+impl std::fmt::Display for ElfRelocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.relocation_offset,
+            self.relocation_info,
+            self.relocation_type,
+            self.symbol_index,
+            self.symbol_value,
+            self.symbol_name,
+            self.relocation_addend
+        );
+        write!(f, "{}", txt)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfRelocationOffset(pub usize);
+
+impl std::fmt::Display for ElfRelocationOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfRelocationInfo(pub u64);
+
+impl std::fmt::Display for ElfRelocationInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfRelocationAddend(pub Option<i64>);
+
+impl std::fmt::Display for ElfRelocationAddend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(addend) if addend < 0 => write!(f, "-{:X}", addend.unsigned_abs()),
+            Some(addend) => write!(f, "+{:X}", addend),
+            None => write!(f, ""),
+        }
+    }
+}
+
+// Relocation types are processor specific, so the raw value is kept next to
+// the symbolic name resolved for the file's `ElfInstructionSet`.
+#[derive(Debug, Default, Serialize)]
+pub struct ElfRelocationType {
+    pub value: u32,
+    pub name: Option<&'static str>,
+}
+
+impl std::fmt::Display for ElfRelocationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "R_<{:#x}>", self.value),
+        }
+    }
+}
+
+pub fn relocation_type_name(machine: &ElfInstructionSet, r_type: u32) -> Option<&'static str> {
+    match machine {
+        ElfInstructionSet::AmdX86_64 => x86_64_relocation_name(r_type),
+        ElfInstructionSet::X86 => i386_relocation_name(r_type),
+        ElfInstructionSet::Arm64bit => aarch64_relocation_name(r_type),
+        ElfInstructionSet::Arm => arm_relocation_name(r_type),
+        ElfInstructionSet::RiscV => riscv_relocation_name(r_type),
+        _ => None,
+    }
+}
+
+fn x86_64_relocation_name(r_type: u32) -> Option<&'static str> {
+    let name = match r_type {
+        0 => "R_X86_64_NONE",
+        1 => "R_X86_64_64",
+        2 => "R_X86_64_PC32",
+        3 => "R_X86_64_GOT32",
+        4 => "R_X86_64_PLT32",
+        5 => "R_X86_64_COPY",
+        6 => "R_X86_64_GLOB_DAT",
+        7 => "R_X86_64_JUMP_SLOT",
+        8 => "R_X86_64_RELATIVE",
+        9 => "R_X86_64_GOTPCREL",
+        10 => "R_X86_64_32",
+        11 => "R_X86_64_32S",
+        12 => "R_X86_64_16",
+        13 => "R_X86_64_PC16",
+        14 => "R_X86_64_8",
+        15 => "R_X86_64_PC8",
+        16 => "R_X86_64_DTPMOD64",
+        17 => "R_X86_64_DTPOFF64",
+        18 => "R_X86_64_TPOFF64",
+        19 => "R_X86_64_TLSGD",
+        20 => "R_X86_64_TLSLD",
+        21 => "R_X86_64_DTPOFF32",
+        22 => "R_X86_64_GOTTPOFF",
+        23 => "R_X86_64_TPOFF32",
+        24 => "R_X86_64_PC64",
+        25 => "R_X86_64_GOTOFF64",
+        26 => "R_X86_64_GOTPC32",
+        27 => "R_X86_64_GOT64",
+        28 => "R_X86_64_GOTPCREL64",
+        29 => "R_X86_64_GOTPC64",
+        30 => "R_X86_64_GOTPLT64",
+        31 => "R_X86_64_PLTOFF64",
+        32 => "R_X86_64_SIZE32",
+        33 => "R_X86_64_SIZE64",
+        34 => "R_X86_64_GOTPC32_TLSDESC",
+        35 => "R_X86_64_TLSDESC_CALL",
+        36 => "R_X86_64_TLSDESC",
+        37 => "R_X86_64_IRELATIVE",
+        38 => "R_X86_64_RELATIVE64",
+        41 => "R_X86_64_GOTPCRELX",
+        42 => "R_X86_64_REX_GOTPCRELX",
+        43 => "R_X86_64_CODE_4_GOTPCRELX",
+        44 => "R_X86_64_CODE_4_GOTTPOFF",
+        45 => "R_X86_64_CODE_4_GOTPC32_TLSDESC",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+fn i386_relocation_name(r_type: u32) -> Option<&'static str> {
+    let name = match r_type {
+        0 => "R_386_NONE",
+        1 => "R_386_32",
+        2 => "R_386_PC32",
+        3 => "R_386_GOT32",
+        4 => "R_386_PLT32",
+        5 => "R_386_COPY",
+        6 => "R_386_GLOB_DAT",
+        7 => "R_386_JUMP_SLOT",
+        8 => "R_386_RELATIVE",
+        9 => "R_386_GOTOFF",
+        10 => "R_386_GOTPC",
+        11 => "R_386_32PLT",
+        14 => "R_386_TLS_TPOFF",
+        15 => "R_386_TLS_IE",
+        16 => "R_386_TLS_GOTIE",
+        17 => "R_386_TLS_LE",
+        18 => "R_386_TLS_GD",
+        19 => "R_386_TLS_LDM",
+        20 => "R_386_16",
+        21 => "R_386_PC16",
+        22 => "R_386_8",
+        23 => "R_386_PC8",
+        24 => "R_386_TLS_GD_32",
+        25 => "R_386_TLS_GD_PUSH",
+        26 => "R_386_TLS_GD_CALL",
+        27 => "R_386_TLS_GD_POP",
+        28 => "R_386_TLS_LDM_32",
+        29 => "R_386_TLS_LDM_PUSH",
+        30 => "R_386_TLS_LDM_CALL",
+        31 => "R_386_TLS_LDM_POP",
+        32 => "R_386_TLS_LDO_32",
+        33 => "R_386_TLS_IE_32",
+        34 => "R_386_TLS_LE_32",
+        35 => "R_386_TLS_DTPMOD32",
+        36 => "R_386_TLS_DTPOFF32",
+        37 => "R_386_TLS_TPOFF32",
+        38 => "R_386_SIZE32",
+        39 => "R_386_TLS_GOTDESC",
+        40 => "R_386_TLS_DESC_CALL",
+        41 => "R_386_TLS_DESC",
+        42 => "R_386_IRELATIVE",
+        43 => "R_386_GOT32X",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+fn aarch64_relocation_name(r_type: u32) -> Option<&'static str> {
+    let name = match r_type {
+        0 => "R_AARCH64_NONE",
+        257 => "R_AARCH64_ABS64",
+        258 => "R_AARCH64_ABS32",
+        259 => "R_AARCH64_ABS16",
+        260 => "R_AARCH64_PREL64",
+        261 => "R_AARCH64_PREL32",
+        262 => "R_AARCH64_PREL16",
+        263 => "R_AARCH64_MOVW_UABS_G0",
+        264 => "R_AARCH64_MOVW_UABS_G0_NC",
+        265 => "R_AARCH64_MOVW_UABS_G1",
+        266 => "R_AARCH64_MOVW_UABS_G1_NC",
+        267 => "R_AARCH64_MOVW_UABS_G2",
+        268 => "R_AARCH64_MOVW_UABS_G2_NC",
+        269 => "R_AARCH64_MOVW_UABS_G3",
+        270 => "R_AARCH64_MOVW_SABS_G0",
+        271 => "R_AARCH64_MOVW_SABS_G1",
+        272 => "R_AARCH64_MOVW_SABS_G2",
+        273 => "R_AARCH64_LD_PREL_LO19",
+        274 => "R_AARCH64_ADR_PREL_LO21",
+        275 => "R_AARCH64_ADR_PREL_PG_HI21",
+        276 => "R_AARCH64_ADR_PREL_PG_HI21_NC",
+        277 => "R_AARCH64_ADD_ABS_LO12_NC",
+        278 => "R_AARCH64_LDST8_ABS_LO12_NC",
+        279 => "R_AARCH64_TSTBR14",
+        280 => "R_AARCH64_CONDBR19",
+        282 => "R_AARCH64_JUMP26",
+        283 => "R_AARCH64_CALL26",
+        284 => "R_AARCH64_LDST16_ABS_LO12_NC",
+        285 => "R_AARCH64_LDST32_ABS_LO12_NC",
+        286 => "R_AARCH64_LDST64_ABS_LO12_NC",
+        287 => "R_AARCH64_MOVW_PREL_G0",
+        288 => "R_AARCH64_MOVW_PREL_G0_NC",
+        289 => "R_AARCH64_MOVW_PREL_G1",
+        290 => "R_AARCH64_MOVW_PREL_G1_NC",
+        291 => "R_AARCH64_MOVW_PREL_G2",
+        292 => "R_AARCH64_MOVW_PREL_G2_NC",
+        293 => "R_AARCH64_MOVW_PREL_G3",
+        299 => "R_AARCH64_LDST128_ABS_LO12_NC",
+        300 => "R_AARCH64_MOVW_GOTOFF_G0",
+        301 => "R_AARCH64_MOVW_GOTOFF_G0_NC",
+        302 => "R_AARCH64_MOVW_GOTOFF_G1",
+        303 => "R_AARCH64_MOVW_GOTOFF_G1_NC",
+        304 => "R_AARCH64_MOVW_GOTOFF_G2",
+        305 => "R_AARCH64_MOVW_GOTOFF_G2_NC",
+        306 => "R_AARCH64_MOVW_GOTOFF_G3",
+        307 => "R_AARCH64_GOTREL64",
+        308 => "R_AARCH64_GOTREL32",
+        309 => "R_AARCH64_GOT_LD_PREL19",
+        310 => "R_AARCH64_LD64_GOTOFF_LO15",
+        311 => "R_AARCH64_ADR_GOT_PAGE",
+        312 => "R_AARCH64_LD64_GOT_LO12_NC",
+        313 => "R_AARCH64_LD64_GOTPAGE_LO15",
+        512 => "R_AARCH64_TLSGD_ADR_PREL21",
+        513 => "R_AARCH64_TLSGD_ADR_PAGE21",
+        514 => "R_AARCH64_TLSGD_ADD_LO12_NC",
+        515 => "R_AARCH64_TLSGD_MOVW_G1",
+        516 => "R_AARCH64_TLSGD_MOVW_G0_NC",
+        517 => "R_AARCH64_TLSLD_ADR_PREL21",
+        518 => "R_AARCH64_TLSLD_ADR_PAGE21",
+        519 => "R_AARCH64_TLSLD_ADD_LO12_NC",
+        539 => "R_AARCH64_TLSIE_MOVW_GOTTPREL_G1",
+        540 => "R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC",
+        541 => "R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21",
+        542 => "R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC",
+        543 => "R_AARCH64_TLSIE_LD_GOTTPREL_PREL19",
+        544 => "R_AARCH64_TLSLE_MOVW_TPREL_G2",
+        545 => "R_AARCH64_TLSLE_MOVW_TPREL_G1",
+        546 => "R_AARCH64_TLSLE_MOVW_TPREL_G1_NC",
+        547 => "R_AARCH64_TLSLE_MOVW_TPREL_G0",
+        548 => "R_AARCH64_TLSLE_MOVW_TPREL_G0_NC",
+        549 => "R_AARCH64_TLSLE_ADD_TPREL_HI12",
+        550 => "R_AARCH64_TLSLE_ADD_TPREL_LO12",
+        551 => "R_AARCH64_TLSLE_ADD_TPREL_LO12_NC",
+        552 => "R_AARCH64_TLSLE_LDST8_TPREL_LO12",
+        553 => "R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC",
+        554 => "R_AARCH64_TLSLE_LDST16_TPREL_LO12",
+        555 => "R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC",
+        556 => "R_AARCH64_TLSLE_LDST32_TPREL_LO12",
+        557 => "R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC",
+        558 => "R_AARCH64_TLSLE_LDST64_TPREL_LO12",
+        559 => "R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC",
+        560 => "R_AARCH64_TLSDESC_LD_PREL19",
+        561 => "R_AARCH64_TLSDESC_ADR_PREL21",
+        562 => "R_AARCH64_TLSDESC_ADR_PAGE21",
+        563 => "R_AARCH64_TLSDESC_LD64_LO12",
+        564 => "R_AARCH64_TLSDESC_ADD_LO12",
+        565 => "R_AARCH64_TLSDESC_OFF_G1",
+        566 => "R_AARCH64_TLSDESC_OFF_G0_NC",
+        567 => "R_AARCH64_TLSDESC_LDR",
+        568 => "R_AARCH64_TLSDESC_ADD",
+        569 => "R_AARCH64_TLSDESC_CALL",
+        1024 => "R_AARCH64_COPY",
+        1025 => "R_AARCH64_GLOB_DAT",
+        1026 => "R_AARCH64_JUMP_SLOT",
+        1027 => "R_AARCH64_RELATIVE",
+        1028 => "R_AARCH64_TLS_DTPMOD",
+        1029 => "R_AARCH64_TLS_DTPREL",
+        1030 => "R_AARCH64_TLS_TPREL",
+        1031 => "R_AARCH64_TLSDESC",
+        1032 => "R_AARCH64_IRELATIVE",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+fn arm_relocation_name(r_type: u32) -> Option<&'static str> {
+    let name = match r_type {
+        0 => "R_ARM_NONE",
+        1 => "R_ARM_PC24",
+        2 => "R_ARM_ABS32",
+        3 => "R_ARM_REL32",
+        4 => "R_ARM_LDR_PC_G0",
+        5 => "R_ARM_ABS16",
+        6 => "R_ARM_ABS12",
+        7 => "R_ARM_THM_ABS5",
+        8 => "R_ARM_ABS8",
+        9 => "R_ARM_SBREL32",
+        10 => "R_ARM_THM_CALL",
+        11 => "R_ARM_THM_PC8",
+        12 => "R_ARM_BREL_ADJ",
+        13 => "R_ARM_TLS_DESC",
+        14 => "R_ARM_THM_SWI8",
+        15 => "R_ARM_XPC25",
+        16 => "R_ARM_THM_XPC22",
+        17 => "R_ARM_TLS_DTPMOD32",
+        18 => "R_ARM_TLS_DTPOFF32",
+        19 => "R_ARM_TLS_TPOFF32",
+        20 => "R_ARM_COPY",
+        21 => "R_ARM_GLOB_DAT",
+        22 => "R_ARM_JUMP_SLOT",
+        23 => "R_ARM_RELATIVE",
+        24 => "R_ARM_GOTOFF32",
+        25 => "R_ARM_BASE_PREL",
+        26 => "R_ARM_GOT_BREL",
+        27 => "R_ARM_PLT32",
+        28 => "R_ARM_CALL",
+        29 => "R_ARM_JUMP24",
+        30 => "R_ARM_THM_JUMP24",
+        31 => "R_ARM_BASE_ABS",
+        38 => "R_ARM_TARGET1",
+        40 => "R_ARM_V4BX",
+        41 => "R_ARM_TARGET2",
+        42 => "R_ARM_PREL31",
+        43 => "R_ARM_MOVW_ABS_NC",
+        44 => "R_ARM_MOVT_ABS",
+        45 => "R_ARM_MOVW_PREL_NC",
+        46 => "R_ARM_MOVT_PREL",
+        47 => "R_ARM_THM_MOVW_ABS_NC",
+        48 => "R_ARM_THM_MOVT_ABS",
+        49 => "R_ARM_THM_MOVW_PREL_NC",
+        50 => "R_ARM_THM_MOVT_PREL",
+        51 => "R_ARM_THM_JUMP19",
+        96 => "R_ARM_GOT_PREL",
+        102 => "R_ARM_THM_JUMP11",
+        103 => "R_ARM_THM_JUMP8",
+        104 => "R_ARM_TLS_GD32",
+        105 => "R_ARM_TLS_LDM32",
+        106 => "R_ARM_TLS_LDO32",
+        107 => "R_ARM_TLS_IE32",
+        108 => "R_ARM_TLS_LE32",
+        160 => "R_ARM_IRELATIVE",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+fn riscv_relocation_name(r_type: u32) -> Option<&'static str> {
+    let name = match r_type {
+        0 => "R_RISCV_NONE",
+        1 => "R_RISCV_32",
+        2 => "R_RISCV_64",
+        3 => "R_RISCV_RELATIVE",
+        4 => "R_RISCV_COPY",
+        5 => "R_RISCV_JUMP_SLOT",
+        6 => "R_RISCV_TLS_DTPMOD32",
+        7 => "R_RISCV_TLS_DTPMOD64",
+        8 => "R_RISCV_TLS_DTPREL32",
+        9 => "R_RISCV_TLS_DTPREL64",
+        10 => "R_RISCV_TLS_TPREL32",
+        11 => "R_RISCV_TLS_TPREL64",
+        12 => "R_RISCV_TLSDESC",
+        16 => "R_RISCV_BRANCH",
+        17 => "R_RISCV_JAL",
+        18 => "R_RISCV_CALL",
+        19 => "R_RISCV_CALL_PLT",
+        20 => "R_RISCV_GOT_HI20",
+        21 => "R_RISCV_TLS_GOT_HI20",
+        22 => "R_RISCV_TLS_GD_HI20",
+        23 => "R_RISCV_PCREL_HI20",
+        24 => "R_RISCV_PCREL_LO12_I",
+        25 => "R_RISCV_PCREL_LO12_S",
+        26 => "R_RISCV_HI20",
+        27 => "R_RISCV_LO12_I",
+        28 => "R_RISCV_LO12_S",
+        29 => "R_RISCV_TPREL_HI20",
+        30 => "R_RISCV_TPREL_LO12_I",
+        31 => "R_RISCV_TPREL_LO12_S",
+        32 => "R_RISCV_TPREL_ADD",
+        33 => "R_RISCV_ADD8",
+        34 => "R_RISCV_ADD16",
+        35 => "R_RISCV_ADD32",
+        36 => "R_RISCV_ADD64",
+        37 => "R_RISCV_SUB8",
+        38 => "R_RISCV_SUB16",
+        39 => "R_RISCV_SUB32",
+        40 => "R_RISCV_SUB64",
+        41 => "R_RISCV_GOT32_PCREL",
+        43 => "R_RISCV_ALIGN",
+        44 => "R_RISCV_RVC_BRANCH",
+        45 => "R_RISCV_RVC_JUMP",
+        51 => "R_RISCV_RELAX",
+        52 => "R_RISCV_SUB6",
+        53 => "R_RISCV_SET6",
+        54 => "R_RISCV_SET8",
+        55 => "R_RISCV_SET16",
+        56 => "R_RISCV_SET32",
+        57 => "R_RISCV_32_PCREL",
+        58 => "R_RISCV_IRELATIVE",
+        59 => "R_RISCV_PLT32",
+        60 => "R_RISCV_SET_ULEB128",
+        61 => "R_RISCV_SUB_ULEB128",
+        62 => "R_RISCV_TLSDESC_HI20",
+        63 => "R_RISCV_TLSDESC_LOAD_LO12",
+        64 => "R_RISCV_TLSDESC_ADD_LO12",
+        65 => "R_RISCV_TLSDESC_CALL",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// Elf32_Rel(a) packs the symbol index in the upper 24 bits of r_info and the
+// type in the lower 8; Elf64_Rel(a) splits r_info into two 32-bit halves.
+pub fn parse_relocation(
+    reader: &mut ElfReader<'_>,
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+    with_addend: bool,
+) -> Result<ElfRelocation, ElfError> {
+    let offset = reader.read_word("r_offset", endian, platform)?;
+    let (info, symbol_index, r_type, addend) = match platform {
+        ElfPlatformType::Bit32 => {
+            let info = reader.read_u32("r_info", endian)?;
+            let addend = match with_addend {
+                true => Some(reader.read_u32("r_addend", endian)? as i32 as i64),
+                false => None,
+            };
+            (info as u64, (info >> 8) as usize, info & 0xFF, addend)
+        }
+        ElfPlatformType::Bit64 => {
+            let info = reader.read_u64("r_info", endian)?;
+            let addend = match with_addend {
+                true => Some(reader.read_u64("r_addend", endian)? as i64),
+                false => None,
+            };
+            (info, (info >> 32) as usize, info as u32, addend)
+        }
+    };
+
+    Ok(ElfRelocation {
+        relocation_offset: ElfRelocationOffset(offset),
+        relocation_info: ElfRelocationInfo(info),
+        relocation_type: ElfRelocationType {
+            value: r_type,
+            name: relocation_type_name(machine, r_type),
+        },
+        symbol_index,
+        symbol_value: ElfSymbolValue::default(),
+        symbol_name: ElfSymbolName::default(),
+        relocation_addend: ElfRelocationAddend(addend),
+    })
+}
+
+pub fn parse_relocation_table(
+    content: &[u8],
+    section_header: &ElfSectionHeader,
+    relocation_section: &ElfSectionHeaderEntry,
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfRelocationTable, ElfError> {
+    let with_addend = matches!(
+        relocation_section.section_header_type,
+        ElfSectionHeaderType::ShtRela
+    );
+    let entry_size = match (
+        relocation_section.section_entry_size.0,
+        platform,
+        with_addend,
+    ) {
+        (0, ElfPlatformType::Bit32, false) => 8,
+        (0, ElfPlatformType::Bit32, true) => 12,
+        (0, ElfPlatformType::Bit64, false) => 16,
+        (0, ElfPlatformType::Bit64, true) => 24,
+        (size, _, _) => size,
+    };
+    let table_offset = relocation_section.section_offset.0;
+    let table = slice_at(
+        content,
+        "relocation table",
+        table_offset,
+        relocation_section.section_size.0,
+    )?;
+
+    // A link of 0 means the relocations do not reference any symbols
+    let symbol_section = match relocation_section.section_link.0 {
+        0 => None,
+        link => section_header.inner.get(link as usize),
+    };
+    let symbols = match symbol_section {
+        Some(symbol_section) => Some(parse_symbol_table(
+            content,
+            section_header,
+            symbol_section,
+            endian,
+            platform,
+        )?),
+        None => None,
+    };
+    let applies_to = match relocation_section.section_info.0 {
+        0 => String::new(),
+        info => section_header
+            .inner
+            .get(info as usize)
+            .map(|section| section.section_name.inner())
+            .unwrap_or_default(),
+    };
+
+    let count = table.len() / entry_size;
+    let mut inner = Vec::with_capacity(count);
+    let mut reader = ElfReader::new(content);
+    for index in 0..count {
+        reader.seek(table_offset + index * entry_size);
+        let mut relocation = parse_relocation(&mut reader, machine, endian, platform, with_addend)?;
+        if let Some(symbol) = symbols
+            .as_ref()
+            .and_then(|symbols| symbols.inner.get(relocation.symbol_index))
+        {
+            relocation.symbol_value = ElfSymbolValue(symbol.symbol_value.0);
+            relocation.symbol_name = ElfSymbolName(symbol.symbol_name.0.clone());
+
+            // Section symbols are unnamed, show the section they stand for instead
+            if let ElfSymbolType::SttSection = symbol.symbol_type
+                && let Some(section) = symbol
+                    .symbol_section_index
+                    .section()
+                    .and_then(|index| section_header.inner.get(index))
+            {
+                relocation.symbol_name = ElfSymbolName(section.section_name.inner());
+            }
+        }
+        inner.push(relocation);
+    }
+
+    Ok(ElfRelocationTable {
+        section_name: relocation_section.section_name.inner(),
+        section_offset: table_offset,
+        applies_to,
+        symbol_table: symbols.map(|table| table.section_name).unwrap_or_default(),
+        inner,
+    })
+}
+
+pub fn parse_relocation_tables(
+    content: &[u8],
+    section_header: &ElfSectionHeader,
+    machine: &ElfInstructionSet,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfRelocationTables, ElfError> {
+    let inner = section_header
+        .inner
+        .iter()
+        .filter(|entry| {
+            matches!(
+                entry.section_header_type,
+                ElfSectionHeaderType::ShtRel | ElfSectionHeaderType::ShtRela
+            )
+        })
+        .map(|entry| {
+            parse_relocation_table(content, section_header, entry, machine, endian, platform)
+        })
+        .collect::<Result<Vec<ElfRelocationTable>, ElfError>>()?;
+
+    Ok(ElfRelocationTables { inner })
+}
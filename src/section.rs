@@ -0,0 +1,758 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfEndianness, ElfError, ElfPlatformType, ElfReader, ElfSectionCompression,
+    ElfSectionHeaderEntryCount, ElfSectionHeaderEntrySize, ElfSectionHeaderSectionsTableIndex,
+    check_entry_size, entry_offset, flag_names, parse_compression_header, section_contents,
+    serialize_flags, serialize_named, slice_at,
+};
+
+#[derive(Debug, Default)]
+pub struct ElfSectionHeader {
+    pub inner: Vec<ElfSectionHeaderEntry>,
+    // Bytes skipped after each entry when e_shentsize is larger than Elf*_Shdr
+    pub entry_padding: usize,
+}
+
+impl ElfSectionHeader {
+    pub fn inner(self) -> Vec<ElfSectionHeaderEntry> {
+        self.inner
+    }
+
+    // Looks a section up by index when `selector` is a number, by name otherwise
+    pub fn find(&self, selector: &str) -> Option<(usize, &ElfSectionHeaderEntry)> {
+        match selector.parse::<usize>() {
+            Ok(index) => self.inner.get(index).map(|entry| (index, entry)),
+            Err(_) => self
+                .inner
+                .iter()
+                .enumerate()
+                .find(|(_, entry)| entry.section_name.0 == selector),
+        }
+    }
+}
+
+impl std::fmt::Display for ElfSectionHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfSectionHeaderEntry {
+    // An offset to a string in the .shstrtab section that
+    // represents the name of this section.
+    pub section_name_offset: ElfSectionNameOffset,
+    // The actual name
+    pub section_name: ElfSectionName,
+    // Identifies the type of this header.
+    pub section_header_type: ElfSectionHeaderType,
+    // Identifies the attributes of the section.
+    pub section_flags: ElfSectionFlags,
+    // Virtual address of the section in memory, for sections
+    // that are loaded.
+    pub section_addr: ElfSectionAddr,
+    // Offset of the section in the file image
+    pub section_offset: ElfSectionOffset,
+    // Size in bytes of the section. May be 0.
+    pub section_size: ElfSectionSize,
+    // Contains the section index of an associated section.
+    // This field is used for several purposes, depending on
+    // the type of section.
+    pub section_link: ElfSectionLink,
+    // Contains extra information about the section.
+    // This field is used for several purposes, depending on
+    // the type of section.
+    pub section_info: ElfSectionInfo,
+    // Contains the required alignment of the section.
+    // This field must be a power of two.
+    pub section_addr_allign: ElfSectionAddrAllign,
+    // Contains the size, in bytes, of each entry, for sections
+    // that contain fixed-size entries. Otherwise, this field contains zero.
+    pub section_entry_size: ElfSectionEntrySize,
+    // Compression type, decompressed size and alignment of SHF_COMPRESSED
+    // and .zdebug_* sections.
+    pub section_compression: ElfSectionCompression,
+}
+
+impl std::fmt::Display for ElfSectionHeaderEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.section_name_offset,
+            self.section_name,
+            self.section_header_type,
+            self.section_flags,
+            self.section_addr,
+            self.section_offset,
+            self.section_size,
+            self.section_link,
+            self.section_info,
+            self.section_addr_allign,
+            self.section_entry_size,
+            self.section_compression
+        );
+        write!(f, "{}", txt)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionName(pub String);
+
+impl ElfSectionName {
+    pub fn inner(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl std::fmt::Display for ElfSectionName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionAddrAllign(pub usize);
+
+impl std::fmt::Display for ElfSectionAddrAllign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionEntrySize(pub usize);
+
+impl std::fmt::Display for ElfSectionEntrySize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionLink(pub u32);
+
+impl std::fmt::Display for ElfSectionLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionInfo(pub u32);
+
+impl std::fmt::Display for ElfSectionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct ElfSectionAddr(pub usize);
+
+impl std::fmt::Display for ElfSectionAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionOffset(pub usize);
+
+impl std::fmt::Display for ElfSectionOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionSize(pub usize);
+
+impl std::fmt::Display for ElfSectionSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+// Raw sh_flags, see `flag_names`
+#[derive(Default, Debug)]
+pub struct ElfSectionFlags(pub u64);
+
+impl ElfSectionFlags {
+    pub const SHF_WRITE: u64 = 0x1; // Writable
+    pub const SHF_ALLOC: u64 = 0x2; // Occupies memory during execution
+    pub const SHF_EXECINSTR: u64 = 0x4; // Executable
+    pub const SHF_MERGE: u64 = 0x10; // Might be merged
+    pub const SHF_STRINGS: u64 = 0x20; // Contains null-terminated strings
+    pub const SHF_INFO_LINK: u64 = 0x40; // sh_info' contains SHT index
+    pub const SHF_LINK_ORDER: u64 = 0x80; // Preserve order after combining
+    pub const SHF_OS_NONCONFORMING: u64 = 0x100; // Non-standard OS specific handling required
+    pub const SHF_GROUP: u64 = 0x200; // Section is member of a group
+    pub const SHF_TLS: u64 = 0x400; // Section hold thread-local data
+    pub const SHF_COMPRESSED: u64 = 0x800; // Section data is compressed
+    pub const SHF_MASKOS: u64 = 0x0FF00000; // OS-specific
+    pub const SHF_GNU_RETAIN: u64 = 0x200000; // Not to be garbage collected by the linker (GNU)
+    pub const SHF_MASKPROC: u64 = 0xF0000000; // Processor-specific
+    pub const SHF_ORDERED: u64 = 0x40000000; // Special ordering requirement (Solaris)
+    pub const SHF_EXCLUDE: u64 = 0x80000000; // Section is excluded unless referenced or allocated (Solaris)
+
+    // Bits with a name, paired with the key letter readelf uses for them.
+    // SHF_ORDERED has none, it is shown as a processor specific bit.
+    const KNOWN: [(u64, Option<char>, &'static str); 14] = [
+        (Self::SHF_WRITE, Some('W'), "SHF_WRITE"),
+        (Self::SHF_ALLOC, Some('A'), "SHF_ALLOC"),
+        (Self::SHF_EXECINSTR, Some('X'), "SHF_EXECINSTR"),
+        (Self::SHF_MERGE, Some('M'), "SHF_MERGE"),
+        (Self::SHF_STRINGS, Some('S'), "SHF_STRINGS"),
+        (Self::SHF_INFO_LINK, Some('I'), "SHF_INFO_LINK"),
+        (Self::SHF_LINK_ORDER, Some('L'), "SHF_LINK_ORDER"),
+        (
+            Self::SHF_OS_NONCONFORMING,
+            Some('O'),
+            "SHF_OS_NONCONFORMING",
+        ),
+        (Self::SHF_GROUP, Some('G'), "SHF_GROUP"),
+        (Self::SHF_TLS, Some('T'), "SHF_TLS"),
+        (Self::SHF_COMPRESSED, Some('C'), "SHF_COMPRESSED"),
+        (Self::SHF_GNU_RETAIN, Some('R'), "SHF_GNU_RETAIN"),
+        (Self::SHF_ORDERED, None, "SHF_ORDERED"),
+        (Self::SHF_EXCLUDE, Some('E'), "SHF_EXCLUDE"),
+    ];
+
+    pub fn contains(&self, flag: u64) -> bool {
+        self.0 & flag == flag
+    }
+
+    fn unknown(&self) -> u64 {
+        Self::KNOWN
+            .iter()
+            .fold(self.0, |rest, (bit, _, _)| rest & !bit)
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        let known = Self::KNOWN
+            .iter()
+            .map(|(bit, _, name)| (*bit, *name))
+            .collect::<Vec<(u64, &str)>>();
+        flag_names(
+            self.0,
+            &known,
+            &[
+                (Self::SHF_MASKOS, "SHF_MASKOS"),
+                (Self::SHF_MASKPROC, "SHF_MASKPROC"),
+                (u64::MAX, "SHF_UNKNOWN"),
+            ],
+        )
+    }
+}
+
+impl Serialize for ElfSectionFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_flags(serializer, self.0, self.names())
+    }
+}
+
+// Rendered with readelf's key letters, e.g. `AX` for .text and `WA` for .data.
+// `o` and `p` mark OS and processor specific bits without a letter, `x` anything else.
+impl std::fmt::Display for ElfSectionFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut txt = Self::KNOWN
+            .iter()
+            .filter(|(bit, _, _)| self.contains(*bit))
+            .filter_map(|(_, key, _)| *key)
+            .collect::<String>();
+        let unlettered = Self::KNOWN
+            .iter()
+            .filter(|(_, key, _)| key.is_none())
+            .fold(0, |bits, (bit, _, _)| bits | bit);
+        let unknown = self.unknown() | (self.0 & unlettered);
+        if unknown & Self::SHF_MASKOS != 0 {
+            txt.push('o');
+        }
+        if unknown & Self::SHF_MASKPROC != 0 {
+            txt.push('p');
+        }
+        if unknown & !(Self::SHF_MASKOS | Self::SHF_MASKPROC) != 0 {
+            txt.push('x');
+        }
+
+        write!(f, "{}", txt)
+    }
+}
+
+#[derive(Default, Debug)]
+pub enum ElfSectionHeaderType {
+    #[default]
+    ShtNull, //Section header table entry unused
+    ShtProgbits,      //Program data
+    ShtSymtab,        //Symbol table
+    ShtStrtab,        //String table
+    ShtRela,          //Relocation entries with addends
+    ShtHash,          //Symbol hash table
+    ShtDynamic,       //Dynamic linking information
+    ShtNote,          //Notes
+    ShtNobits,        //Program space with no data (bss)
+    ShtRel,           //Relocation entries, no addends
+    ShtShlib,         //Reserved
+    ShtDynsym,        //Dynamic linker symbol table
+    ShtInitArray,     //Array of constructors
+    ShtFiniArray,     //Array of destructors
+    ShtPreinitArray,  //Array of pre-constructors
+    ShtGroup,         //Section group
+    ShtSymtabShndx,   //Extended section indices
+    ShtNum,           //Number of defined types.
+    ShtLoos,          //Start OS-specific.
+    ShtGnuAttributes, //Object attributes (GNU)
+    ShtGnuHash,       //GNU-style hash table
+    ShtGnuLiblist,    //Prelink library list (GNU)
+    ShtGnuVerdef,     //Version definition section (GNU)
+    ShtGnuVerneed,    //Version needs section (GNU)
+    ShtGnuVersym,     //Version symbol table (GNU)
+    Other(u32),       //Any other value, named after the range it falls in
+}
+
+impl std::fmt::Display for ElfSectionHeaderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfSectionHeaderType::ShtNull => "SHT_NULL",
+            ElfSectionHeaderType::ShtProgbits => "SHT_PROGBITS",
+            ElfSectionHeaderType::ShtSymtab => "SHT_SYMTAB",
+            ElfSectionHeaderType::ShtStrtab => "SHT_STRTAB",
+            ElfSectionHeaderType::ShtRela => "SHT_RELA",
+            ElfSectionHeaderType::ShtHash => "SHT_HASH",
+            ElfSectionHeaderType::ShtDynamic => "SHT_DYNAMIC",
+            ElfSectionHeaderType::ShtNote => "SHT_NOTE",
+            ElfSectionHeaderType::ShtNobits => "SHT_NOBITS",
+            ElfSectionHeaderType::ShtRel => "SHT_REL",
+            ElfSectionHeaderType::ShtShlib => "SHT_SHLIB",
+            ElfSectionHeaderType::ShtDynsym => "SHT_DYNSYM",
+            ElfSectionHeaderType::ShtInitArray => "SHT_INIT_ARRAY",
+            ElfSectionHeaderType::ShtFiniArray => "SHT_FINI_ARRAY",
+            ElfSectionHeaderType::ShtPreinitArray => "SHT_PREINIT_ARRAY",
+            ElfSectionHeaderType::ShtGroup => "SHT_GROUP",
+            ElfSectionHeaderType::ShtSymtabShndx => "SHT_SYMTAB_SHNDX",
+            ElfSectionHeaderType::ShtNum => "SHT_NUM",
+            ElfSectionHeaderType::ShtLoos => "SHT_LOOS",
+            ElfSectionHeaderType::ShtGnuAttributes => "SHT_GNU_ATTRIBUTES",
+            ElfSectionHeaderType::ShtGnuHash => "SHT_GNU_HASH",
+            ElfSectionHeaderType::ShtGnuLiblist => "SHT_GNU_LIBLIST",
+            ElfSectionHeaderType::ShtGnuVerdef => "SHT_GNU_verdef",
+            ElfSectionHeaderType::ShtGnuVerneed => "SHT_GNU_verneed",
+            ElfSectionHeaderType::ShtGnuVersym => "SHT_GNU_versym",
+            ElfSectionHeaderType::Other(value) => {
+                return match value {
+                    0x60000000..=0x6FFFFFFF => write!(f, "SHT_LOOS+{:#x}", value - 0x60000000),
+                    0x70000000..=0x7FFFFFFF => write!(f, "SHT_LOPROC+{:#x}", value - 0x70000000),
+                    0x80000000..=0xFFFFFFFF => write!(f, "SHT_LOUSER+{:#x}", value - 0x80000000),
+                    _ => write!(f, "SHT_<{:#x}>", value),
+                };
+            }
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfSectionHeaderType {
+    // Raw sh_type value
+    pub fn value(&self) -> u32 {
+        match self {
+            ElfSectionHeaderType::ShtNull => 0x0,
+            ElfSectionHeaderType::ShtProgbits => 0x1,
+            ElfSectionHeaderType::ShtSymtab => 0x2,
+            ElfSectionHeaderType::ShtStrtab => 0x3,
+            ElfSectionHeaderType::ShtRela => 0x4,
+            ElfSectionHeaderType::ShtHash => 0x5,
+            ElfSectionHeaderType::ShtDynamic => 0x6,
+            ElfSectionHeaderType::ShtNote => 0x7,
+            ElfSectionHeaderType::ShtNobits => 0x8,
+            ElfSectionHeaderType::ShtRel => 0x9,
+            ElfSectionHeaderType::ShtShlib => 0x0A,
+            ElfSectionHeaderType::ShtDynsym => 0x0B,
+            ElfSectionHeaderType::ShtInitArray => 0x0E,
+            ElfSectionHeaderType::ShtFiniArray => 0x0F,
+            ElfSectionHeaderType::ShtPreinitArray => 0x10,
+            ElfSectionHeaderType::ShtGroup => 0x11,
+            ElfSectionHeaderType::ShtSymtabShndx => 0x12,
+            ElfSectionHeaderType::ShtNum => 0x13,
+            ElfSectionHeaderType::ShtLoos => 0x60000000,
+            ElfSectionHeaderType::ShtGnuAttributes => 0x6FFFFFF5,
+            ElfSectionHeaderType::ShtGnuHash => 0x6FFFFFF6,
+            ElfSectionHeaderType::ShtGnuLiblist => 0x6FFFFFF7,
+            ElfSectionHeaderType::ShtGnuVerdef => 0x6FFFFFFD,
+            ElfSectionHeaderType::ShtGnuVerneed => 0x6FFFFFFE,
+            ElfSectionHeaderType::ShtGnuVersym => 0x6FFFFFFF,
+            ElfSectionHeaderType::Other(value) => *value,
+        }
+    }
+}
+
+impl Serialize for ElfSectionHeaderType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSectionNameOffset(pub u32);
+
+impl std::fmt::Display for ElfSectionNameOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ElfSectionsData {
+    pub inner: Vec<ElfSectionsDataEntry>,
+}
+
+impl ElfSectionsData {
+    pub fn inner(self) -> Vec<ElfSectionsDataEntry> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfSectionsData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfSectionsDataEntry {
+    pub section_name: String,
+    // TODO: You might wanna use `&'s [u8]` here if necessary
+    pub data: String,
+}
+
+impl std::fmt::Display for ElfSectionsDataEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = format!("{}\n{:?}", self.section_name, self.data);
+
+        write!(f, "{}", txt)
+    }
+}
+
+pub fn parse_section_entry_size(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSectionEntrySize, ElfError> {
+    let value = reader.read_word("sh_entsize", endian, platform)?;
+
+    Ok(ElfSectionEntrySize(value))
+}
+
+pub fn parse_section_addr_allignment(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSectionAddrAllign, ElfError> {
+    let value = reader.read_word("sh_addralign", endian, platform)?;
+
+    Ok(ElfSectionAddrAllign(value))
+}
+
+pub fn parse_section_info(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionInfo, ElfError> {
+    let value = reader.read_u32("sh_info", endian)?;
+
+    Ok(ElfSectionInfo(value))
+}
+
+pub fn parse_section_link(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionLink, ElfError> {
+    let value = reader.read_u32("sh_link", endian)?;
+
+    Ok(ElfSectionLink(value))
+}
+
+pub fn parse_section_size(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSectionSize, ElfError> {
+    let value = reader.read_word("sh_size", endian, platform)?;
+
+    Ok(ElfSectionSize(value))
+}
+
+pub fn parse_section_offset(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSectionOffset, ElfError> {
+    let value = reader.read_word("sh_offset", endian, platform)?;
+
+    Ok(ElfSectionOffset(value))
+}
+
+pub fn parse_section_addr(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSectionAddr, ElfError> {
+    let value = reader.read_word("sh_addr", endian, platform)?;
+
+    Ok(ElfSectionAddr(value))
+}
+
+pub fn parse_section_flags(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSectionFlags, ElfError> {
+    let flags = match platform {
+        ElfPlatformType::Bit32 => reader.read_u32("sh_flags", endian)? as u64,
+        ElfPlatformType::Bit64 => reader.read_u64("sh_flags", endian)?,
+    };
+
+    Ok(ElfSectionFlags(flags))
+}
+
+pub fn parse_section_header_type(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionHeaderType, ElfError> {
+    let h_type = reader.read_u32("sh_type", endian)?;
+    let h_type = match h_type {
+        0x0 => ElfSectionHeaderType::ShtNull,
+        0x1 => ElfSectionHeaderType::ShtProgbits,
+        0x2 => ElfSectionHeaderType::ShtSymtab,
+        0x3 => ElfSectionHeaderType::ShtStrtab,
+        0x4 => ElfSectionHeaderType::ShtRela,
+        0x5 => ElfSectionHeaderType::ShtHash,
+        0x6 => ElfSectionHeaderType::ShtDynamic,
+        0x7 => ElfSectionHeaderType::ShtNote,
+        0x8 => ElfSectionHeaderType::ShtNobits,
+        0x9 => ElfSectionHeaderType::ShtRel,
+        0x0A => ElfSectionHeaderType::ShtShlib,
+        0x0B => ElfSectionHeaderType::ShtDynsym,
+        0x0E => ElfSectionHeaderType::ShtInitArray,
+        0x0F => ElfSectionHeaderType::ShtFiniArray,
+        0x10 => ElfSectionHeaderType::ShtPreinitArray,
+        0x11 => ElfSectionHeaderType::ShtGroup,
+        0x12 => ElfSectionHeaderType::ShtSymtabShndx,
+        0x13 => ElfSectionHeaderType::ShtNum,
+        0x60000000 => ElfSectionHeaderType::ShtLoos,
+        0x6FFFFFF5 => ElfSectionHeaderType::ShtGnuAttributes,
+        0x6FFFFFF6 => ElfSectionHeaderType::ShtGnuHash,
+        0x6FFFFFF7 => ElfSectionHeaderType::ShtGnuLiblist,
+        0x6FFFFFFD => ElfSectionHeaderType::ShtGnuVerdef,
+        0x6FFFFFFE => ElfSectionHeaderType::ShtGnuVerneed,
+        0x6FFFFFFF => ElfSectionHeaderType::ShtGnuVersym,
+        other => ElfSectionHeaderType::Other(other),
+    };
+
+    Ok(h_type)
+}
+
+pub fn parse_section_name_offset(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+) -> Result<ElfSectionNameOffset, ElfError> {
+    let value = reader.read_u32("sh_name", endian)?;
+
+    Ok(ElfSectionNameOffset(value))
+}
+
+pub fn parse_section_header_entry(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSectionHeaderEntry, ElfError> {
+    let section_name_offset = parse_section_name_offset(reader, endian)?;
+    let section_name = ElfSectionName::default();
+    let section_header_type = parse_section_header_type(reader, endian)?;
+    let section_flags = parse_section_flags(reader, endian, platform)?;
+    let section_addr = parse_section_addr(reader, endian, platform)?;
+    let section_offset = parse_section_offset(reader, endian, platform)?;
+    let section_size = parse_section_size(reader, endian, platform)?;
+    let section_link = parse_section_link(reader, endian)?;
+    let section_info = parse_section_info(reader, endian)?;
+    let section_addr_allign = parse_section_addr_allignment(reader, endian, platform)?;
+    let section_entry_size = parse_section_entry_size(reader, endian, platform)?;
+
+    Ok({
+        ElfSectionHeaderEntry {
+            section_name_offset,
+            section_name,
+            section_header_type,
+            section_flags,
+            section_addr,
+            section_offset,
+            section_size,
+            section_link,
+            section_info,
+            section_addr_allign,
+            section_entry_size,
+            section_compression: ElfSectionCompression::default(),
+        }
+    })
+}
+
+// The bytes of a section in the file. SHT_NOBITS sections occupy no space in
+// the file and have no content.
+pub fn section_data<'data>(
+    content: &'data [u8],
+    entry: &ElfSectionHeaderEntry,
+) -> Result<&'data [u8], ElfError> {
+    match entry.section_header_type {
+        ElfSectionHeaderType::ShtNobits => Ok(&[]),
+        _ => slice_at(
+            content,
+            "section data",
+            entry.section_offset.0,
+            entry.section_size.0,
+        ),
+    }
+}
+
+pub fn parse_sections_data(
+    content: &[u8],
+    section_header: &ElfSectionHeader,
+) -> Result<ElfSectionsData, ElfError> {
+    let inner = section_header
+        .inner
+        .iter()
+        .flat_map(|entry| {
+            if let ElfSectionHeaderType::ShtNull = entry.section_header_type {
+                return None;
+            }
+            let data = match section_contents(content, entry) {
+                Ok(data) => data,
+                Err(err) => return Some(Err(err)),
+            };
+
+            // TODO: Might wanna change to display actual text in some sections
+            let data = data
+                .iter()
+                .map(|byte| format!("{:X}", byte))
+                .collect::<Vec<String>>()
+                .join(" ");
+            Some(Ok(ElfSectionsDataEntry {
+                section_name: entry.section_name.inner(),
+                data,
+            }))
+        })
+        .collect::<Result<Vec<ElfSectionsDataEntry>, ElfError>>()?;
+    Ok(ElfSectionsData { inner })
+}
+
+// Reads the NUL terminated string found `offset` bytes into a string table section.
+pub fn parse_string(
+    content: &[u8],
+    string_table: &ElfSectionHeaderEntry,
+    offset: usize,
+) -> Result<String, ElfError> {
+    parse_string_at(
+        content,
+        string_table.section_offset.0,
+        string_table.section_size.0,
+        offset,
+    )
+}
+
+// Same as `parse_string` for string tables located without a section header,
+// e.g. through DT_STRTAB and DT_STRSZ.
+pub fn parse_string_at(
+    content: &[u8],
+    table_offset: usize,
+    table_size: usize,
+    offset: usize,
+) -> Result<String, ElfError> {
+    let table = slice_at(content, "string table", table_offset, table_size)?;
+    let bytes = table.get(offset..).ok_or(ElfError::Truncated {
+        field: "string table",
+        offset: table_offset.saturating_add(offset),
+        expected: 1,
+        actual: 0,
+    })?;
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+
+    Ok(String::from_utf8_lossy(&bytes[..end]).to_string())
+}
+
+pub fn parse_section_header(
+    reader: &mut ElfReader<'_>,
+    entry_count: &ElfSectionHeaderEntryCount,
+    entry_size: &ElfSectionHeaderEntrySize,
+    sections_names_index: &ElfSectionHeaderSectionsTableIndex,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSectionHeader, ElfError> {
+    // Extended section numbering: with SHN_LORESERVE or more sections e_shnum is 0
+    // and the real count is the sh_size of entry 0, which only exists when the
+    // file has a section header table at all.
+    let mut entry_count = entry_count.0 as usize;
+    if entry_count == 0 && reader.pointer() != 0 {
+        let first = parse_section_header_entry(&mut reader.clone(), endian, platform)?;
+        entry_count = first.section_size.0;
+    }
+    if entry_count == 0 {
+        return Ok(ElfSectionHeader::default());
+    }
+
+    // Elf32_Shdr and Elf64_Shdr, and where e_shentsize sits in the file header
+    let (minimum_entry_size, field_offset) = match platform {
+        ElfPlatformType::Bit32 => (40, 0x2E),
+        ElfPlatformType::Bit64 => (64, 0x3A),
+    };
+    let entry_size = check_entry_size(
+        "e_shentsize",
+        field_offset,
+        entry_size.0,
+        minimum_entry_size,
+    )?;
+    let entry_padding = entry_size - minimum_entry_size;
+
+    let start = reader.pointer();
+    let mut entries = Vec::with_capacity(reader.capacity_for(entry_count, entry_size));
+    for index in 0..entry_count {
+        reader.seek(entry_offset("section header", start, index, entry_size)?);
+        let entry = parse_section_header_entry(reader, endian, platform)?;
+        entries.push(entry);
+    }
+
+    // Each entry names itself through an offset into the section names table.
+    // SHN_XINDEX means the index did not fit e_shstrndx and is the sh_link of entry 0.
+    let index = match sections_names_index.0 {
+        ElfSectionHeaderSectionsTableIndex::SHN_XINDEX => entries
+            .first()
+            .map_or(0, |entry| entry.section_link.0 as usize),
+        index => index as usize,
+    };
+    let Some((table_offset, table_size)) = entries
+        .get(index)
+        .map(|section| (section.section_offset.0, section.section_size.0))
+    else {
+        return Ok(ElfSectionHeader {
+            inner: entries,
+            entry_padding,
+        });
+    };
+    for entry in entries.iter_mut() {
+        let name_offset = entry.section_name_offset.0 as usize;
+        let name = parse_string_at(reader.content(), table_offset, table_size, name_offset)
+            .unwrap_or_else(|_| format!("<corrupt: {:#x}>", name_offset));
+        entry.section_name = ElfSectionName(name);
+    }
+
+    // Needs the names for .zdebug_* sections. A broken compression header
+    // leaves the column empty, reading the section reports it.
+    for entry in entries.iter_mut() {
+        let compression = parse_compression_header(reader.content(), entry, endian, platform);
+        entry.section_compression = ElfSectionCompression(compression.ok().flatten());
+    }
+
+    Ok(ElfSectionHeader {
+        inner: entries,
+        entry_padding,
+    })
+}
@@ -0,0 +1,380 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfError, ElfProgramHeader, ElfSectionFlags, ElfSectionHeader, ElfSectionHeaderType,
+    ElfSegmentType, serialize_named, slice_at,
+};
+
+// Printable runs found by `parse_strings`, in file order per section
+#[derive(Debug, Default)]
+pub struct ElfStrings {
+    pub inner: Vec<ElfStringsEntry>,
+}
+
+impl ElfStrings {
+    pub fn inner(self) -> Vec<ElfStringsEntry> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfStrings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Tabled, Serialize)]
+pub struct ElfStringsEntry {
+    // Section the string was found in, `PT_LOAD[<index>]` when the file has
+    // no section headers
+    pub section_name: String,
+    pub string_offset: ElfStringOffset,
+    pub string_vaddr: ElfStringVAddr,
+    pub string_encoding: ElfStringEncoding,
+    pub string: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfStringOffset(pub usize);
+
+impl std::fmt::Display for ElfStringOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+// `None` for strings in sections that are not loaded
+#[derive(Debug, Default, Serialize)]
+pub struct ElfStringVAddr(pub Option<usize>);
+
+impl std::fmt::Display for ElfStringVAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(vaddr) => write!(f, "{:X}", vaddr),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ElfStringEncoding {
+    #[default]
+    Ascii, // Printable 7-bit characters and tabs
+    Utf8,    // Runs holding at least one multi-byte UTF-8 character
+    Utf16Le, // 2 byte little endian code units, as used by PE resources and Windows tools
+}
+
+impl ElfStringEncoding {
+    // Used when --encoding is not given, UTF-8 runs without multi-byte
+    // characters are reported as ASCII
+    pub const DEFAULT: [ElfStringEncoding; 2] = [ElfStringEncoding::Ascii, ElfStringEncoding::Utf8];
+
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfStringEncoding::Ascii => 0,
+            ElfStringEncoding::Utf8 => 1,
+            ElfStringEncoding::Utf16Le => 2,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ascii" => Some(ElfStringEncoding::Ascii),
+            "utf-8" | "utf8" => Some(ElfStringEncoding::Utf8),
+            "utf-16le" | "utf16le" => Some(ElfStringEncoding::Utf16Le),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ElfStringEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfStringEncoding::Ascii => "ascii",
+            ElfStringEncoding::Utf8 => "utf-8",
+            ElfStringEncoding::Utf16Le => "utf-16le",
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl Serialize for ElfStringEncoding {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, self.value() as u64, self)
+    }
+}
+
+// A run of characters found at `start` bytes into a region
+struct StringRun {
+    start: usize,
+    encoding: ElfStringEncoding,
+    string: String,
+}
+
+fn is_printable(c: char) -> bool {
+    match c.is_ascii() {
+        true => c.is_ascii_graphic() || c == ' ' || c == '\t',
+        false => !c.is_control(),
+    }
+}
+
+// Decodes the UTF-8 character at the start of `bytes`, with its length
+fn decode_utf8(bytes: &[u8]) -> Option<(char, usize)> {
+    let width = match bytes.first()? {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => return None,
+    };
+    let c = std::str::from_utf8(bytes.get(..width)?)
+        .ok()?
+        .chars()
+        .next()?;
+
+    Some((c, width))
+}
+
+// Collects the runs of at least `min_length` characters accepted by `decode`,
+// which returns the character at the start of a slice and its length in bytes.
+fn scan_runs(
+    data: &[u8],
+    min_length: usize,
+    step: usize,
+    decode: impl Fn(&[u8]) -> Option<(char, usize)>,
+    encoding: impl Fn(&str) -> ElfStringEncoding,
+    runs: &mut Vec<StringRun>,
+) {
+    let mut position = 0;
+    let mut start = 0;
+    let mut string = String::new();
+    let mut length = 0;
+    let mut flush = |start: usize, string: &mut String, length: &mut usize| {
+        let string = std::mem::take(string);
+        if *length >= min_length {
+            runs.push(StringRun {
+                start,
+                encoding: encoding(&string),
+                string,
+            });
+        }
+        *length = 0;
+    };
+
+    while position < data.len() {
+        match decode(&data[position..]).filter(|(c, _)| is_printable(*c)) {
+            Some((c, width)) => {
+                if length == 0 {
+                    start = position;
+                }
+                string.push(c);
+                length += 1;
+                position += width;
+            }
+            None => {
+                flush(start, &mut string, &mut length);
+                position += step;
+            }
+        }
+    }
+    flush(start, &mut string, &mut length);
+}
+
+// Two ASCII bytes read as one code unit make a valid CJK character, so plain
+// text scanned as UTF-16 turns into runs of ideographs. Wide runs must start
+// with a Latin-1 character and hold mostly Latin-1 characters.
+fn trim_wide_run(mut run: StringRun, min_length: usize) -> Option<StringRun> {
+    let is_latin = |c: &char| (*c as u32) < 0x100;
+    let skipped = run
+        .string
+        .chars()
+        .take_while(|c| !is_latin(c))
+        .collect::<String>();
+    run.start += skipped.encode_utf16().count() * 2;
+    run.string.drain(..skipped.len());
+
+    let length = run.string.chars().count();
+    let latin = run.string.chars().filter(is_latin).count();
+    (length >= min_length && latin * 2 >= length).then_some(run)
+}
+
+fn find_runs(data: &[u8], min_length: usize, encodings: &[ElfStringEncoding]) -> Vec<StringRun> {
+    let mut runs = Vec::new();
+    // ASCII is a subset of UTF-8, a single pass reports both
+    if encodings.contains(&ElfStringEncoding::Utf8) {
+        let ascii = encodings.contains(&ElfStringEncoding::Ascii);
+        scan_runs(
+            data,
+            min_length,
+            1,
+            decode_utf8,
+            |string| match string.is_ascii() {
+                true => ElfStringEncoding::Ascii,
+                false => ElfStringEncoding::Utf8,
+            },
+            &mut runs,
+        );
+        if !ascii {
+            runs.retain(|run| run.encoding == ElfStringEncoding::Utf8);
+        }
+    } else if encodings.contains(&ElfStringEncoding::Ascii) {
+        scan_runs(
+            data,
+            min_length,
+            1,
+            |bytes| {
+                bytes
+                    .first()
+                    .filter(|byte| byte.is_ascii())
+                    .map(|byte| (*byte as char, 1))
+            },
+            |_| ElfStringEncoding::Ascii,
+            &mut runs,
+        );
+    }
+
+    // Code units are read 2-byte aligned from the start of the region
+    if encodings.contains(&ElfStringEncoding::Utf16Le) {
+        let unit = |bytes: &[u8]| -> Option<u16> {
+            Some(u16::from_le_bytes([*bytes.first()?, *bytes.get(1)?]))
+        };
+        let mut wide = Vec::new();
+        scan_runs(
+            data,
+            min_length,
+            2,
+            |bytes| {
+                let first = unit(bytes)?;
+                let (c, width) = match char::from_u32(first as u32) {
+                    Some(c) => (c, 2),
+                    // Surrogate pair
+                    None => {
+                        let second = unit(bytes.get(2..)?)?;
+                        (char::decode_utf16([first, second]).next()?.ok()?, 4)
+                    }
+                };
+                (c.is_ascii() || c.is_alphanumeric()).then_some((c, width))
+            },
+            |_| ElfStringEncoding::Utf16Le,
+            &mut wide,
+        );
+        runs.extend(
+            wide.into_iter()
+                .filter_map(|run| trim_wide_run(run, min_length)),
+        );
+    }
+
+    runs
+}
+
+// A stretch of the file to scan, with the virtual address it is loaded at
+struct StringRegion {
+    name: String,
+    offset: usize,
+    size: usize,
+    vaddr: Option<usize>,
+}
+
+fn string_regions(
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+) -> Vec<StringRegion> {
+    // Stripped of section headers, fall back to what the loader maps
+    if section_header.inner.is_empty() {
+        return program_header
+            .inner
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| matches!(entry.segment_type, ElfSegmentType::PtLoad))
+            .map(|(index, entry)| StringRegion {
+                name: format!("PT_LOAD[{index}]"),
+                offset: entry.segment_offset.0,
+                size: entry.segment_file_size.0,
+                vaddr: Some(entry.segment_vaddr.0),
+            })
+            .collect();
+    }
+
+    section_header
+        .inner
+        .iter()
+        .filter(|entry| {
+            !matches!(
+                entry.section_header_type,
+                ElfSectionHeaderType::ShtNull | ElfSectionHeaderType::ShtNobits
+            )
+        })
+        .map(|entry| StringRegion {
+            name: entry.section_name.inner(),
+            offset: entry.section_offset.0,
+            size: entry.section_size.0,
+            vaddr: entry
+                .section_flags
+                .contains(ElfSectionFlags::SHF_ALLOC)
+                .then_some(entry.section_addr.0),
+        })
+        .collect()
+}
+
+// Scans every section with content in the file, or every PT_LOAD segment when
+// there are no section headers, for printable runs of at least `min_length`
+// characters in the given encodings.
+pub fn parse_strings(
+    content: &[u8],
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+    min_length: usize,
+    encodings: &[ElfStringEncoding],
+) -> Result<ElfStrings, ElfError> {
+    let mut inner = Vec::new();
+    for region in string_regions(program_header, section_header) {
+        let data = slice_at(content, "string region", region.offset, region.size)?;
+        let mut runs = find_runs(data, min_length.max(1), encodings);
+        runs.sort_by_key(|run| run.start);
+
+        inner.extend(runs.into_iter().map(|run| ElfStringsEntry {
+            section_name: region.name.clone(),
+            string_offset: ElfStringOffset(region.offset + run.start),
+            string_vaddr: ElfStringVAddr(region.vaddr.map(|vaddr| vaddr.wrapping_add(run.start))),
+            string_encoding: run.encoding,
+            string: run.string,
+        }));
+    }
+
+    Ok(ElfStrings { inner })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_runs_are_dropped() {
+        let runs = find_runs(
+            b"ab\0hello world\0xy",
+            4,
+            &[ElfStringEncoding::Ascii, ElfStringEncoding::Utf8],
+        );
+        let found = runs
+            .iter()
+            .map(|run| (run.start, run.string.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(found, [(3, "hello world")]);
+    }
+
+    #[test]
+    fn short_wide_runs_are_dropped() {
+        let data = "ab\0hello"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<u8>>();
+        let runs = find_runs(&data, 4, &[ElfStringEncoding::Utf16Le]);
+        let found = runs
+            .iter()
+            .map(|run| (run.start, run.string.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(found, [(6, "hello")]);
+    }
+}
@@ -0,0 +1,125 @@
+use serde::Serialize;
+
+use crate::{
+    ElfDynamicTable, ElfDynamicTag, ElfEndianness, ElfEntryPoint, ElfError, ElfHeader,
+    ElfInstructionSet, ElfObjectFileType, ElfPlatformType, ElfProgramHeader, ElfSectionHeader,
+    ElfSegmentType, parse_dynamic_table, parse_note_tables, slice_at,
+};
+
+// The handful of facts most often looked up in a binary: what loads it,
+// which build it is and what it links against.
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSummary {
+    pub object_file_type: ElfObjectFileType,
+    pub instruction_set: ElfInstructionSet,
+    pub platform_type: ElfPlatformType,
+    pub endianness: ElfEndianness,
+    pub entry_point: ElfEntryPoint,
+    // Path of the program interpreter from PT_INTERP
+    pub interpreter: Option<String>,
+    // NT_GNU_BUILD_ID as a hex string
+    pub build_id: Option<String>,
+    // DT_SONAME
+    pub soname: Option<String>,
+    // DT_NEEDED entries in load order
+    pub needed: Vec<String>,
+}
+
+impl std::fmt::Display for ElfSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Type:         {}", self.object_file_type)?;
+        writeln!(
+            f,
+            "Machine:      {} ({}, {} endian)",
+            self.instruction_set, self.platform_type, self.endianness
+        )?;
+        writeln!(f, "Entry point:  {:#x}", self.entry_point.0)?;
+        writeln!(
+            f,
+            "Interpreter:  {}",
+            self.interpreter.as_deref().unwrap_or("-")
+        )?;
+        writeln!(
+            f,
+            "Build ID:     {}",
+            self.build_id.as_deref().unwrap_or("-")
+        )?;
+        writeln!(f, "SONAME:       {}", self.soname.as_deref().unwrap_or("-"))?;
+        match self.needed.is_empty() {
+            true => write!(f, "Needed:       -"),
+            false => write!(f, "Needed:       {}", self.needed.join(", ")),
+        }
+    }
+}
+
+// Reads the NUL terminated path stored in the PT_INTERP segment.
+pub fn parse_interpreter(
+    content: &[u8],
+    program_header: &ElfProgramHeader,
+) -> Result<Option<String>, ElfError> {
+    let Some(entry) = program_header
+        .inner
+        .iter()
+        .find(|entry| matches!(entry.segment_type, ElfSegmentType::PtInterp))
+    else {
+        return Ok(None);
+    };
+
+    let path = slice_at(
+        content,
+        "interpreter",
+        entry.segment_offset.0,
+        entry.segment_file_size.0,
+    )?;
+    let end = path
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(path.len());
+    Ok(Some(String::from_utf8_lossy(&path[..end]).into_owned()))
+}
+
+fn dynamic_strings(dynamic: &ElfDynamicTable, tag: u64) -> Vec<String> {
+    dynamic
+        .inner
+        .iter()
+        .filter(|entry| entry.dynamic_tag.value() == tag)
+        .map(|entry| entry.dynamic_meaning.clone())
+        .collect()
+}
+
+pub fn parse_summary(
+    content: &[u8],
+    header: &ElfHeader,
+    program_header: &ElfProgramHeader,
+    section_header: &ElfSectionHeader,
+) -> Result<ElfSummary, ElfError> {
+    let note_tables = parse_note_tables(
+        content,
+        program_header,
+        section_header,
+        &header.instruction_set,
+        &header.endianness,
+        &header.platform_type,
+    )?;
+    let dynamic = parse_dynamic_table(
+        content,
+        program_header,
+        section_header,
+        &header.endianness,
+        &header.platform_type,
+    )?;
+
+    Ok(ElfSummary {
+        object_file_type: header.object_file_type.clone(),
+        instruction_set: header.instruction_set.clone(),
+        platform_type: header.platform_type.clone(),
+        endianness: header.endianness.clone(),
+        entry_point: header.entry_point.clone(),
+        interpreter: parse_interpreter(content, program_header)?,
+        build_id: note_tables.gnu_build_id(),
+        soname: dynamic_strings(&dynamic, ElfDynamicTag::DT_SONAME)
+            .into_iter()
+            .next(),
+        needed: dynamic_strings(&dynamic, ElfDynamicTag::DT_NEEDED),
+    })
+}
@@ -0,0 +1,479 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfEndianness, ElfError, ElfPlatformType, ElfReader, ElfSectionHeader, ElfSectionHeaderEntry,
+    ElfSectionHeaderType, parse_string, serialize_named, slice_at,
+};
+
+// All the symbol tables (SHT_SYMTAB and SHT_DYNSYM) found in a file
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSymbolTables {
+    pub inner: Vec<ElfSymbolTable>,
+}
+
+impl ElfSymbolTables {
+    pub fn inner(self) -> Vec<ElfSymbolTable> {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for ElfSymbolTables {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSymbolTable {
+    // Name of the section holding the table, e.g. `.dynsym`
+    pub section_name: String,
+    pub inner: Vec<ElfSymbol>,
+}
+
+impl std::fmt::Display for ElfSymbolTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{:?}", self.section_name, self.inner)
+    }
+}
+
+#[derive(Debug, Default, Tabled, Serialize)]
+pub struct ElfSymbol {
+    // Position of the symbol in its table.
+    pub symbol_index: usize,
+    // An offset into the string table linked through `section_link`.
+    #[tabled(skip)]
+    pub symbol_name_offset: ElfSymbolNameOffset,
+    // Address for defined symbols, alignment for common ones.
+    pub symbol_value: ElfSymbolValue,
+    // Size of the object or function. May be 0.
+    pub symbol_size: ElfSymbolSize,
+    pub symbol_type: ElfSymbolType,
+    pub symbol_binding: ElfSymbolBinding,
+    pub symbol_visibility: ElfSymbolVisibility,
+    // Index of the section the symbol is defined in.
+    pub symbol_section_index: ElfSymbolSectionIndex,
+    // The actual name
+    pub symbol_name: ElfSymbolName,
+}
+
+impl std::fmt::Display for ElfSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.symbol_index,
+            self.symbol_value,
+            self.symbol_size,
+            self.symbol_type,
+            self.symbol_binding,
+            self.symbol_visibility,
+            self.symbol_section_index,
+            self.symbol_name
+        );
+        write!(f, "{}", txt)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSymbolName(pub String);
+
+impl std::fmt::Display for ElfSymbolName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSymbolNameOffset(pub u32);
+
+impl std::fmt::Display for ElfSymbolNameOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSymbolValue(pub usize);
+
+impl std::fmt::Display for ElfSymbolValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfSymbolSize(pub usize);
+
+impl std::fmt::Display for ElfSymbolSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default)]
+pub enum ElfSymbolType {
+    #[default]
+    SttNotype, // Symbol type is unspecified
+    SttObject,   // Data object
+    SttFunc,     // Function or other executable code
+    SttSection,  // Associated with a section
+    SttFile,     // Name of the source file
+    SttCommon,   // Uninitialised common block
+    SttTls,      // Thread-local storage
+    SttGnuIfunc, // Indirect function (GNU)
+    Other(u8),   // OS or processor specific
+}
+
+impl std::fmt::Display for ElfSymbolType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfSymbolType::SttNotype => "STT_NOTYPE",
+            ElfSymbolType::SttObject => "STT_OBJECT",
+            ElfSymbolType::SttFunc => "STT_FUNC",
+            ElfSymbolType::SttSection => "STT_SECTION",
+            ElfSymbolType::SttFile => "STT_FILE",
+            ElfSymbolType::SttCommon => "STT_COMMON",
+            ElfSymbolType::SttTls => "STT_TLS",
+            ElfSymbolType::SttGnuIfunc => "STT_GNU_IFUNC",
+            ElfSymbolType::Other(value) => return write!(f, "STT_<{value}>"),
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfSymbolType {
+    // Raw STT_* value from the low nibble of st_info
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfSymbolType::SttNotype => 0,
+            ElfSymbolType::SttObject => 1,
+            ElfSymbolType::SttFunc => 2,
+            ElfSymbolType::SttSection => 3,
+            ElfSymbolType::SttFile => 4,
+            ElfSymbolType::SttCommon => 5,
+            ElfSymbolType::SttTls => 6,
+            ElfSymbolType::SttGnuIfunc => 10,
+            ElfSymbolType::Other(value) => *value,
+        }
+    }
+}
+
+impl Serialize for ElfSymbolType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+#[derive(Debug, Default)]
+pub enum ElfSymbolBinding {
+    #[default]
+    StbLocal, // Not visible outside the object file
+    StbGlobal,    // Visible to all object files being combined
+    StbWeak,      // Like global, with lower precedence
+    StbGnuUnique, // Unique in the whole process (GNU)
+    Other(u8),    // OS or processor specific
+}
+
+impl std::fmt::Display for ElfSymbolBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfSymbolBinding::StbLocal => "STB_LOCAL",
+            ElfSymbolBinding::StbGlobal => "STB_GLOBAL",
+            ElfSymbolBinding::StbWeak => "STB_WEAK",
+            ElfSymbolBinding::StbGnuUnique => "STB_GNU_UNIQUE",
+            ElfSymbolBinding::Other(value) => return write!(f, "STB_<{value}>"),
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfSymbolBinding {
+    // Raw STB_* value from the high nibble of st_info
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfSymbolBinding::StbLocal => 0,
+            ElfSymbolBinding::StbGlobal => 1,
+            ElfSymbolBinding::StbWeak => 2,
+            ElfSymbolBinding::StbGnuUnique => 10,
+            ElfSymbolBinding::Other(value) => *value,
+        }
+    }
+}
+
+impl Serialize for ElfSymbolBinding {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+#[derive(Debug, Default)]
+pub enum ElfSymbolVisibility {
+    #[default]
+    StvDefault, // Visibility given by the binding
+    StvInternal,  // Processor specific hidden class
+    StvHidden,    // Not visible to other components
+    StvProtected, // Visible but not preemptible
+}
+
+impl std::fmt::Display for ElfSymbolVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfSymbolVisibility::StvDefault => "STV_DEFAULT",
+            ElfSymbolVisibility::StvInternal => "STV_INTERNAL",
+            ElfSymbolVisibility::StvHidden => "STV_HIDDEN",
+            ElfSymbolVisibility::StvProtected => "STV_PROTECTED",
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl ElfSymbolVisibility {
+    // Raw STV_* value from st_other
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfSymbolVisibility::StvDefault => 0,
+            ElfSymbolVisibility::StvInternal => 1,
+            ElfSymbolVisibility::StvHidden => 2,
+            ElfSymbolVisibility::StvProtected => 3,
+        }
+    }
+}
+
+impl Serialize for ElfSymbolVisibility {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.value()), self)
+    }
+}
+
+// st_shndx. Symbols defined in sections past SHN_LORESERVE store SHN_XINDEX
+// there and their real index in the SHT_SYMTAB_SHNDX section, kept in `index`.
+#[derive(Debug, Default)]
+pub struct ElfSymbolSectionIndex {
+    pub shndx: u16,
+    pub index: u32,
+}
+
+impl ElfSymbolSectionIndex {
+    pub const SHN_UNDEF: u16 = 0x0000;
+    pub const SHN_LORESERVE: u16 = 0xFF00;
+    pub const SHN_ABS: u16 = 0xFFF1;
+    pub const SHN_COMMON: u16 = 0xFFF2;
+    pub const SHN_XINDEX: u16 = 0xFFFF;
+
+    pub fn is_undefined(&self) -> bool {
+        self.shndx == Self::SHN_UNDEF
+    }
+
+    // Index of the section the symbol is defined in, `None` for the reserved values
+    pub fn section(&self) -> Option<usize> {
+        match self.shndx {
+            Self::SHN_UNDEF => None,
+            Self::SHN_XINDEX => Some(self.index as usize),
+            shndx if shndx >= Self::SHN_LORESERVE => None,
+            shndx => Some(shndx as usize),
+        }
+    }
+
+    // Symbolic name of st_shndx, the section index itself for ordinary sections
+    pub fn name(&self) -> String {
+        match self.shndx {
+            Self::SHN_UNDEF => "UND".to_string(),
+            Self::SHN_ABS => "ABS".to_string(),
+            Self::SHN_COMMON => "COMMON".to_string(),
+            Self::SHN_XINDEX => "XINDEX".to_string(),
+            shndx if shndx >= Self::SHN_LORESERVE => {
+                format!("LORESERVE+{:#x}", shndx - Self::SHN_LORESERVE)
+            }
+            _ => self.index.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ElfSymbolSectionIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.shndx {
+            Self::SHN_UNDEF => write!(f, "UND"),
+            Self::SHN_ABS => write!(f, "ABS"),
+            Self::SHN_COMMON => write!(f, "COM"),
+            _ => write!(f, "{}", self.index),
+        }
+    }
+}
+
+// The value is the section index, read from SHT_SYMTAB_SHNDX for XINDEX
+impl Serialize for ElfSymbolSectionIndex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, u64::from(self.index), &self.name())
+    }
+}
+
+pub fn parse_symbol_type(info: u8) -> ElfSymbolType {
+    match info & 0xF {
+        0 => ElfSymbolType::SttNotype,
+        1 => ElfSymbolType::SttObject,
+        2 => ElfSymbolType::SttFunc,
+        3 => ElfSymbolType::SttSection,
+        4 => ElfSymbolType::SttFile,
+        5 => ElfSymbolType::SttCommon,
+        6 => ElfSymbolType::SttTls,
+        10 => ElfSymbolType::SttGnuIfunc,
+        other => ElfSymbolType::Other(other),
+    }
+}
+
+pub fn parse_symbol_binding(info: u8) -> ElfSymbolBinding {
+    match info >> 4 {
+        0 => ElfSymbolBinding::StbLocal,
+        1 => ElfSymbolBinding::StbGlobal,
+        2 => ElfSymbolBinding::StbWeak,
+        10 => ElfSymbolBinding::StbGnuUnique,
+        other => ElfSymbolBinding::Other(other),
+    }
+}
+
+pub fn parse_symbol_visibility(other: u8) -> ElfSymbolVisibility {
+    match other & 0x3 {
+        0 => ElfSymbolVisibility::StvDefault,
+        1 => ElfSymbolVisibility::StvInternal,
+        2 => ElfSymbolVisibility::StvHidden,
+        _ => ElfSymbolVisibility::StvProtected,
+    }
+}
+
+// Elf32_Sym and Elf64_Sym hold the same fields in a different order
+pub fn parse_symbol(
+    reader: &mut ElfReader<'_>,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSymbol, ElfError> {
+    let name_offset = reader.read_u32("st_name", endian)?;
+    let (value, size, info, other, section_index) = match platform {
+        ElfPlatformType::Bit32 => {
+            let value = reader.read_word("st_value", endian, platform)?;
+            let size = reader.read_word("st_size", endian, platform)?;
+            let info = reader.read_u8("st_info")?;
+            let other = reader.read_u8("st_other")?;
+            let section_index = reader.read_u16("st_shndx", endian)?;
+            (value, size, info, other, section_index)
+        }
+        ElfPlatformType::Bit64 => {
+            let info = reader.read_u8("st_info")?;
+            let other = reader.read_u8("st_other")?;
+            let section_index = reader.read_u16("st_shndx", endian)?;
+            let value = reader.read_word("st_value", endian, platform)?;
+            let size = reader.read_word("st_size", endian, platform)?;
+            (value, size, info, other, section_index)
+        }
+    };
+
+    Ok(ElfSymbol {
+        symbol_index: 0,
+        symbol_name_offset: ElfSymbolNameOffset(name_offset),
+        symbol_value: ElfSymbolValue(value),
+        symbol_size: ElfSymbolSize(size),
+        symbol_type: parse_symbol_type(info),
+        symbol_binding: parse_symbol_binding(info),
+        symbol_visibility: parse_symbol_visibility(other),
+        symbol_section_index: ElfSymbolSectionIndex {
+            shndx: section_index,
+            index: section_index as u32,
+        },
+        symbol_name: ElfSymbolName::default(),
+    })
+}
+
+pub fn parse_symbol_table(
+    content: &[u8],
+    section_header: &ElfSectionHeader,
+    symbol_section: &ElfSectionHeaderEntry,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSymbolTable, ElfError> {
+    let entry_size = match (symbol_section.section_entry_size.0, platform) {
+        (0, ElfPlatformType::Bit32) => 16,
+        (0, ElfPlatformType::Bit64) => 24,
+        (size, _) => size,
+    };
+    let table_offset = symbol_section.section_offset.0;
+    let table = slice_at(
+        content,
+        "symbol table",
+        table_offset,
+        symbol_section.section_size.0,
+    )?;
+    let string_table = section_header
+        .inner
+        .get(symbol_section.section_link.0 as usize);
+    // The SHT_SYMTAB_SHNDX section linked back to this table, holding one
+    // 32-bit section index per symbol for the ones marked SHN_XINDEX
+    let table_index = section_header
+        .inner
+        .iter()
+        .position(|entry| std::ptr::eq(entry, symbol_section));
+    let extended_indices = section_header.inner.iter().find(|entry| {
+        matches!(
+            entry.section_header_type,
+            ElfSectionHeaderType::ShtSymtabShndx
+        ) && Some(entry.section_link.0 as usize) == table_index
+    });
+
+    let count = table.len() / entry_size;
+    let mut inner = Vec::with_capacity(count);
+    let mut reader = ElfReader::new(content);
+    for index in 0..count {
+        reader.seek(table_offset + index * entry_size);
+        let mut symbol = parse_symbol(&mut reader, endian, platform)?;
+        symbol.symbol_index = index;
+        if symbol.symbol_section_index.shndx == ElfSymbolSectionIndex::SHN_XINDEX
+            && let Some(extended_indices) = extended_indices
+        {
+            let offset = extended_indices
+                .section_offset
+                .0
+                .checked_add(index * 4)
+                .ok_or(ElfError::Overflow {
+                    field: "SHT_SYMTAB_SHNDX entry",
+                    offset: extended_indices.section_offset.0,
+                })?;
+            let mut reader = ElfReader::at(content, offset);
+            symbol.symbol_section_index.index =
+                reader.read_u32("SHT_SYMTAB_SHNDX entry", endian)?;
+        }
+        if let Some(string_table) = string_table {
+            let name_offset = symbol.symbol_name_offset.0 as usize;
+            let name = parse_string(content, string_table, name_offset)
+                .unwrap_or_else(|_| format!("<corrupt: {:#x}>", name_offset));
+            symbol.symbol_name = ElfSymbolName(name);
+        }
+        inner.push(symbol);
+    }
+
+    Ok(ElfSymbolTable {
+        section_name: symbol_section.section_name.inner(),
+        inner,
+    })
+}
+
+pub fn parse_symbol_tables(
+    content: &[u8],
+    section_header: &ElfSectionHeader,
+    endian: &ElfEndianness,
+    platform: &ElfPlatformType,
+) -> Result<ElfSymbolTables, ElfError> {
+    let inner = section_header
+        .inner
+        .iter()
+        .filter(|entry| {
+            matches!(
+                entry.section_header_type,
+                ElfSectionHeaderType::ShtSymtab | ElfSectionHeaderType::ShtDynsym
+            )
+        })
+        .map(|entry| parse_symbol_table(content, section_header, entry, endian, platform))
+        .collect::<Result<Vec<ElfSymbolTable>, ElfError>>()?;
+
+    Ok(ElfSymbolTables { inner })
+}
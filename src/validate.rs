@@ -0,0 +1,658 @@
+use serde::{Serialize, Serializer};
+use tabled::Tabled;
+
+use crate::{
+    ElfError, ElfHeader, ElfPlatformType, ElfProgramHeaderEntry, ElfReader, ElfSectionFlags,
+    ElfSectionHeaderEntry, ElfSectionHeaderSectionsTableIndex, ElfSectionHeaderType,
+    ElfSegmentType, entry_offset, parse_header, parse_program_header_count,
+    parse_program_header_entry, parse_section_header_entry, parse_string_at, parse_symbol,
+    serialize_named, slice_at,
+};
+
+// Everything `validate` found wrong with a file, in file order per check
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDiagnostics {
+    pub inner: Vec<ElfDiagnostic>,
+}
+
+impl ElfDiagnostics {
+    pub fn inner(self) -> Vec<ElfDiagnostic> {
+        self.inner
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.inner
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == ElfSeverity::Error)
+            .count()
+    }
+
+    fn push(&mut self, severity: ElfSeverity, check: &'static str, offset: usize, message: String) {
+        self.inner.push(ElfDiagnostic {
+            severity,
+            offset: ElfDiagnosticOffset(offset),
+            check,
+            message,
+        });
+    }
+
+    fn error(&mut self, check: &'static str, offset: usize, message: String) {
+        self.push(ElfSeverity::Error, check, offset, message);
+    }
+
+    fn warning(&mut self, check: &'static str, offset: usize, message: String) {
+        self.push(ElfSeverity::Warning, check, offset, message);
+    }
+}
+
+impl std::fmt::Display for ElfDiagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+#[derive(Debug, Tabled, Serialize)]
+pub struct ElfDiagnostic {
+    pub severity: ElfSeverity,
+    // Offset in the file of the structure the diagnostic is about
+    pub offset: ElfDiagnosticOffset,
+    // Short name of the invariant that does not hold, e.g. `segment-alignment`
+    pub check: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ElfDiagnosticOffset(pub usize);
+
+impl std::fmt::Display for ElfDiagnosticOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub enum ElfSeverity {
+    #[default]
+    Warning, // Unusual, but loaders and tools cope with it
+    Error, // Breaks the specification
+}
+
+impl ElfSeverity {
+    pub fn value(&self) -> u8 {
+        match self {
+            ElfSeverity::Warning => 1,
+            ElfSeverity::Error => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for ElfSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let txt = match self {
+            ElfSeverity::Warning => "warning",
+            ElfSeverity::Error => "error",
+        };
+
+        write!(f, "{}", txt)
+    }
+}
+
+impl Serialize for ElfSeverity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_named(serializer, self.value() as u64, self)
+    }
+}
+
+fn error_offset(err: &ElfError) -> usize {
+    match err {
+        ElfError::Io(_)
+        | ElfError::EmptyFile
+        | ElfError::SectionNotFound(_)
+        | ElfError::SymbolNotFound(_) => 0,
+        ElfError::NotElf { offset, .. }
+        | ElfError::UnsupportedClass { offset, .. }
+        | ElfError::Truncated { offset, .. }
+        | ElfError::Overflow { offset, .. }
+        | ElfError::InvalidValue { offset, .. }
+        | ElfError::Dwarf { offset, .. }
+        | ElfError::Decompression { offset, .. } => *offset,
+    }
+}
+
+// 0 and 1 mean no alignment constraint
+fn is_valid_alignment(align: usize) -> bool {
+    align <= 1 || align.is_power_of_two()
+}
+
+fn ranges_overlap(a: (usize, usize), b: (usize, usize)) -> bool {
+    a.0 < b.0.saturating_add(b.1) && b.0 < a.0.saturating_add(a.1)
+}
+
+// Sizes of the header and of one table entry for the file class, followed by
+// the offsets of the header fields they are checked against
+struct ClassLayout {
+    header_size: usize,
+    program_header_entry_size: usize,
+    section_header_entry_size: usize,
+    e_ehsize: usize,
+    e_phentsize: usize,
+    e_shentsize: usize,
+    e_shstrndx: usize,
+}
+
+fn class_layout(platform: &ElfPlatformType) -> ClassLayout {
+    match platform {
+        ElfPlatformType::Bit32 => ClassLayout {
+            header_size: 52,
+            program_header_entry_size: 32,
+            section_header_entry_size: 40,
+            e_ehsize: 0x28,
+            e_phentsize: 0x2A,
+            e_shentsize: 0x2E,
+            e_shstrndx: 0x32,
+        },
+        ElfPlatformType::Bit64 => ClassLayout {
+            header_size: 64,
+            program_header_entry_size: 56,
+            section_header_entry_size: 64,
+            e_ehsize: 0x34,
+            e_phentsize: 0x36,
+            e_shentsize: 0x3A,
+            e_shstrndx: 0x3E,
+        },
+    }
+}
+
+// A header table as declared by the ELF header, with the entry size of the
+// file class and the offset of the e_*entsize field it is checked against
+struct TableLayout {
+    name: &'static str,
+    offset: usize,
+    count: usize,
+    entry_size: usize,
+    minimum_entry_size: usize,
+    size_field_offset: usize,
+}
+
+// Checks that the table fits in the file. Returns the entry size to walk it
+// with.
+fn check_table(
+    diagnostics: &mut ElfDiagnostics,
+    content: &[u8],
+    table: &TableLayout,
+) -> Option<usize> {
+    let TableLayout {
+        name,
+        offset,
+        count,
+        entry_size,
+        minimum_entry_size,
+        size_field_offset,
+    } = *table;
+    if count == 0 {
+        return None;
+    }
+    if entry_size < minimum_entry_size {
+        diagnostics.error(
+            "entry-size",
+            size_field_offset,
+            format!(
+                "{name} entry size is {entry_size}, smaller than the {minimum_entry_size} bytes of the class"
+            ),
+        );
+        return None;
+    }
+    if entry_size > minimum_entry_size {
+        diagnostics.warning(
+            "entry-size",
+            size_field_offset,
+            format!(
+                "{name} entry size is {entry_size} instead of {minimum_entry_size}, entries are padded"
+            ),
+        );
+    }
+
+    let end = count
+        .checked_mul(entry_size)
+        .and_then(|size| size.checked_add(offset));
+    match end {
+        Some(end) if end <= content.len() => Some(entry_size),
+        _ => {
+            diagnostics.error(
+                "table-bounds",
+                offset,
+                format!(
+                    "{name} table of {count} entries of {entry_size} bytes does not fit in the file ({} bytes)",
+                    content.len()
+                ),
+            );
+            None
+        }
+    }
+}
+
+fn check_segments(
+    diagnostics: &mut ElfDiagnostics,
+    content: &[u8],
+    segments: &[(usize, ElfProgramHeaderEntry)],
+) {
+    let mut previous_load: Option<&ElfProgramHeaderEntry> = None;
+    let mut loads: Vec<&(usize, ElfProgramHeaderEntry)> = Vec::new();
+    for entry @ (offset, segment) in segments {
+        let offset = *offset;
+        let file_range = (segment.segment_offset.0, segment.segment_file_size.0);
+        if slice_at(content, "segment", file_range.0, file_range.1).is_err() {
+            diagnostics.error(
+                "segment-bounds",
+                offset,
+                format!(
+                    "{} at {:#x} with {:#x} bytes runs past the end of the file",
+                    segment.segment_type, file_range.0, file_range.1
+                ),
+            );
+        }
+
+        let align = segment.segment_allignment.0;
+        if !is_valid_alignment(align) {
+            diagnostics.error(
+                "segment-alignment",
+                offset,
+                format!(
+                    "{} alignment {:#x} is not a power of two",
+                    segment.segment_type, align
+                ),
+            );
+        }
+
+        if !matches!(segment.segment_type, ElfSegmentType::PtLoad) {
+            continue;
+        }
+
+        if segment.segment_memory_size.0 < segment.segment_file_size.0 {
+            diagnostics.error(
+                "segment-size",
+                offset,
+                format!(
+                    "PT_LOAD memory size {:#x} is smaller than its file size {:#x}",
+                    segment.segment_memory_size.0, segment.segment_file_size.0
+                ),
+            );
+        }
+        if align > 1
+            && is_valid_alignment(align)
+            && segment.segment_vaddr.0 % align != segment.segment_offset.0 % align
+        {
+            diagnostics.error(
+                "segment-congruence",
+                offset,
+                format!(
+                    "PT_LOAD vaddr {:#x} and offset {:#x} are not congruent modulo the alignment {:#x}",
+                    segment.segment_vaddr.0, segment.segment_offset.0, align
+                ),
+            );
+        }
+        if let Some(previous) = previous_load
+            && segment.segment_vaddr.0 < previous.segment_vaddr.0
+        {
+            diagnostics.error(
+                "segment-order",
+                offset,
+                format!(
+                    "PT_LOAD at vaddr {:#x} follows one at {:#x}, they must be sorted by vaddr",
+                    segment.segment_vaddr.0, previous.segment_vaddr.0
+                ),
+            );
+        }
+        previous_load = Some(segment);
+
+        let memory_range = (segment.segment_vaddr.0, segment.segment_memory_size.0);
+        for (other_offset, other) in loads.iter().copied() {
+            if ranges_overlap(
+                memory_range,
+                (other.segment_vaddr.0, other.segment_memory_size.0),
+            ) {
+                diagnostics.error(
+                    "segment-overlap",
+                    offset,
+                    format!(
+                        "PT_LOAD at vaddr {:#x} overlaps the PT_LOAD at {:#x} (entry at {:#x}) in memory",
+                        segment.segment_vaddr.0, other.segment_vaddr.0, other_offset
+                    ),
+                );
+            }
+        }
+        loads.push(entry);
+    }
+}
+
+// Entries of the section header table that could be read, with their index in
+// the table and their file offset
+type ElfSectionEntries = [(usize, usize, ElfSectionHeaderEntry)];
+
+fn section_at(sections: &ElfSectionEntries, index: usize) -> Option<&ElfSectionHeaderEntry> {
+    sections
+        .iter()
+        .find(|(entry_index, _, _)| *entry_index == index)
+        .map(|(_, _, entry)| entry)
+}
+
+fn check_sections(
+    diagnostics: &mut ElfDiagnostics,
+    content: &[u8],
+    header: &ElfHeader,
+    count: usize,
+    sections: &mut ElfSectionEntries,
+) {
+    let layout = class_layout(&header.platform_type);
+    // Missing or unreadable tables are already reported
+    if sections.is_empty() {
+        return;
+    }
+
+    // e_shstrndx, resolved through section 0 when it is SHN_XINDEX
+    let names_index = match header.section_header_sections_table_index.0 {
+        0 => None,
+        ElfSectionHeaderSectionsTableIndex::SHN_XINDEX => {
+            section_at(sections, 0).map(|entry| entry.section_link.0 as usize)
+        }
+        index => Some(index as usize),
+    };
+    let names_table = match names_index.map(|index| (index, section_at(sections, index))) {
+        None => None,
+        Some((index, None)) => {
+            diagnostics.error(
+                "shstrndx",
+                layout.e_shstrndx,
+                format!("e_shstrndx {index} is out of range ({count} sections)"),
+            );
+            None
+        }
+        Some((index, Some(entry))) => {
+            if !matches!(entry.section_header_type, ElfSectionHeaderType::ShtStrtab) {
+                diagnostics.error(
+                    "shstrndx",
+                    layout.e_shstrndx,
+                    format!(
+                        "e_shstrndx {index} points at a {} section instead of SHT_STRTAB",
+                        entry.section_header_type
+                    ),
+                );
+            }
+            Some((entry.section_offset.0, entry.section_size.0))
+        }
+    };
+
+    // The table entries carry only name offsets, resolve them for the messages
+    if let Some((table_offset, table_size)) = names_table {
+        for (index, offset, section) in sections.iter_mut() {
+            let name_offset = section.section_name_offset.0 as usize;
+            match parse_string_at(content, table_offset, table_size, name_offset) {
+                Ok(name) => section.section_name.0 = name,
+                Err(_) => diagnostics.error(
+                    "string-offset",
+                    *offset,
+                    format!(
+                        "section {index} name offset {name_offset:#x} is outside the section name table"
+                    ),
+                ),
+            }
+        }
+    }
+
+    for (index, offset, section) in sections.iter() {
+        let (index, offset) = (*index, *offset);
+        let name = &section.section_name.0;
+
+        if !matches!(
+            section.section_header_type,
+            ElfSectionHeaderType::ShtNull | ElfSectionHeaderType::ShtNobits
+        ) && slice_at(
+            content,
+            "section",
+            section.section_offset.0,
+            section.section_size.0,
+        )
+        .is_err()
+        {
+            diagnostics.error(
+                "section-bounds",
+                offset,
+                format!(
+                    "section {index} {name} at {:#x} with {:#x} bytes runs past the end of the file",
+                    section.section_offset.0, section.section_size.0
+                ),
+            );
+        }
+
+        let align = section.section_addr_allign.0;
+        if !is_valid_alignment(align) {
+            diagnostics.error(
+                "section-alignment",
+                offset,
+                format!("section {index} {name} alignment {align:#x} is not a power of two"),
+            );
+        }
+
+        // Section 0 holds the extended counts in sh_link and sh_info
+        if index == 0 {
+            continue;
+        }
+
+        let link = section.section_link.0 as usize;
+        if link >= count {
+            diagnostics.error(
+                "section-link",
+                offset,
+                format!("section {index} {name} sh_link {link} is out of range ({count} sections)"),
+            );
+        }
+
+        let info = section.section_info.0 as usize;
+        let info_is_index = section
+            .section_flags
+            .contains(ElfSectionFlags::SHF_INFO_LINK)
+            || matches!(
+                section.section_header_type,
+                ElfSectionHeaderType::ShtRel | ElfSectionHeaderType::ShtRela
+            );
+        if info_is_index && info >= count {
+            diagnostics.error(
+                "section-info",
+                offset,
+                format!("section {index} {name} sh_info {info} is out of range ({count} sections)"),
+            );
+        }
+
+        if matches!(
+            section.section_header_type,
+            ElfSectionHeaderType::ShtSymtab | ElfSectionHeaderType::ShtDynsym
+        ) {
+            check_symbols(diagnostics, content, header, sections, offset, section);
+        }
+    }
+}
+
+// sh_link of a symbol table must be a string table holding every st_name, and
+// sh_info is one past the last local symbol.
+fn check_symbols(
+    diagnostics: &mut ElfDiagnostics,
+    content: &[u8],
+    header: &ElfHeader,
+    sections: &ElfSectionEntries,
+    offset: usize,
+    section: &ElfSectionHeaderEntry,
+) {
+    let name = &section.section_name.0;
+    let entry_size = match (section.section_entry_size.0, &header.platform_type) {
+        (0, ElfPlatformType::Bit32) => 16,
+        (0, ElfPlatformType::Bit64) => 24,
+        (size, _) => size,
+    };
+    let Ok(table) = slice_at(
+        content,
+        "symbol table",
+        section.section_offset.0,
+        section.section_size.0,
+    ) else {
+        return;
+    };
+    let symbol_count = table.len() / entry_size;
+
+    if section.section_info.0 as usize > symbol_count {
+        diagnostics.error(
+            "symbol-info",
+            offset,
+            format!(
+                "{name} sh_info {} is past its {symbol_count} symbols",
+                section.section_info.0
+            ),
+        );
+    }
+
+    let Some(strings) = section_at(sections, section.section_link.0 as usize) else {
+        return;
+    };
+    if !matches!(strings.section_header_type, ElfSectionHeaderType::ShtStrtab) {
+        diagnostics.error(
+            "symbol-strings",
+            offset,
+            format!(
+                "{name} links to {} which is a {} section instead of SHT_STRTAB",
+                strings.section_name.0, strings.section_header_type
+            ),
+        );
+        return;
+    }
+
+    let mut reader = ElfReader::new(content);
+    for symbol_index in 0..symbol_count {
+        let Ok(symbol_offset) =
+            entry_offset("symbol", section.section_offset.0, symbol_index, entry_size)
+        else {
+            return;
+        };
+        reader.seek(symbol_offset);
+        let Ok(symbol) = parse_symbol(&mut reader, &header.endianness, &header.platform_type)
+        else {
+            return;
+        };
+        let name_offset = symbol.symbol_name_offset.0 as usize;
+        if parse_string_at(
+            content,
+            strings.section_offset.0,
+            strings.section_size.0,
+            name_offset,
+        )
+        .is_err()
+        {
+            diagnostics.error(
+                "string-offset",
+                symbol_offset,
+                format!(
+                    "symbol {symbol_index} of {name} has name offset {name_offset:#x} outside {}",
+                    strings.section_name.0
+                ),
+            );
+        }
+    }
+}
+
+// Checks the structural invariants of an ELF file. Unlike the parsers this keeps
+// going after a problem, so that every violation is reported at once.
+pub fn validate(content: &[u8]) -> ElfDiagnostics {
+    let mut diagnostics = ElfDiagnostics::default();
+    let mut reader = ElfReader::new(content);
+    let header = match parse_header(&mut reader) {
+        Ok(header) => header,
+        Err(err) => {
+            diagnostics.error("header", error_offset(&err), err.to_string());
+            return diagnostics;
+        }
+    };
+    let layout = class_layout(&header.platform_type);
+
+    if header.header_size.0 as usize != layout.header_size {
+        diagnostics.error(
+            "header-size",
+            layout.e_ehsize,
+            format!(
+                "e_ehsize is {}, the {} header is {} bytes",
+                header.header_size.0, header.platform_type, layout.header_size
+            ),
+        );
+    }
+
+    // Program header table
+    let mut segments = Vec::new();
+    match parse_program_header_count(content, &header) {
+        Err(err) => diagnostics.error("table-bounds", error_offset(&err), err.to_string()),
+        Ok(count) => {
+            let start = header.program_header_offset.0;
+            if let Some(entry_size) = check_table(
+                &mut diagnostics,
+                content,
+                &TableLayout {
+                    name: "program header",
+                    offset: start,
+                    count,
+                    entry_size: header.program_header_entry_size.0 as usize,
+                    minimum_entry_size: layout.program_header_entry_size,
+                    size_field_offset: layout.e_phentsize,
+                },
+            ) {
+                for index in 0..count {
+                    let offset = start + index * entry_size;
+                    reader.seek(offset);
+                    match parse_program_header_entry(
+                        &mut reader,
+                        &header.endianness,
+                        &header.platform_type,
+                    ) {
+                        Ok(entry) => segments.push((offset, entry)),
+                        Err(err) => {
+                            diagnostics.error("segment", error_offset(&err), err.to_string())
+                        }
+                    }
+                }
+            }
+        }
+    }
+    check_segments(&mut diagnostics, content, &segments);
+
+    // Section header table, with the count in section 0 for extended numbering
+    let start = header.section_header_offset.0;
+    let mut count = header.section_header_entry_count.0 as usize;
+    if count == 0 && start != 0 {
+        reader.seek(start);
+        match parse_section_header_entry(&mut reader, &header.endianness, &header.platform_type) {
+            Ok(first) => count = first.section_size.0,
+            Err(err) => diagnostics.error("table-bounds", error_offset(&err), err.to_string()),
+        }
+    }
+    let mut sections = Vec::new();
+    if let Some(entry_size) = check_table(
+        &mut diagnostics,
+        content,
+        &TableLayout {
+            name: "section header",
+            offset: start,
+            count,
+            entry_size: header.section_header_entry_size.0 as usize,
+            minimum_entry_size: layout.section_header_entry_size,
+            size_field_offset: layout.e_shentsize,
+        },
+    ) {
+        for index in 0..count {
+            let offset = start + index * entry_size;
+            reader.seek(offset);
+            match parse_section_header_entry(&mut reader, &header.endianness, &header.platform_type)
+            {
+                Ok(entry) => sections.push((index, offset, entry)),
+                Err(err) => diagnostics.error("section", error_offset(&err), err.to_string()),
+            }
+        }
+    }
+    check_sections(&mut diagnostics, content, &header, count, &mut sections);
+
+    diagnostics
+}